@@ -0,0 +1,31 @@
+/* C interface to the f8 crate. F8 values are passed as their raw bits. */
+#ifndef F8_H
+#define F8_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef uint8_t f8;
+
+f8 f8_from_f32(float f);
+float f8_to_f32(f8 f);
+f8 f8_add(f8 a, f8 b);
+f8 f8_sub(f8 a, f8 b);
+f8 f8_mul(f8 a, f8 b);
+f8 f8_neg(f8 a);
+
+/* src and dst must hold len elements, and must not overlap. */
+void f8_from_f32_slice(const float *src, f8 *dst, size_t len);
+void f8_to_f32_slice(const f8 *src, float *dst, size_t len);
+/* a[i] = a[i] + b[i] */
+void f8_add_slice(f8 *a, const f8 *b, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,85 @@
+//! Activation functions applied directly to F8 values through 256 entry lookup tables, which
+//! hold the nearest F8 to the f32 result for every input bit pattern. As the f32 result is
+//! itself rounded, this is not guaranteed to be the correctly rounded F8 result.
+
+use crate::f8::F8;
+use std::sync::OnceLock;
+
+/// Builds the table of `f` rounded back to F8 for every F8 input
+pub fn build_lut(f: impl Fn(f32) -> f32) -> [F8; 256] {
+  let mut lut = [F8::from_bits(0); 256];
+  for (i, l) in lut.iter_mut().enumerate() {
+    *l = F8::approx_from(f(F8::DECODE_TABLE[i]));
+  }
+  lut
+}
+
+/// Applies a lookup table to every element of `src`, writing into `dst`
+pub fn apply_lut(lut: &[F8; 256], src: &[F8], dst: &mut [F8]) {
+  assert_eq!(src.len(), dst.len(), "Mismatched lengths");
+  for (d, s) in dst.iter_mut().zip(src) {
+    *d = lut[s.to_bits() as usize];
+  }
+}
+
+/// Applies a lookup table to every element of `v` in place
+pub fn apply_lut_in_place(lut: &[F8; 256], v: &mut [F8]) {
+  for x in v.iter_mut() {
+    *x = lut[x.to_bits() as usize];
+  }
+}
+
+fn erf(x: f32) -> f32 {
+  // Abramowitz and Stegun 7.1.26, computed in f64 which is far beyond F8 precision
+  let x = x as f64;
+  let t = 1.0 / (1.0 + 0.327_591_1 * x.abs());
+  let poly = [
+    1.061_405_429,
+    -1.453_152_027,
+    1.421_413_741,
+    -0.284_496_736,
+    0.254_829_592,
+  ]
+  .iter()
+  .fold(0.0, |acc, &c| (acc + c) * t);
+  let y = 1.0 - poly * (-x * x).exp();
+  (if x < 0.0 { -y } else { y }) as f32
+}
+
+pub(crate) fn relu_f32(x: f32) -> f32 { x.max(0.0) }
+pub(crate) fn gelu_f32(x: f32) -> f32 { 0.5 * x * (1.0 + erf(x / std::f32::consts::SQRT_2)) }
+pub(crate) fn silu_f32(x: f32) -> f32 { x / (1.0 + (-x).exp()) }
+
+pub(crate) fn relu_grad_f32(x: f32) -> f32 { (x > 0.0) as u8 as f32 }
+pub(crate) fn gelu_grad_f32(x: f32) -> f32 {
+  let pdf = (-0.5 * x * x).exp() / (2.0 * std::f32::consts::PI).sqrt();
+  0.5 * (1.0 + erf(x / std::f32::consts::SQRT_2)) + x * pdf
+}
+pub(crate) fn silu_grad_f32(x: f32) -> f32 {
+  let s = 1.0 / (1.0 + (-x).exp());
+  s * (1.0 + x * (1.0 - s))
+}
+
+macro_rules! activation {
+  ($name: ident, $in_place: ident, $lut: ident, $f: expr, $doc: literal) => {
+    #[doc = concat!("Lookup table of ", $doc, " for every F8")]
+    pub fn $lut() -> &'static [F8; 256] {
+      static LUT: OnceLock<[F8; 256]> = OnceLock::new();
+      LUT.get_or_init(|| build_lut($f))
+    }
+    #[doc = concat!("Applies ", $doc, " to every element of `src`, writing into `dst`")]
+    pub fn $name(src: &[F8], dst: &mut [F8]) { apply_lut($lut(), src, dst) }
+    #[doc = concat!("Applies ", $doc, " to every element in place")]
+    pub fn $in_place(v: &mut [F8]) { apply_lut_in_place($lut(), v) }
+  };
+}
+
+activation!(relu, relu_in_place, relu_lut, relu_f32, "ReLU");
+activation!(
+  gelu,
+  gelu_in_place,
+  gelu_lut,
+  gelu_f32,
+  "GELU (exact erf form)"
+);
+activation!(silu, silu_in_place, silu_lut, silu_f32, "SiLU");
@@ -0,0 +1,125 @@
+use crate::{
+  f8::{bracket, F8},
+  scaled::{absmax_scale, ScaledF8Tensor},
+};
+
+const ZETA: f32 = 1.1;
+const GAMMA: f32 = -0.1;
+const LAMBDA: f32 = 0.01;
+const LR: f32 = 0.01;
+
+fn sigmoid(x: f32) -> f32 { 1.0 / (1.0 + (-x).exp()) }
+
+/// Rectified sigmoid, giving the fraction of the way from rounding down to up
+fn rect(v: f32) -> f32 { (sigmoid(v) * (ZETA - GAMMA) + GAMMA).clamp(0.0, 1.0) }
+
+/// Mean squared output error `(x (w - q)^T)^2` of a quantized `rows x cols` matrix against
+/// `n x cols` activations.
+pub fn output_error(weights: &[f32], q: &ScaledF8Tensor, activations: &[f32], cols: usize) -> f32 {
+  let n = activations.len() / cols;
+  let mut sum = 0f64;
+  for x in activations.chunks_exact(cols) {
+    for (w, qr) in weights.chunks_exact(cols).zip(q.data.chunks_exact(cols)) {
+      let e: f32 = (0..cols).map(|c| x[c] * (w[c] - qr[c].v() * q.scale)).sum();
+      sum += (e * e) as f64;
+    }
+  }
+  (sum / n.max(1) as f64) as f32
+}
+
+/// Quantizes a `rows x cols` row major weight matrix, choosing for each element whether to
+/// round down or up so as to minimize the error of the layer's output on `activations`, a
+/// row major `n x cols` matrix of calibration inputs.
+///
+/// This follows AdaRound (Nagel et al. 2020): a rectified sigmoid relaxation of each rounding
+/// choice is optimized with Adam for `iters` steps, with a regularizer which is annealed to
+/// push every choice to either end after a warm up period.
+pub fn adaround(weights: &[f32], activations: &[f32], cols: usize, iters: usize) -> ScaledF8Tensor {
+  assert!(
+    cols > 0 && weights.len().is_multiple_of(cols),
+    "weights are not a multiple of cols"
+  );
+  assert!(
+    activations.len().is_multiple_of(cols),
+    "activations are not a multiple of cols"
+  );
+  let scale = absmax_scale(weights);
+  let n = (activations.len() / cols).max(1);
+  // Hessian of the output error, X^T X / n
+  let mut h = vec![0f32; cols * cols];
+  for x in activations.chunks_exact(cols) {
+    for i in 0..cols {
+      for j in 0..cols {
+        h[i * cols + j] += x[i] * x[j] / n as f32;
+      }
+    }
+  }
+
+  let ws: Vec<f32> = weights.iter().map(|w| w / scale).collect();
+  let (lo, hi): (Vec<f32>, Vec<f32>) = ws
+    .iter()
+    .map(|&w| {
+      let (lo, hi) = bracket(w.abs());
+      if w < 0.0 {
+        (-hi.v(), -lo.v())
+      } else {
+        (lo.v(), hi.v())
+      }
+    })
+    .unzip();
+  let mut v: Vec<f32> = ws
+    .iter()
+    .zip(lo.iter().zip(&hi))
+    .map(|(&w, (&l, &h))| {
+      let frac = if h > l { (w - l) / (h - l) } else { 0.0 };
+      let p = ((frac - GAMMA) / (ZETA - GAMMA)).clamp(1e-4, 1.0 - 1e-4);
+      (p / (1.0 - p)).ln()
+    })
+    .collect();
+
+  let (mut m1, mut m2) = (vec![0f32; v.len()], vec![0f32; v.len()]);
+  let rows = ws.len() / cols;
+  let warmup = iters / 5;
+  let mut d = vec![0f32; cols];
+  for t in 0..iters {
+    let (lambda, beta) = if t < warmup {
+      (0.0, 20.0)
+    } else {
+      (
+        LAMBDA,
+        20.0 - 18.0 * (t - warmup) as f32 / (iters - warmup) as f32,
+      )
+    };
+    for r in 0..rows {
+      let row = r * cols..(r + 1) * cols;
+      // error in the original units of the weights
+      for (c, i) in row.clone().enumerate() {
+        d[c] = scale * (ws[i] - (lo[i] + rect(v[i]) * (hi[i] - lo[i])));
+      }
+      for (c, i) in row.enumerate() {
+        let hd: f32 = (0..cols).map(|j| h[c * cols + j] * d[j]).sum();
+        let s = sigmoid(v[i]);
+        let soft = s * (ZETA - GAMMA) + GAMMA;
+        let dh_dv = if soft > 0.0 && soft < 1.0 {
+          s * (1.0 - s) * (ZETA - GAMMA)
+        } else {
+          0.0
+        };
+        let hv = rect(v[i]);
+        let x = 2.0 * hv - 1.0;
+        let reg = -2.0 * beta * x.abs().powf(beta - 1.0) * x.signum();
+        let g = (-2.0 * hd * scale * (hi[i] - lo[i]) / rows as f32 + lambda * reg) * dh_dv;
+        m1[i] = 0.9 * m1[i] + 0.1 * g;
+        m2[i] = 0.999 * m2[i] + 0.001 * g * g;
+        let m1h = m1[i] / (1.0 - 0.9f32.powi(t as i32 + 1));
+        let m2h = m2[i] / (1.0 - 0.999f32.powi(t as i32 + 1));
+        v[i] -= LR * m1h / (m2h.sqrt() + 1e-8);
+      }
+    }
+  }
+
+  let data = (0..ws.len())
+    .map(|i| F8::approx_from(if rect(v[i]) >= 0.5 { hi[i] } else { lo[i] }))
+    .collect();
+  ScaledF8Tensor { scale, data }
+}
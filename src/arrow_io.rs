@@ -0,0 +1,103 @@
+//! An Arrow extension type for 8 bit floats stored in `UInt8` columns, with casts to and from
+//! `Float32`.
+
+use crate::{
+  f8::F8,
+  ofp8::{E4M3, E5M2},
+};
+use arrow_array::{
+  types::{Float32Type, UInt8Type},
+  Float32Array, UInt8Array,
+};
+use arrow_schema::{extension::ExtensionType, ArrowError, DataType, Field};
+
+/// Which 8 bit format the bytes of a column encode
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Fp8Kind {
+  F8,
+  E4M3,
+  E5M2,
+}
+
+impl Fp8Kind {
+  pub fn name(self) -> &'static str {
+    match self {
+      Fp8Kind::F8 => "f8",
+      Fp8Kind::E4M3 => "e4m3",
+      Fp8Kind::E5M2 => "e5m2",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    [Fp8Kind::F8, Fp8Kind::E4M3, Fp8Kind::E5M2]
+      .iter()
+      .copied()
+      .find(|k| k.name() == name)
+  }
+  pub fn decode(self, b: u8) -> f32 {
+    match self {
+      Fp8Kind::F8 => F8::from_bits(b).v(),
+      Fp8Kind::E4M3 => E4M3(b).to_f32(),
+      Fp8Kind::E5M2 => E5M2(b).to_f32(),
+    }
+  }
+  pub fn encode(self, v: f32) -> u8 {
+    match self {
+      Fp8Kind::F8 => F8::approx_from(v).to_bits(),
+      Fp8Kind::E4M3 => E4M3::from_f32(v).to_bits(),
+      Fp8Kind::E5M2 => E5M2::from_f32(v).to_bits(),
+    }
+  }
+}
+
+/// The `f8.fp8` extension type over `UInt8`, with the format name as its metadata
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Fp8Extension(pub Fp8Kind);
+
+impl ExtensionType for Fp8Extension {
+  const NAME: &'static str = "f8.fp8";
+  type Metadata = Fp8Kind;
+  fn metadata(&self) -> &Fp8Kind { &self.0 }
+  fn serialize_metadata(&self) -> Option<String> { Some(self.0.name().to_string()) }
+  fn deserialize_metadata(metadata: Option<&str>) -> Result<Fp8Kind, ArrowError> {
+    metadata
+      .and_then(Fp8Kind::from_name)
+      .ok_or_else(|| ArrowError::InvalidArgumentError(format!("Unknown FP8 format {:?}", metadata)))
+  }
+  fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+    match data_type {
+      DataType::UInt8 => Ok(()),
+      t => Err(ArrowError::InvalidArgumentError(format!(
+        "FP8 must be stored as UInt8, not {}",
+        t
+      ))),
+    }
+  }
+  fn try_new(data_type: &DataType, metadata: Fp8Kind) -> Result<Self, ArrowError> {
+    let e = Fp8Extension(metadata);
+    e.supports_data_type(data_type)?;
+    Ok(e)
+  }
+}
+
+/// A `UInt8` field tagged with the FP8 extension type
+pub fn fp8_field(name: &str, kind: Fp8Kind, nullable: bool) -> Field {
+  Field::new(name, DataType::UInt8, nullable).with_extension_type(Fp8Extension(kind))
+}
+
+/// The format of a field, if it has the FP8 extension type
+pub fn fp8_kind(field: &Field) -> Option<Fp8Kind> {
+  field.try_extension_type::<Fp8Extension>().ok().map(|e| e.0)
+}
+
+/// An F8 column without nulls
+pub fn from_f8s(data: &[F8]) -> UInt8Array { data.iter().map(|f| f.to_bits()).collect() }
+
+/// Decodes an FP8 column, keeping its nulls
+pub fn cast_to_f32(a: &UInt8Array, kind: Fp8Kind) -> Float32Array {
+  a.unary::<_, Float32Type>(|b| kind.decode(b))
+}
+
+/// Encodes a `Float32` column as FP8, keeping its nulls
+pub fn cast_from_f32(a: &Float32Array, kind: Fp8Kind) -> UInt8Array {
+  a.unary::<_, UInt8Type>(|v| kind.encode(v))
+}
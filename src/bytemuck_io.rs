@@ -0,0 +1,36 @@
+//! `bytemuck` support, so slices of the 8 bit formats and the pixel and vector types built on
+//! them can be cast to and from bytes.
+
+use crate::{
+  color::{Rgb, Rgba},
+  e8m0::E8M0,
+  f8::F8,
+  normal::PackedNormal,
+  ofp8::{E4M3, E5M2},
+  packed::F8x4,
+  rgbe::Rgbe8,
+};
+use bytemuck::{Pod, Zeroable};
+
+macro_rules! impl_pod {
+  ($($t: ty),*) => {
+    $(
+      // SAFETY: each type is transparent or `repr(C)` over bytes with no padding, and every bit
+      // pattern is valid.
+      unsafe impl Zeroable for $t {}
+      unsafe impl Pod for $t {}
+    )*
+  };
+}
+
+impl_pod!(
+  F8,
+  E4M3,
+  E5M2,
+  E8M0,
+  F8x4,
+  PackedNormal,
+  Rgb<F8>,
+  Rgba<F8>,
+  Rgbe8
+);
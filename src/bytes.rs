@@ -0,0 +1,46 @@
+//! Zero copy casts between F8 and bytes, relying on F8 being `repr(transparent)` over `u8`
+//! with every bit pattern valid, for I/O without a `bytemuck` dependency.
+
+use crate::f8::F8;
+#[cfg(feature = "alloc")]
+use {alloc::vec::Vec, core::mem::ManuallyDrop};
+
+/// The bit patterns of `data`
+pub fn as_bytes(data: &[F8]) -> &[u8] {
+  // SAFETY: F8 is repr(transparent) over u8
+  unsafe { core::slice::from_raw_parts(data.as_ptr() as *const u8, data.len()) }
+}
+
+/// `as_bytes` for mutable slices
+pub fn as_bytes_mut(data: &mut [F8]) -> &mut [u8] {
+  // SAFETY: F8 is repr(transparent) over u8, and every bit pattern is a valid F8
+  unsafe { core::slice::from_raw_parts_mut(data.as_mut_ptr() as *mut u8, data.len()) }
+}
+
+/// Each byte as an F8 bit pattern
+pub fn from_bytes(bytes: &[u8]) -> &[F8] {
+  // SAFETY: F8 is repr(transparent) over u8, and every bit pattern is a valid F8
+  unsafe { core::slice::from_raw_parts(bytes.as_ptr() as *const F8, bytes.len()) }
+}
+
+/// `from_bytes` for mutable slices
+pub fn from_bytes_mut(bytes: &mut [u8]) -> &mut [F8] {
+  // SAFETY: as for `from_bytes`
+  unsafe { core::slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut F8, bytes.len()) }
+}
+
+/// `as_bytes` for an owned vector, without copying
+#[cfg(feature = "alloc")]
+pub fn into_bytes(data: Vec<F8>) -> Vec<u8> {
+  let mut data = ManuallyDrop::new(data);
+  // SAFETY: F8 and u8 share size and alignment, so the allocation is valid for either
+  unsafe { Vec::from_raw_parts(data.as_mut_ptr() as *mut u8, data.len(), data.capacity()) }
+}
+
+/// `from_bytes` for an owned vector, without copying
+#[cfg(feature = "alloc")]
+pub fn from_byte_vec(bytes: Vec<u8>) -> Vec<F8> {
+  let mut bytes = ManuallyDrop::new(bytes);
+  // SAFETY: as for `into_bytes`, and every bit pattern is a valid F8
+  unsafe { Vec::from_raw_parts(bytes.as_mut_ptr() as *mut F8, bytes.len(), bytes.capacity()) }
+}
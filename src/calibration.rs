@@ -0,0 +1,117 @@
+use crate::{f8::F8, scaled::absmax_scale};
+
+/// What `calibrate_with` minimizes when choosing a scale
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Objective {
+  /// Mean squared error between the data and its quantized reconstruction
+  Mse,
+  /// KL divergence between the histograms of the data and its quantized reconstruction
+  Kl,
+}
+
+/// Recommended quantization parameters for a dataset
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Calibration {
+  /// Value to divide data by before converting to F8
+  pub scale: f32,
+  /// Magnitude beyond which values are clipped with this scale
+  pub clip: f32,
+  pub objective: Objective,
+  /// Value of the objective at the chosen scale
+  pub error: f32,
+}
+
+/// Number of candidate scales tried, each a quarter octave below the previous
+const CANDIDATES: i32 = 48;
+/// The KL histogram uses logarithmic bins, spanning this many octaves below the largest value
+const KL_OCTAVES: usize = 24;
+const KL_BINS_PER_OCTAVE: usize = 32;
+const KL_BINS: usize = KL_OCTAVES * KL_BINS_PER_OCTAVE;
+
+fn kl_bin(v: f32, amax: f32) -> usize {
+  let pos = ((v / amax).log2() + KL_OCTAVES as f32) * KL_BINS_PER_OCTAVE as f32;
+  (pos.max(0.0) as usize).min(KL_BINS - 1)
+}
+
+fn kl_bin_center(i: usize, amax: f32) -> f32 {
+  amax * ((i as f32 + 0.5) / KL_BINS_PER_OCTAVE as f32 - KL_OCTAVES as f32).exp2()
+}
+
+fn candidates(data: &[f32]) -> impl Iterator<Item = f32> {
+  let base = absmax_scale(data);
+  (0..CANDIDATES).map(move |i| base * 2f32.powf(-i as f32 / 4.0))
+}
+
+fn mse(data: &[f32], scale: f32) -> f32 {
+  let sum: f64 = data
+    .iter()
+    .map(|&v| {
+      let d = (v - F8::approx_from(v / scale).v() * scale) as f64;
+      d * d
+    })
+    .sum();
+  (sum / data.len().max(1) as f64) as f32
+}
+
+fn kl(hist: &[f64], amax: f32, scale: f32) -> f32 {
+  // Bins which quantize to the same F8 share their mass evenly within the quantized
+  // distribution, so the divergence measures how much detail merging them loses.
+  let level = |i: usize| F8::approx_from(kl_bin_center(i, amax) / scale).to_bits();
+  let mut mass = [0f64; 256];
+  let mut count = [0u32; 256];
+  for (i, &p) in hist.iter().enumerate() {
+    if p > 0.0 {
+      mass[level(i) as usize] += p;
+      count[level(i) as usize] += 1;
+    }
+  }
+  let mut div = 0.0;
+  for (i, &p) in hist.iter().enumerate() {
+    if p > 0.0 {
+      let l = level(i) as usize;
+      div += p * (p * count[l] as f64 / mass[l]).ln();
+    }
+  }
+  div as f32
+}
+
+/// Chooses a scale for quantizing `data` to F8 which minimizes the mean squared error.
+pub fn calibrate(data: &[f32]) -> Calibration { calibrate_with(data, Objective::Mse) }
+
+/// Chooses a scale for quantizing `data` to F8 by sweeping candidates below the
+/// largest magnitude and keeping the one which minimizes `objective`.
+pub fn calibrate_with(data: &[f32], objective: Objective) -> Calibration {
+  // non-finite values have no scale which represents them, so are left out of every step
+  let data: Vec<f32> = data.iter().copied().filter(|v| v.is_finite()).collect();
+  let amax = data.iter().fold(0f32, |m, v| m.max(v.abs()));
+  let mut hist = vec![];
+  if objective == Objective::Kl && amax > 0.0 {
+    hist = vec![0f64; KL_BINS];
+    let n = data.len() as f64;
+    for &v in &data {
+      hist[kl_bin(v.abs(), amax)] += 1.0 / n;
+    }
+  }
+  let mut best = Calibration {
+    scale: absmax_scale(&data),
+    clip: amax,
+    objective,
+    error: f32::INFINITY,
+  };
+  for scale in candidates(&data) {
+    let error = match objective {
+      Objective::Mse => mse(&data, scale),
+      Objective::Kl if amax > 0.0 => kl(&hist, amax, scale),
+      Objective::Kl => 0.0,
+    };
+    if error < best.error {
+      best = Calibration {
+        scale,
+        clip: scale * F8::MAX.v(),
+        objective,
+        error,
+      };
+    }
+  }
+  best
+}
@@ -0,0 +1,90 @@
+use crate::{e8m0::E8M0, f8::F8, linalg, scaled::absmax_scale};
+
+/// A scale factor which can be stored alongside quantized data
+pub trait Scale: Copy {
+  /// A scale for which `absmax / scale` does not exceed `F8::MAX`
+  fn for_absmax(absmax: f32) -> Self;
+  fn to_f32(self) -> f32;
+}
+
+impl Scale for f32 {
+  fn for_absmax(absmax: f32) -> Self { absmax_scale(&[absmax]) }
+  fn to_f32(self) -> f32 { self }
+}
+
+impl Scale for E8M0 {
+  fn for_absmax(absmax: f32) -> Self { E8M0::from_f32_ceil(absmax_scale(&[absmax])) }
+  fn to_f32(self) -> f32 { E8M0::to_f32(self) }
+}
+
+/// A row major matrix quantized with one scale per row (output channel)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerChannelF8<S = f32> {
+  pub rows: usize,
+  pub cols: usize,
+  pub scales: Vec<S>,
+  pub data: Vec<F8>,
+}
+
+impl<S: Scale> PerChannelF8<S> {
+  /// Quantizes a `rows x cols` row major matrix, scaling each row by its largest magnitude
+  pub fn quantize(w: &[f32], rows: usize, cols: usize) -> Self {
+    assert_eq!(w.len(), rows * cols, "w is not rows x cols");
+    let mut scales = Vec::with_capacity(rows);
+    let mut data = Vec::with_capacity(w.len());
+    for row in w.chunks_exact(cols.max(1)).take(rows) {
+      let amax = row.iter().fold(0f32, |m, v| m.max(v.abs()));
+      let scale = S::for_absmax(amax);
+      let inv = 1.0 / scale.to_f32();
+      data.extend(row.iter().map(|&v| F8::approx_from(v * inv)));
+      scales.push(scale);
+    }
+    PerChannelF8 {
+      rows,
+      cols,
+      scales,
+      data,
+    }
+  }
+  /// Quantized values of row `r`
+  pub fn row(&self, r: usize) -> &[F8] { &self.data[r * self.cols..(r + 1) * self.cols] }
+  pub fn dequantize(&self) -> Vec<f32> {
+    let mut out = vec![0.0; self.data.len()];
+    self.dequantize_into(&mut out);
+    out
+  }
+  pub fn dequantize_into(&self, out: &mut [f32]) {
+    assert_eq!(out.len(), self.data.len(), "Mismatched lengths");
+    for (r, out_row) in out
+      .chunks_exact_mut(self.cols.max(1))
+      .take(self.rows)
+      .enumerate()
+    {
+      let s = self.scales[r].to_f32();
+      for (o, &f) in out_row.iter_mut().zip(self.row(r)) {
+        *o = f.v() * s;
+      }
+    }
+  }
+  /// Computes `out = x * self^T`, where `x` is `batch x cols` and `out` is `batch x rows`,
+  /// applying each row's scale once per output.
+  pub fn matmul(&self, x: &[f32], batch: usize, out: &mut [f32]) {
+    assert_eq!(x.len(), batch * self.cols, "x is not batch x cols");
+    assert_eq!(out.len(), batch * self.rows, "out is not batch x rows");
+    let rows = out.chunks_exact_mut(self.rows.max(1));
+    for (xr, out_row) in x.chunks_exact(self.cols.max(1)).zip(rows) {
+      for (r, o) in out_row.iter_mut().enumerate() {
+        let acc: f32 = self.row(r).iter().zip(xr).map(|(&w, &x)| w.v() * x).sum();
+        *o = acc * self.scales[r].to_f32();
+      }
+    }
+  }
+  /// Computes `out = self * x` for a vector `x` of F8 quantized with `x_scale`.
+  pub fn matvec_f8(&self, x: &[F8], x_scale: f32, out: &mut [f32]) {
+    assert_eq!(x.len(), self.cols, "x does not have cols elements");
+    assert_eq!(out.len(), self.rows, "out does not have rows elements");
+    for (r, o) in out.iter_mut().enumerate() {
+      *o = linalg::dot(self.row(r), x) * self.scales[r].to_f32() * x_scale;
+    }
+  }
+}
@@ -0,0 +1,50 @@
+//! Fast checksums of F8 data, for checking copies of a tensor match, not for security.
+
+use crate::{f8::F8, quantize::splitmix64};
+
+/// A checksum of the multiset of bit patterns in `data`, the same for any permutation of it.
+///
+/// Each element is hashed independently and the hashes summed, so it can also be computed in
+/// parts which are combined with `fingerprint_combine`.
+pub fn fingerprint(data: &[F8]) -> u64 { fingerprint_finish(fingerprint_partial(data), data.len()) }
+
+/// The unfinished sum of element hashes of `fingerprint`
+pub fn fingerprint_partial(data: &[F8]) -> u64 {
+  let mut counts = [0u64; 256];
+  for f in data {
+    counts[f.to_bits() as usize] += 1;
+  }
+  counts.iter().enumerate().fold(0u64, |acc, (b, &c)| {
+    acc.wrapping_add(c.wrapping_mul(splitmix64(b as u64)))
+  })
+}
+
+/// Combines the partial sums of disjoint parts, as summed by `fingerprint_partial`
+pub fn fingerprint_combine(a: u64, b: u64) -> u64 { a.wrapping_add(b) }
+
+/// Finishes a partial sum over `len` elements in total, giving `fingerprint`
+pub fn fingerprint_finish(partial: u64, len: usize) -> u64 {
+  splitmix64(partial ^ splitmix64(len as u64))
+}
+
+/// A checksum of the bit patterns of `data` in order
+pub fn fingerprint_ordered(data: &[F8]) -> u64 {
+  let chunks = data.chunks_exact(8);
+  let mut tail = [0u8; 8];
+  for (t, f) in tail.iter_mut().zip(chunks.remainder()) {
+    *t = f.to_bits();
+  }
+  let words = chunks
+    .map(|c| {
+      let mut w = [0u8; 8];
+      for (w, f) in w.iter_mut().zip(c) {
+        *w = f.to_bits();
+      }
+      w
+    })
+    .chain((!data.len().is_multiple_of(8)).then_some(tail));
+  let h = words.fold(splitmix64(data.len() as u64), |h, w| {
+    splitmix64(h ^ u64::from_le_bytes(w))
+  });
+  splitmix64(h)
+}
@@ -0,0 +1,103 @@
+use crate::f8::F8;
+
+/// A non-uniform quantizer mapping values to the index of their nearest centroid.
+/// Centroids are kept sorted, so indices preserve order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Codebook {
+  centroids: Vec<f32>,
+}
+
+impl Codebook {
+  /// Constructs a codebook from at most 256 centroids
+  ///
+  /// # Panics
+  /// If there are no centroids, more than 256, or any centroid is NaN
+  pub fn new(mut centroids: Vec<f32>) -> Self {
+    assert!(
+      !centroids.is_empty() && centroids.len() <= 256,
+      "Codebook must have 1..=256 entries"
+    );
+    centroids.sort_by(|a, b| a.partial_cmp(b).expect("NaN centroid"));
+    centroids.dedup();
+    Codebook { centroids }
+  }
+  /// Learns at most `k` centroids for `data` with Lloyd's algorithm (1-D k-means), starting
+  /// from evenly spaced quantiles and running at most `iters` iterations.
+  pub fn train(data: &[f32], k: usize, iters: usize) -> Self {
+    assert!((1..=256).contains(&k), "k must be in 1..=256");
+    let mut sorted: Vec<f32> = data.iter().copied().filter(|v| v.is_finite()).collect();
+    if sorted.is_empty() {
+      return Codebook::new(vec![0.0]);
+    }
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = sorted.len();
+    let mut prefix = Vec::with_capacity(n + 1);
+    prefix.push(0f64);
+    for &v in &sorted {
+      prefix.push(prefix.last().unwrap() + v as f64);
+    }
+    let mut centroids: Vec<f32> = (0..k).map(|i| sorted[(2 * i + 1) * n / (2 * k)]).collect();
+    centroids.dedup();
+    for _ in 0..iters {
+      let mut next = Vec::with_capacity(centroids.len());
+      let mut start = 0;
+      for (i, &c) in centroids.iter().enumerate() {
+        let end = match centroids.get(i + 1) {
+          Some(&d) => sorted.partition_point(|&v| v <= (c + d) / 2.0),
+          None => n,
+        };
+        if end > start {
+          next.push(((prefix[end] - prefix[start]) / (end - start) as f64) as f32);
+        } else {
+          next.push(c);
+        }
+        start = end;
+      }
+      next.dedup();
+      if next == centroids {
+        break;
+      }
+      centroids = next;
+    }
+    Codebook::new(centroids)
+  }
+  pub fn centroids(&self) -> &[f32] { &self.centroids }
+  pub fn len(&self) -> usize { self.centroids.len() }
+  pub fn is_empty(&self) -> bool { self.centroids.is_empty() }
+  /// Rounds each centroid to the nearest F8, so the codebook itself can be stored as F8
+  pub fn snap_to_f8(&self) -> Codebook {
+    Codebook::new(
+      self
+        .centroids
+        .iter()
+        .map(|&c| F8::approx_from(c).v())
+        .collect(),
+    )
+  }
+  /// The centroids as F8, exact if this codebook was produced by `snap_to_f8`
+  pub fn to_f8(&self) -> Vec<F8> { self.centroids.iter().map(|&c| F8::approx_from(c)).collect() }
+  /// Index of the centroid nearest to `v`
+  pub fn encode_one(&self, v: f32) -> u8 {
+    let c = &self.centroids;
+    let i = c.partition_point(|&x| x < v);
+    let prev_closer = i == c.len() || (i > 0 && v - c[i - 1] <= c[i] - v);
+    if prev_closer {
+      (i - 1) as u8
+    } else {
+      i as u8
+    }
+  }
+  pub fn decode_one(&self, i: u8) -> f32 { self.centroids[i as usize] }
+  pub fn encode(&self, src: &[f32], dst: &mut [u8]) {
+    assert_eq!(src.len(), dst.len(), "Mismatched lengths");
+    for (d, &v) in dst.iter_mut().zip(src) {
+      *d = self.encode_one(v);
+    }
+  }
+  pub fn decode(&self, src: &[u8], dst: &mut [f32]) {
+    assert_eq!(src.len(), dst.len(), "Mismatched lengths");
+    for (d, &i) in dst.iter_mut().zip(src) {
+      *d = self.decode_one(i);
+    }
+  }
+}
@@ -0,0 +1,337 @@
+//! Lossless codecs for F8 data, which store it in fewer bytes or rearrange it so general
+//! purpose compressors such as zstd or deflate do better.
+
+use crate::f8::F8;
+use std::{
+  convert::{TryFrom, TryInto},
+  io,
+};
+
+fn invalid(msg: &str) -> io::Error { io::Error::new(io::ErrorKind::InvalidData, msg) }
+
+/// Reads a little endian u64 length from the start of `bytes`
+fn read_len(bytes: &[u8]) -> io::Result<(usize, &[u8])> {
+  if bytes.len() < 8 {
+    return Err(invalid("truncated length"));
+  }
+  let (len, rest) = bytes.split_at(8);
+  let len = u64::from_le_bytes(len.try_into().unwrap());
+  let len = usize::try_from(len).map_err(|_| invalid("length too large"))?;
+  Ok((len, rest))
+}
+
+/// Packs the low `width` bits of each value, least significant bit first
+fn pack_bits(values: impl Iterator<Item = u8>, width: u32, out: &mut Vec<u8>) {
+  let (mut acc, mut bits) = (0u32, 0);
+  for v in values {
+    acc |= (v as u32 & ((1 << width) - 1)) << bits;
+    bits += width;
+    while bits >= 8 {
+      out.push(acc as u8);
+      acc >>= 8;
+      bits -= 8;
+    }
+  }
+  if bits > 0 {
+    out.push(acc as u8);
+  }
+}
+
+/// The `i`th `width` bit value packed by `pack_bits`
+fn unpack_bits(plane: &[u8], i: usize, width: usize) -> u8 {
+  let (start, mut v) = (i * width, 0);
+  for b in 0..width {
+    let bit = start + b;
+    v |= ((plane[bit / 8] >> (bit % 8)) & 1) << b;
+  }
+  v
+}
+
+/// Splits `data` into its sign, exponent and significand bits, each packed densely into its
+/// own plane, as `len: u64 (LE) | signs | exponents | significands`.
+///
+/// The planes together take the same space as `data`, but each has far less entropy than
+/// the interleaved bytes.
+pub fn encode_planes(data: &[F8]) -> Vec<u8> {
+  let mut out = Vec::with_capacity(8 + data.len() + 3);
+  out.extend_from_slice(&(data.len() as u64).to_le_bytes());
+  pack_bits(data.iter().map(|f| f.to_bits() >> 7), 1, &mut out);
+  pack_bits(data.iter().map(|f| f.exponent()), 3, &mut out);
+  pack_bits(data.iter().map(|f| f.significand()), 4, &mut out);
+  out
+}
+
+/// Reverses `encode_planes`
+pub fn decode_planes(bytes: &[u8]) -> io::Result<Vec<F8>> {
+  let (n, rest) = read_len(bytes)?;
+  let sizes = [n.div_ceil(8), (3 * n).div_ceil(8), n.div_ceil(2)];
+  if rest.len() != sizes.iter().sum::<usize>() {
+    return Err(invalid("planes do not match length"));
+  }
+  let (signs, rest) = rest.split_at(sizes[0]);
+  let (exps, signifs) = rest.split_at(sizes[1]);
+  let out = (0..n)
+    .map(|i| {
+      let (s, e, m) = (
+        unpack_bits(signs, i, 1),
+        unpack_bits(exps, i, 3),
+        unpack_bits(signifs, i, 4),
+      );
+      F8::from_bits(s << 7 | e << 4 | m)
+    })
+    .collect();
+  Ok(out)
+}
+
+/// Range coder normalization bounds, with frequency totals at most `BOT`
+const TOP: u32 = 1 << 24;
+const BOT: u32 = 1 << 16;
+
+/// Literal bytes `1..=255`, then a run of `0x00` bytes of length in `2^k..2^(k+1)` for each
+/// `k < RUN_BUCKETS`, followed by its `k` low bits
+const RUN_BUCKETS: usize = 32;
+const SYMBOLS: usize = 256 + RUN_BUCKETS;
+
+/// An adaptive frequency model over `SYMBOLS`
+struct Model {
+  freq: Vec<u32>,
+  total: u32,
+}
+
+impl Model {
+  const STEP: u32 = 32;
+  fn new() -> Self {
+    Model {
+      freq: vec![1; SYMBOLS],
+      total: SYMBOLS as u32,
+    }
+  }
+  fn cum(&self, sym: usize) -> u32 { self.freq[..sym].iter().sum() }
+  /// The symbol whose cumulative range contains `target`, and the start of its range
+  fn find(&self, target: u32) -> (usize, u32) {
+    let mut cum = 0;
+    for (sym, &f) in self.freq.iter().enumerate() {
+      if target < cum + f {
+        return (sym, cum);
+      }
+      cum += f;
+    }
+    unreachable!("target beyond total")
+  }
+  fn update(&mut self, sym: usize) {
+    self.freq[sym] += Self::STEP;
+    self.total += Self::STEP;
+    if self.total > BOT {
+      self.freq.iter_mut().for_each(|f| *f = f.div_ceil(2));
+      self.total = self.freq.iter().sum();
+    }
+  }
+}
+
+/// A carryless range coder, after Subbotin
+struct Encoder {
+  low: u32,
+  range: u32,
+  out: Vec<u8>,
+}
+
+impl Encoder {
+  fn encode(&mut self, cum: u32, freq: u32, total: u32) {
+    self.range /= total;
+    self.low = self.low.wrapping_add(cum * self.range);
+    self.range *= freq;
+    loop {
+      if (self.low ^ self.low.wrapping_add(self.range)) >= TOP {
+        if self.range >= BOT {
+          break;
+        }
+        self.range = self.low.wrapping_neg() & (BOT - 1);
+      }
+      self.out.push((self.low >> 24) as u8);
+      self.low <<= 8;
+      self.range <<= 8;
+    }
+  }
+  fn symbol(&mut self, m: &mut Model, sym: usize) {
+    self.encode(m.cum(sym), m.freq[sym], m.total);
+    m.update(sym);
+  }
+  fn bits(&mut self, mut v: u32, mut k: u32) {
+    while k > 0 {
+      let b = k.min(16);
+      self.encode(v & ((1 << b) - 1), 1, 1 << b);
+      v >>= b;
+      k -= b;
+    }
+  }
+  fn finish(mut self) -> Vec<u8> {
+    for _ in 0..4 {
+      self.out.push((self.low >> 24) as u8);
+      self.low <<= 8;
+    }
+    self.out
+  }
+}
+
+struct Decoder<'a> {
+  low: u32,
+  range: u32,
+  code: u32,
+  input: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+  fn new(input: &'a [u8]) -> Self {
+    let mut d = Decoder {
+      low: 0,
+      range: u32::MAX,
+      code: 0,
+      input,
+    };
+    for _ in 0..4 {
+      d.code = d.code << 8 | d.next() as u32;
+    }
+    d
+  }
+  /// The next byte, or 0 past the end
+  fn next(&mut self) -> u8 {
+    let (&b, rest) = self.input.split_first().unwrap_or((&0, &[]));
+    self.input = rest;
+    b
+  }
+  fn target(&mut self, total: u32) -> u32 {
+    self.range /= total;
+    (self.code.wrapping_sub(self.low) / self.range).min(total - 1)
+  }
+  fn decode(&mut self, cum: u32, freq: u32) {
+    self.low = self.low.wrapping_add(cum * self.range);
+    self.range *= freq;
+    loop {
+      if (self.low ^ self.low.wrapping_add(self.range)) >= TOP {
+        if self.range >= BOT {
+          break;
+        }
+        self.range = self.low.wrapping_neg() & (BOT - 1);
+      }
+      self.code = self.code << 8 | self.next() as u32;
+      self.low <<= 8;
+      self.range <<= 8;
+    }
+  }
+  fn symbol(&mut self, m: &mut Model) -> usize {
+    let (sym, cum) = m.find(self.target(m.total));
+    self.decode(cum, m.freq[sym]);
+    m.update(sym);
+    sym
+  }
+  fn bits(&mut self, k: u32) -> u32 {
+    let (mut v, mut shift) = (0, 0);
+    while shift < k {
+      let b = (k - shift).min(16);
+      let part = self.target(1 << b);
+      self.decode(part, 1);
+      v |= part << shift;
+      shift += b;
+    }
+    v
+  }
+}
+
+/// Compresses `data` as runs of zero bytes and literals, entropy coded by an adaptive range
+/// coder, as `len: u64 (LE) | coded symbols`.
+///
+/// Only the `0x00` encoding of zero is run length coded, so every bit pattern round trips.
+pub fn compress(data: &[F8]) -> Vec<u8> {
+  let mut enc = Encoder {
+    low: 0,
+    range: u32::MAX,
+    out: (data.len() as u64).to_le_bytes().to_vec(),
+  };
+  let mut m = Model::new();
+  let mut i = 0;
+  while i < data.len() {
+    let b = data[i].to_bits();
+    if b != 0 {
+      enc.symbol(&mut m, b as usize);
+      i += 1;
+      continue;
+    }
+    let run = data[i..]
+      .iter()
+      .take(u32::MAX as usize)
+      .take_while(|f| f.to_bits() == 0)
+      .count() as u32;
+    let k = 31 - run.leading_zeros();
+    enc.symbol(&mut m, 256 + k as usize);
+    enc.bits(run - (1 << k), k);
+    i += run as usize;
+  }
+  enc.finish()
+}
+
+/// Reverses `compress`, failing where corruption yields an impossible stream
+pub fn decompress(bytes: &[u8]) -> io::Result<Vec<F8>> {
+  let (n, rest) = read_len(bytes)?;
+  let mut dec = Decoder::new(rest);
+  let mut m = Model::new();
+  let mut out = Vec::with_capacity(n.min(rest.len().saturating_mul(64)));
+  while out.len() < n {
+    match dec.symbol(&mut m) {
+      0 => return Err(invalid("literal zero")),
+      b @ 1..=255 => out.push(F8::from_bits(b as u8)),
+      run => {
+        let k = (run - 256) as u32;
+        let len = ((1u64 << k) + dec.bits(k) as u64) as usize;
+        if len > n - out.len() {
+          return Err(invalid("run beyond length"));
+        }
+        out.resize(out.len() + len, F8::from_bits(0));
+      },
+    }
+  }
+  Ok(out)
+}
+
+/// The position of each bit pattern in `F8::total_cmp` order, and its inverse
+fn total_order_tables() -> ([u8; 256], [u8; 256]) {
+  let mut patterns: Vec<F8> = F8::iter_all().collect();
+  patterns.sort_unstable_by(F8::total_cmp);
+  let (mut key, mut pattern) = ([0; 256], [0; 256]);
+  for (i, f) in patterns.iter().enumerate() {
+    key[f.to_bits() as usize] = i as u8;
+    pattern[i] = f.to_bits();
+  }
+  (key, pattern)
+}
+
+/// The differences between the `F8::total_cmp` positions of consecutive elements, wrapping,
+/// where the first is relative to `+0`.
+///
+/// Slowly varying data becomes mostly small differences, which compress much better than the
+/// samples themselves, and every bit pattern round trips.
+pub fn delta_encode(data: &[F8]) -> Vec<u8> {
+  let (key, _) = total_order_tables();
+  let mut prev = key[0];
+  data
+    .iter()
+    .map(|f| {
+      let k = key[f.to_bits() as usize];
+      let d = k.wrapping_sub(prev);
+      prev = k;
+      d
+    })
+    .collect()
+}
+
+/// Reverses `delta_encode`
+pub fn delta_decode(deltas: &[u8]) -> Vec<F8> {
+  let (key, pattern) = total_order_tables();
+  let mut k = key[0];
+  deltas
+    .iter()
+    .map(|&d| {
+      k = k.wrapping_add(d);
+      F8::from_bits(pattern[k as usize])
+    })
+    .collect()
+}
@@ -0,0 +1,123 @@
+//! Small linear color types, mainly for F8 channels.
+//!
+//! Arithmetic on F8 colors decodes each channel to f32 and rounds the result once. Note F8 alpha
+//! only has steps of a quarter in [0, 1].
+
+use crate::f8::F8;
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Hash)]
+#[repr(C)]
+pub struct Rgb<T> {
+  pub r: T,
+  pub g: T,
+  pub b: T,
+}
+
+/// A color with alpha, which is straight (not premultiplied) unless stated otherwise
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default, Hash)]
+#[repr(C)]
+pub struct Rgba<T> {
+  pub r: T,
+  pub g: T,
+  pub b: T,
+  pub a: T,
+}
+
+impl<T> Rgb<T> {
+  pub const fn new(r: T, g: T, b: T) -> Self { Rgb { r, g, b } }
+  pub fn map<U>(self, f: impl Fn(T) -> U) -> Rgb<U> { Rgb::new(f(self.r), f(self.g), f(self.b)) }
+  /// Adds an alpha channel
+  pub fn with_alpha(self, a: T) -> Rgba<T> {
+    Rgba {
+      r: self.r,
+      g: self.g,
+      b: self.b,
+      a,
+    }
+  }
+}
+
+impl<T> Rgba<T> {
+  pub const fn new(r: T, g: T, b: T, a: T) -> Self { Rgba { r, g, b, a } }
+  pub fn map<U>(self, f: impl Fn(T) -> U) -> Rgba<U> {
+    Rgba::new(f(self.r), f(self.g), f(self.b), f(self.a))
+  }
+  /// Drops the alpha channel
+  pub fn rgb(self) -> Rgb<T> { Rgb::new(self.r, self.g, self.b) }
+}
+
+impl From<[f32; 3]> for Rgb<F8> {
+  fn from([r, g, b]: [f32; 3]) -> Self { Rgb::new(r, g, b).map(F8::approx_from) }
+}
+
+impl From<Rgb<F8>> for [f32; 3] {
+  fn from(c: Rgb<F8>) -> Self { [c.r.v(), c.g.v(), c.b.v()] }
+}
+
+impl From<[f32; 4]> for Rgba<F8> {
+  fn from([r, g, b, a]: [f32; 4]) -> Self { Rgba::new(r, g, b, a).map(F8::approx_from) }
+}
+
+impl From<Rgba<F8>> for [f32; 4] {
+  fn from(c: Rgba<F8>) -> Self { [c.r.v(), c.g.v(), c.b.v(), c.a.v()] }
+}
+
+macro_rules! impl_ops {
+  ($t: ident, $($c: ident),+) => {
+    impl Add for $t<F8> {
+      type Output = Self;
+      fn add(self, o: Self) -> Self { $t { $($c: F8::approx_from(self.$c.v() + o.$c.v())),+ } }
+    }
+    impl Sub for $t<F8> {
+      type Output = Self;
+      fn sub(self, o: Self) -> Self { $t { $($c: F8::approx_from(self.$c.v() - o.$c.v())),+ } }
+    }
+    /// Component-wise product
+    impl Mul for $t<F8> {
+      type Output = Self;
+      fn mul(self, o: Self) -> Self { $t { $($c: F8::approx_from(self.$c.v() * o.$c.v())),+ } }
+    }
+    impl Mul<f32> for $t<F8> {
+      type Output = Self;
+      fn mul(self, s: f32) -> Self { self.map(|c| F8::approx_from(c.v() * s)) }
+    }
+    impl $t<F8> {
+      /// Linear interpolation from `self` at `t = 0` to `o` at `t = 1`
+      pub fn lerp(self, o: Self, t: f32) -> Self {
+        $t { $($c: F8::approx_from(self.$c.v() + (o.$c.v() - self.$c.v()) * t)),+ }
+      }
+    }
+  };
+}
+
+impl_ops!(Rgb, r, g, b);
+impl_ops!(Rgba, r, g, b, a);
+
+impl Rgba<F8> {
+  /// Multiplies the color channels by alpha
+  pub fn premultiply(self) -> Self {
+    let a = self.a.v();
+    let c = self.rgb().map(|c| F8::approx_from(c.v() * a));
+    c.with_alpha(self.a)
+  }
+  /// Divides the color channels by alpha, giving black for zero alpha
+  pub fn unpremultiply(self) -> Self {
+    let a = self.a.v();
+    if a == 0.0 {
+      return Rgba::new(F8::from_bits(0), F8::from_bits(0), F8::from_bits(0), self.a);
+    }
+    let c = self.rgb().map(|c| F8::approx_from(c.v() / a));
+    c.with_alpha(self.a)
+  }
+  /// Composites premultiplied `self` over premultiplied `dst`
+  pub fn over(self, dst: Self) -> Self {
+    let k = 1.0 - self.a.v();
+    Rgba {
+      r: F8::approx_from(self.r.v() + dst.r.v() * k),
+      g: F8::approx_from(self.g.v() + dst.g.v() * k),
+      b: F8::approx_from(self.b.v() + dst.b.v() * k),
+      a: F8::approx_from(self.a.v() + dst.a.v() * k),
+    }
+  }
+}
@@ -0,0 +1,126 @@
+//! ITU-T G.711 mu-law and A-law codecs, for comparing F8 against traditional 8 bit audio
+//! companding.
+//!
+//! Samples are f32 in [-1, 1], mapped onto 16 bit PCM as the codecs are defined.
+
+use crate::{f8::F8, scaled::absmax_scale};
+
+const MU_BIAS: i32 = 0x84;
+const MU_CLIP: i32 = 32635;
+
+fn to_pcm(v: f32) -> i16 {
+  if v.is_nan() {
+    return 0;
+  }
+  (v * 32768.0).round().clamp(-32768.0, 32767.0) as i16
+}
+
+fn from_pcm(p: i16) -> f32 { p as f32 / 32768.0 }
+
+/// An 8 bit G.711 mu-law sample
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct MuLaw(pub u8);
+
+impl MuLaw {
+  pub fn from_pcm(pcm: i16) -> Self {
+    let sign = if pcm < 0 { 0x80 } else { 0 };
+    let mag = (pcm as i32).abs().min(MU_CLIP) + MU_BIAS;
+    // position of the leading bit past bit 7, in [0, 7]
+    let exp = (31 - mag.leading_zeros() as i32 - 7).clamp(0, 7);
+    let mantissa = (mag >> (exp + 3)) & 0x0F;
+    MuLaw(!(sign | (exp << 4) as u8 | mantissa as u8))
+  }
+  pub fn to_pcm(self) -> i16 {
+    let u = !self.0;
+    let t = ((((u & 0x0F) as i32) << 3) + MU_BIAS) << ((u & 0x70) >> 4);
+    (if u & 0x80 != 0 {
+      MU_BIAS - t
+    } else {
+      t - MU_BIAS
+    }) as i16
+  }
+  pub fn from_f32(v: f32) -> Self { Self::from_pcm(to_pcm(v)) }
+  pub fn to_f32(self) -> f32 { from_pcm(self.to_pcm()) }
+  /// Converts an F8 sample representing `scale * f` to mu-law
+  pub fn from_f8(f: F8, scale: f32) -> Self { Self::from_f32(f.v() * scale) }
+  /// Converts to the nearest F8 with the given scale
+  pub fn to_f8(self, scale: f32) -> F8 { F8::approx_from(self.to_f32() / scale) }
+}
+
+/// Upper bounds of each A-law segment, on 13 bit magnitudes
+const A_SEGMENT_END: [i32; 8] = [0x1F, 0x3F, 0x7F, 0xFF, 0x1FF, 0x3FF, 0x7FF, 0xFFF];
+
+/// An 8 bit G.711 A-law sample
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ALaw(pub u8);
+
+impl ALaw {
+  pub fn from_pcm(pcm: i16) -> Self {
+    let p = pcm as i32 >> 3;
+    let (mask, mag) = if p >= 0 { (0xD5, p) } else { (0x55, -p - 1) };
+    let seg = A_SEGMENT_END.iter().position(|&end| mag <= end);
+    let a = match seg {
+      None => 0x7F,
+      Some(seg) => {
+        let shift = if seg < 2 { 1 } else { seg };
+        ((seg << 4) as i32 | ((mag >> shift) & 0x0F)) as u8
+      },
+    };
+    ALaw(a ^ mask)
+  }
+  pub fn to_pcm(self) -> i16 {
+    let a = self.0 ^ 0x55;
+    let mut t = ((a & 0x0F) as i32) << 4;
+    match (a & 0x70) >> 4 {
+      0 => t += 8,
+      1 => t += 0x108,
+      seg => t = (t + 0x108) << (seg - 1),
+    }
+    (if a & 0x80 != 0 { t } else { -t }) as i16
+  }
+  pub fn from_f32(v: f32) -> Self { Self::from_pcm(to_pcm(v)) }
+  pub fn to_f32(self) -> f32 { from_pcm(self.to_pcm()) }
+  /// Converts an F8 sample representing `scale * f` to A-law
+  pub fn from_f8(f: F8, scale: f32) -> Self { Self::from_f32(f.v() * scale) }
+  /// Converts to the nearest F8 with the given scale
+  pub fn to_f8(self, scale: f32) -> F8 { F8::approx_from(self.to_f32() / scale) }
+}
+
+/// Signal to noise ratio in decibels of each codec over the same material
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CompandingReport {
+  /// F8 with one absmax scale over all samples
+  pub f8_snr_db: f32,
+  pub mulaw_snr_db: f32,
+  pub alaw_snr_db: f32,
+}
+
+fn snr_db(src: &[f32], approx: impl Iterator<Item = f32>) -> f32 {
+  let (mut signal, mut noise) = (0f64, 0f64);
+  for (&s, a) in src.iter().zip(approx) {
+    signal += (s as f64).powi(2);
+    noise += (s as f64 - a as f64).powi(2);
+  }
+  (10.0 * (signal / noise).log10()) as f32
+}
+
+/// Encodes `samples` with each codec and measures the resulting signal to noise ratios.
+/// Samples outside [-1, 1] clip for mu-law and A-law.
+pub fn compare(samples: &[f32]) -> CompandingReport {
+  let scale = absmax_scale(samples);
+  CompandingReport {
+    f8_snr_db: snr_db(
+      samples,
+      samples
+        .iter()
+        .map(|&v| F8::approx_from(v / scale).v() * scale),
+    ),
+    mulaw_snr_db: snr_db(
+      samples,
+      samples.iter().map(|&v| MuLaw::from_f32(v).to_f32()),
+    ),
+    alaw_snr_db: snr_db(samples, samples.iter().map(|&v| ALaw::from_f32(v).to_f32())),
+  }
+}
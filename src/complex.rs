@@ -0,0 +1,74 @@
+//! Complex numbers with F8 parts.
+//!
+//! `num_complex::Complex<F8>` would need F8 to implement `Num`, including division and
+//! remainder, so this is a dedicated type instead, laid out as `[re, im]`.
+
+use crate::f8::F8;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Neg, Sub};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ComplexF8 {
+  pub re: F8,
+  pub im: F8,
+}
+
+impl ComplexF8 {
+  pub fn new(re: F8, im: F8) -> Self { ComplexF8 { re, im } }
+  /// Rounds both parts to the nearest F8
+  pub fn from_f32(re: f32, im: f32) -> Self {
+    ComplexF8::new(F8::approx_from(re), F8::approx_from(im))
+  }
+  pub fn to_f32(self) -> (f32, f32) { (self.re.v(), self.im.v()) }
+  pub fn conj(self) -> Self { ComplexF8::new(self.re, -self.im) }
+  /// `re^2 + im^2`, exactly
+  pub fn norm_sqr(self) -> f32 { self.re.v() * self.re.v() + self.im.v() * self.im.v() }
+  #[cfg(any(feature = "std", feature = "libm"))]
+  pub fn norm(self) -> f32 { crate::math::sqrt(self.norm_sqr()) }
+  /// Scales both parts by `s`, rounding once
+  pub fn scale(self, s: f32) -> Self { ComplexF8::from_f32(self.re.v() * s, self.im.v() * s) }
+}
+
+impl Add for ComplexF8 {
+  type Output = Self;
+  fn add(self, o: Self) -> Self {
+    ComplexF8::from_f32(self.re.v() + o.re.v(), self.im.v() + o.im.v())
+  }
+}
+
+impl Sub for ComplexF8 {
+  type Output = Self;
+  fn sub(self, o: Self) -> Self { self + -o }
+}
+
+/// Both parts of the product are computed exactly in f32, and rounded once
+impl Mul for ComplexF8 {
+  type Output = Self;
+  fn mul(self, o: Self) -> Self {
+    let ((a, b), (c, d)) = (self.to_f32(), o.to_f32());
+    ComplexF8::from_f32(a * c - b * d, a * d + b * c)
+  }
+}
+
+impl Neg for ComplexF8 {
+  type Output = Self;
+  fn neg(self) -> Self { ComplexF8::new(-self.re, -self.im) }
+}
+
+/// Rounds interleaved `[re, im, re, im, ..]` f32 data
+#[cfg(feature = "alloc")]
+pub fn from_interleaved(src: &[f32]) -> Vec<ComplexF8> {
+  assert!(src.len().is_multiple_of(2), "Odd number of parts");
+  src
+    .chunks_exact(2)
+    .map(|c| ComplexF8::from_f32(c[0], c[1]))
+    .collect()
+}
+
+/// Widens to interleaved `[re, im, re, im, ..]` f32 data
+#[cfg(feature = "alloc")]
+pub fn to_interleaved(src: &[ComplexF8]) -> Vec<f32> {
+  src.iter().flat_map(|c| [c.re.v(), c.im.v()]).collect()
+}
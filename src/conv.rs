@@ -0,0 +1,119 @@
+use crate::f8::{dec, F8};
+
+/// Shape of a 2-D convolution over `CHW` F8 input with `[out][in][kh][kw]` F8 weights,
+/// accumulated in f32 directly from the input without an im2col buffer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Conv2d {
+  pub in_channels: usize,
+  pub out_channels: usize,
+  pub kernel: (usize, usize),
+  pub stride: (usize, usize),
+  pub padding: (usize, usize),
+}
+
+impl Conv2d {
+  /// A convolution with stride 1 and no padding
+  pub fn new(in_channels: usize, out_channels: usize, kernel: (usize, usize)) -> Self {
+    Conv2d {
+      in_channels,
+      out_channels,
+      kernel,
+      stride: (1, 1),
+      padding: (0, 0),
+    }
+  }
+  /// Height and width of the output for an input of height `h` and width `w`, zero along
+  /// a dimension where the kernel is larger than the padded input
+  pub fn output_size(&self, h: usize, w: usize) -> (usize, usize) {
+    assert!(self.stride.0 > 0 && self.stride.1 > 0, "Stride is zero");
+    let dim =
+      |n: usize, k: usize, s: usize, p: usize| (n + 2 * p).checked_sub(k).map_or(0, |d| d / s + 1);
+    (
+      dim(h, self.kernel.0, self.stride.0, self.padding.0),
+      dim(w, self.kernel.1, self.stride.1, self.padding.1),
+    )
+  }
+  pub fn weight_len(&self) -> usize {
+    self.out_channels * self.in_channels * self.kernel.0 * self.kernel.1
+  }
+
+  /// Convolves a `in_channels x h x w` input, writing the `out_channels x oh x ow` result.
+  pub fn forward(
+    &self,
+    input: &[F8],
+    (h, w): (usize, usize),
+    weights: &[F8],
+    bias: Option<&[f32]>,
+    out: &mut [f32],
+  ) {
+    let (kh, kw) = self.kernel;
+    let (oh, ow) = self.output_size(h, w);
+    assert_eq!(
+      input.len(),
+      self.in_channels * h * w,
+      "input is not in_channels x h x w"
+    );
+    assert_eq!(
+      weights.len(),
+      self.weight_len(),
+      "weights do not match the kernel shape"
+    );
+    assert_eq!(
+      out.len(),
+      self.out_channels * oh * ow,
+      "out is not out_channels x oh x ow"
+    );
+    if let Some(b) = bias {
+      assert_eq!(b.len(), self.out_channels, "bias is not out_channels long");
+    }
+    if oh * ow == 0 {
+      return;
+    }
+    for (oc, out_c) in out.chunks_exact_mut(oh * ow).enumerate() {
+      out_c
+        .iter_mut()
+        .for_each(|o| *o = bias.map_or(0.0, |b| b[oc]));
+      for ic in 0..self.in_channels {
+        let plane = &input[ic * h * w..(ic + 1) * h * w];
+        let kernel = &weights[(oc * self.in_channels + ic) * kh * kw..][..kh * kw];
+        for (ky, k_row) in kernel.chunks_exact(kw).enumerate() {
+          for (kx, &k) in k_row.iter().enumerate() {
+            let k = dec(k);
+            if k == 0.0 {
+              continue;
+            }
+            for y in 0..oh {
+              let iy = (y * self.stride.0 + ky).wrapping_sub(self.padding.0);
+              if iy >= h {
+                continue;
+              }
+              let row = &plane[iy * w..(iy + 1) * w];
+              for (x, o) in out_c[y * ow..(y + 1) * ow].iter_mut().enumerate() {
+                let ix = (x * self.stride.1 + kx).wrapping_sub(self.padding.1);
+                if ix < w {
+                  *o += k * dec(row[ix]);
+                }
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+  /// Like `forward`, requantizing the output to F8 after multiplying by `out_scale`
+  pub fn forward_f8(
+    &self,
+    input: &[F8],
+    hw: (usize, usize),
+    weights: &[F8],
+    bias: Option<&[f32]>,
+    out_scale: f32,
+    out: &mut [F8],
+  ) {
+    let mut acc = vec![0.0; out.len()];
+    self.forward(input, hw, weights, bias, &mut acc);
+    for (o, &a) in out.iter_mut().zip(&acc) {
+      *o = F8::approx_from(a * out_scale);
+    }
+  }
+}
@@ -0,0 +1,146 @@
+//! D8, an 8 bit decimal float for human facing quantities such as prices and percentages,
+//! where values like 0.1 should be exact.
+
+use crate::minifloat::Minifloat;
+use core::fmt;
+
+/// 8 bit decimal float
+/// Repr: 1(sign) | 2(exp) | 5(significand)
+/// Magnitude = significand * 10^(exp - 2), so steps of 0.01 up to 0.31, 0.1 up to 3.1, 1 up
+/// to 31 and 10 up to 310. As with F8 there is no implicit digit, so some values have several
+/// encodings.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[repr(transparent)]
+pub struct D8(pub u8);
+
+const SIGN_MASK: u8 = 0b1000_0000;
+const EXP_SHIFT: u32 = 5;
+const SIGNIF_MASK: u8 = 0b0001_1111;
+/// The number of distinct magnitudes
+const DISTINCT: usize = 116;
+
+/// Every distinct magnitude in ascending order, each in its encoding with the smallest exponent
+const ASCENDING: [D8; DISTINCT] = ascending();
+
+const fn ascending() -> [D8; DISTINCT] {
+  let mut out = [D8(0); DISTINCT];
+  let mut i = 0;
+  while i < 32 {
+    out[i] = D8::new(0, 0, i as u8);
+    i += 1;
+  }
+  // each larger exponent adds the multiples of its step past the previous exponent's range
+  while i < DISTINCT {
+    let exp = (i - 32) / 28 + 1;
+    let signif = (i - 32) % 28 + 4;
+    out[i] = D8::new(0, exp as u8, signif as u8);
+    i += 1;
+  }
+  out
+}
+
+impl D8 {
+  pub const fn new(sign: u8, exp: u8, signif: u8) -> Self {
+    D8(sign << 7 | (exp & 0b11) << EXP_SHIFT | (signif & SIGNIF_MASK))
+  }
+  pub const MAX: D8 = D8::new(0, 0b11, 31);
+  pub const fn is_sign_negative(self) -> bool { self.0 & SIGN_MASK != 0 }
+  pub const fn exponent(self) -> u8 { (self.0 >> EXP_SHIFT) & 0b11 }
+  pub const fn significand(self) -> u8 { self.0 & SIGNIF_MASK }
+  /// The magnitude in hundredths, which is always an integer
+  pub const fn cents(self) -> u32 {
+    let mut c = self.significand() as u32;
+    let mut e = 0;
+    while e < self.exponent() {
+      c *= 10;
+      e += 1;
+    }
+    c
+  }
+  pub fn to_f32(self) -> f32 {
+    let v = (self.cents() as f64 / 100.0) as f32;
+    if self.is_sign_negative() {
+      -v
+    } else {
+      v
+    }
+  }
+  /// Rounds to the nearest D8 in decimal, ties to an even significand, saturating at
+  /// `D8::MAX`, with NaN as zero
+  pub fn from_f32(v: f32) -> Self {
+    if v.is_nan() {
+      return D8(0);
+    }
+    // exact, as f32 has 24 significant bits and 100 needs 7
+    let x = (v as f64).abs() * 100.0;
+    let i = ASCENDING.partition_point(|d| d.cents() as f64 <= x);
+    let mag = if i == DISTINCT {
+      D8::MAX
+    } else {
+      let (lo, hi) = (ASCENDING[i - 1], ASCENDING[i]);
+      let (dl, dh) = (x - lo.cents() as f64, hi.cents() as f64 - x);
+      if dl < dh || (dl == dh && lo.significand() % 2 == 0) {
+        lo
+      } else {
+        hi
+      }
+    };
+    D8(mag.0 | ((v.is_sign_negative() as u8) << 7))
+  }
+  /// Rounds the magnitude `n / scale` hundredths like `from_f32`, without leaving integers
+  fn from_cents_ratio(neg: bool, n: u64, scale: u64) -> Self {
+    let i = ASCENDING.partition_point(|d| d.cents() as u64 * scale <= n);
+    let mag = if i == DISTINCT {
+      D8::MAX
+    } else {
+      let (lo, hi) = (ASCENDING[i - 1], ASCENDING[i]);
+      let (dl, dh) = (n - lo.cents() as u64 * scale, hi.cents() as u64 * scale - n);
+      if dl < dh || (dl == dh && lo.significand() % 2 == 0) {
+        lo
+      } else {
+        hi
+      }
+    };
+    D8(mag.0 | ((neg as u8) << 7))
+  }
+  fn signed_cents(self) -> i64 {
+    let c = self.cents() as i64;
+    if self.is_sign_negative() {
+      -c
+    } else {
+      c
+    }
+  }
+}
+
+/// The exact decimal value, without trailing zeros
+impl fmt::Display for D8 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let sign = if self.is_sign_negative() { "-" } else { "" };
+    let c = self.cents();
+    match c % 100 {
+      0 => write!(f, "{}{}", sign, c / 100),
+      r if r % 10 == 0 => write!(f, "{}{}.{}", sign, c / 100, r / 10),
+      r => write!(f, "{}{}.{:02}", sign, c / 100, r),
+    }
+  }
+}
+
+impl Minifloat for D8 {
+  fn from_bits(bits: u8) -> Self { D8(bits) }
+  fn to_bits(self) -> u8 { self.0 }
+  fn to_f32(self) -> f32 { D8::to_f32(self) }
+  fn from_f32(f: f32) -> Self { D8::from_f32(f) }
+  /// Rounded once from the exact sum in hundredths, as decimal sums are not exact in f32
+  fn add(self, o: Self) -> Self {
+    let sum = self.signed_cents() + o.signed_cents();
+    // an exact zero is negative only if both operands are
+    let neg = sum < 0 || (sum == 0 && self.is_sign_negative() && o.is_sign_negative());
+    D8::from_cents_ratio(neg, sum.unsigned_abs(), 1)
+  }
+  /// Rounded once from the exact product in ten thousandths
+  fn mul(self, o: Self) -> Self {
+    let neg = self.is_sign_negative() != o.is_sign_negative();
+    D8::from_cents_ratio(neg, self.cents() as u64 * o.cents() as u64, 100)
+  }
+}
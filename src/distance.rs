@@ -0,0 +1,116 @@
+//! Similarity and distance kernels over F8 vectors for nearest neighbor search, decoding
+//! through `F8::DECODE_TABLE` as elements are accumulated in f32.
+
+use crate::f8::{dec, F8};
+
+/// The dot product and the squared lengths of `a` and `b`, in one pass
+pub(crate) fn dot_and_norms(a: &[F8], b: &[F8]) -> (f32, f32, f32) {
+  let (mut ab, mut aa, mut bb) = ([0f32; 4], [0f32; 4], [0f32; 4]);
+  let (ca, cb) = (a.chunks_exact(4), b.chunks_exact(4));
+  let mut tail = (0.0, 0.0, 0.0);
+  for (&x, &y) in ca.remainder().iter().zip(cb.remainder()) {
+    let (x, y) = (dec(x), dec(y));
+    tail = (tail.0 + x * y, tail.1 + x * x, tail.2 + y * y);
+  }
+  for (x, y) in ca.zip(cb) {
+    for i in 0..4 {
+      let (x, y) = (dec(x[i]), dec(y[i]));
+      ab[i] += x * y;
+      aa[i] += x * x;
+      bb[i] += y * y;
+    }
+  }
+  let sum = |v: [f32; 4]| v.iter().sum::<f32>();
+  (sum(ab) + tail.0, sum(aa) + tail.1, sum(bb) + tail.2)
+}
+
+/// The cosine of the angle between `a` and `b`, or 0 if either is zero
+pub fn cosine_similarity(a: &[F8], b: &[F8]) -> f32 {
+  assert_eq!(a.len(), b.len(), "Mismatched lengths");
+  let (ab, aa, bb) = dot_and_norms(a, b);
+  if aa == 0.0 || bb == 0.0 {
+    return 0.0;
+  }
+  (ab as f64 / (aa as f64 * bb as f64).sqrt()) as f32
+}
+
+/// The `cosine_similarity` of `query` with each row of the row major `rows`, which is
+/// `out.len() x query.len()`
+pub fn cosine_similarity_rows(query: &[F8], rows: &[F8], out: &mut [f32]) {
+  let dim = query.len();
+  assert_eq!(
+    rows.len(),
+    out.len() * dim,
+    "rows is not out.len() x query.len()"
+  );
+  if dim == 0 {
+    out.iter_mut().for_each(|o| *o = 0.0);
+    return;
+  }
+  for (o, row) in out.iter_mut().zip(rows.chunks_exact(dim)) {
+    *o = cosine_similarity(query, row);
+  }
+}
+
+/// The squared Euclidean distance between `a` and `b`.
+///
+/// With the `simd` feature on x86_64 the differences are accumulated with SSE.
+pub fn l2_distance_sq(a: &[F8], b: &[F8]) -> f32 {
+  assert_eq!(a.len(), b.len(), "Mismatched lengths");
+  let (ca, cb) = (a.chunks_exact(4), b.chunks_exact(4));
+  let tail: f32 = ca
+    .remainder()
+    .iter()
+    .zip(cb.remainder())
+    .map(|(&x, &y)| (dec(x) - dec(y)) * (dec(x) - dec(y)))
+    .sum();
+  let decode = |c: &[F8]| [dec(c[0]), dec(c[1]), dec(c[2]), dec(c[3])];
+  sum_sq_diff(ca.zip(cb).map(|(x, y)| (decode(x), decode(y)))) + tail
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+fn sum_sq_diff(chunks: impl Iterator<Item = ([f32; 4], [f32; 4])>) -> f32 {
+  let mut acc = [0f32; 4];
+  for (x, y) in chunks {
+    for i in 0..4 {
+      acc[i] += (x[i] - y[i]) * (x[i] - y[i]);
+    }
+  }
+  acc.iter().sum()
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+fn sum_sq_diff(chunks: impl Iterator<Item = ([f32; 4], [f32; 4])>) -> f32 {
+  use std::arch::x86_64::{
+    _mm_add_ps, _mm_loadu_ps, _mm_mul_ps, _mm_setzero_ps, _mm_storeu_ps, _mm_sub_ps,
+  };
+  let mut acc = [0f32; 4];
+  // SAFETY: SSE is part of the x86_64 baseline, and every load reads a whole array of 4
+  unsafe {
+    let mut v = _mm_setzero_ps();
+    for (x, y) in chunks {
+      let d = _mm_sub_ps(_mm_loadu_ps(x.as_ptr()), _mm_loadu_ps(y.as_ptr()));
+      v = _mm_add_ps(v, _mm_mul_ps(d, d));
+    }
+    _mm_storeu_ps(acc.as_mut_ptr(), v);
+  }
+  acc.iter().sum()
+}
+
+/// The `l2_distance_sq` of `query` to each row of the row major `rows`, which is
+/// `out.len() x query.len()`
+pub fn l2_distance_sq_rows(query: &[F8], rows: &[F8], out: &mut [f32]) {
+  let dim = query.len();
+  assert_eq!(
+    rows.len(),
+    out.len() * dim,
+    "rows is not out.len() x query.len()"
+  );
+  if dim == 0 {
+    out.iter_mut().for_each(|o| *o = 0.0);
+    return;
+  }
+  for (o, row) in out.iter_mut().zip(rows.chunks_exact(dim)) {
+    *o = l2_distance_sq(query, row);
+  }
+}
@@ -0,0 +1,79 @@
+//! Dual numbers over F8 for forward mode automatic differentiation.
+
+use crate::{
+  activation::{self, gelu_grad_f32, relu_grad_f32, silu_grad_f32},
+  f8::F8,
+};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A value and its derivative with respect to some input
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Dual<T> {
+  pub value: T,
+  pub deriv: T,
+}
+
+impl Dual<F8> {
+  /// A value which does not depend on the input
+  pub fn constant(value: F8) -> Self {
+    Dual {
+      value,
+      deriv: F8::from_bits(0),
+    }
+  }
+  /// The input being differentiated with respect to
+  pub fn variable(value: F8) -> Self {
+    Dual {
+      value,
+      deriv: F8::approx_from(1.0),
+    }
+  }
+  /// Applies `f` through its lookup table, scaling the derivative by `grad` at the input
+  fn chain(self, lut: &[F8; 256], grad: fn(f32) -> f32) -> Self {
+    Dual {
+      value: lut[self.value.to_bits() as usize],
+      deriv: F8::approx_from(grad(self.value.v()) * self.deriv.v()),
+    }
+  }
+  pub fn relu(self) -> Self { self.chain(activation::relu_lut(), relu_grad_f32) }
+  pub fn gelu(self) -> Self { self.chain(activation::gelu_lut(), gelu_grad_f32) }
+  pub fn silu(self) -> Self { self.chain(activation::silu_lut(), silu_grad_f32) }
+}
+
+// each part is computed in f32 and rounded once
+
+impl Add for Dual<F8> {
+  type Output = Self;
+  fn add(self, o: Self) -> Self {
+    Dual {
+      value: F8::approx_from(self.value.v() + o.value.v()),
+      deriv: F8::approx_from(self.deriv.v() + o.deriv.v()),
+    }
+  }
+}
+
+impl Sub for Dual<F8> {
+  type Output = Self;
+  fn sub(self, o: Self) -> Self { self + -o }
+}
+
+impl Mul for Dual<F8> {
+  type Output = Self;
+  fn mul(self, o: Self) -> Self {
+    let (a, b) = (self.value.v(), o.value.v());
+    Dual {
+      value: F8::approx_from(a * b),
+      deriv: F8::approx_from(self.deriv.v() * b + a * o.deriv.v()),
+    }
+  }
+}
+
+impl Neg for Dual<F8> {
+  type Output = Self;
+  fn neg(self) -> Self {
+    Dual {
+      value: -self.value,
+      deriv: -self.deriv,
+    }
+  }
+}
@@ -0,0 +1,41 @@
+/// 8 bit exponent-only scale as used by the OCP MX formats
+/// Value = 2^(bits - 127), with 0xFF reserved for NaN
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[repr(transparent)]
+pub struct E8M0(pub u8);
+
+impl E8M0 {
+  pub const ONE: E8M0 = E8M0(127);
+  pub const NAN: E8M0 = E8M0(0xFF);
+  /// Constructs the scale 2^exp, clamping exp to [-127, 127]
+  pub fn from_exp(exp: i32) -> Self { E8M0((exp.clamp(-127, 127) + 127) as u8) }
+  /// Power of two exponent of this scale
+  pub const fn exp(self) -> i32 { self.0 as i32 - 127 }
+  pub const fn is_nan(self) -> bool { self.0 == 0xFF }
+  /// Smallest representable power of two at least as large as `|f|`
+  pub fn from_f32_ceil(f: f32) -> Self {
+    if f.is_nan() {
+      return E8M0::NAN;
+    }
+    let bits = f.to_bits();
+    let exp = ((bits >> 23) & 0xFF) as i32 - 127;
+    let inexact = bits & 0x7F_FFFF != 0;
+    E8M0::from_exp(exp + inexact as i32)
+  }
+  pub fn to_f32(self) -> f32 {
+    match self.0 {
+      0xFF => f32::NAN,
+      // 2^-127 is only reachable as an f32 denormal
+      0 => f32::from_bits(1 << 22),
+      b => f32::from_bits((b as u32) << 23),
+    }
+  }
+}
+
+impl From<E8M0> for f32 {
+  fn from(e: E8M0) -> f32 { e.to_f32() }
+}
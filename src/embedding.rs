@@ -0,0 +1,43 @@
+use crate::channel::{PerChannelF8, Scale};
+
+/// An embedding table with rows stored as F8, each with its own scale
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddingTable<S = f32> {
+  table: PerChannelF8<S>,
+}
+
+impl<S: Scale> EmbeddingTable<S> {
+  /// Quantizes a row major `rows x dim` table of embeddings
+  pub fn from_f32(data: &[f32], rows: usize, dim: usize) -> Self {
+    EmbeddingTable {
+      table: PerChannelF8::quantize(data, rows, dim),
+    }
+  }
+  pub fn rows(&self) -> usize { self.table.rows }
+  pub fn dim(&self) -> usize { self.table.cols }
+  pub fn quantized(&self) -> &PerChannelF8<S> { &self.table }
+  /// Gathers and dequantizes the rows for `ids` into `out`, which is `ids.len() x dim`
+  pub fn lookup(&self, ids: &[u32], out: &mut [f32]) {
+    let dim = self.dim();
+    assert_eq!(out.len(), ids.len() * dim, "out is not ids.len() x dim");
+    for (&id, o) in ids.iter().zip(out.chunks_exact_mut(dim.max(1))) {
+      let id = id as usize;
+      let s = self.table.scales[id].to_f32();
+      for (o, f) in o.iter_mut().zip(self.table.row(id)) {
+        *o = f.v() * s;
+      }
+    }
+  }
+  /// Sums the rows for `ids` into `out`, which is `dim` long, as in an embedding bag
+  pub fn lookup_sum(&self, ids: &[u32], out: &mut [f32]) {
+    assert_eq!(out.len(), self.dim(), "out is not dim long");
+    out.iter_mut().for_each(|o| *o = 0.0);
+    for &id in ids {
+      let id = id as usize;
+      let s = self.table.scales[id].to_f32();
+      for (o, f) in out.iter_mut().zip(self.table.row(id)) {
+        *o += f.v() * s;
+      }
+    }
+  }
+}
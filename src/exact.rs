@@ -0,0 +1,55 @@
+//! Reference arithmetic which computes each result exactly as a `BigRational` and then rounds
+//! it once to the nearest F8, ties to even, saturating past `F8::MAX`.
+//!
+//! This is slow, and only meant as an oracle when checking faster implementations. Results
+//! are compared by value, so an exactly zero result is always positive zero.
+
+use crate::f8::{ASCENDING, F8};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, Zero};
+
+/// The exact value of an F8
+pub fn to_rational(f: F8) -> BigRational {
+  let s = f.significand() as i32;
+  let s = if f.is_sign_negative() { -s } else { s };
+  let e = f.exponent() as i32 - crate::f8::BIAS as i32;
+  let (n, d) = if e >= 0 { (s << e, 1) } else { (s, 1 << -e) };
+  BigRational::new(BigInt::from(n), BigInt::from(d))
+}
+
+/// Rounds to the nearest F8, ties to an even significand, saturating past `F8::MAX`
+pub fn round(x: &BigRational) -> F8 {
+  let a = x.abs();
+  let i = ASCENDING.partition_point(|f| to_rational(*f) <= a);
+  let mag = if i == ASCENDING.len() {
+    F8::MAX
+  } else {
+    let (lo, hi) = (ASCENDING[i - 1], ASCENDING[i]);
+    let below = &a - to_rational(lo);
+    let above = to_rational(hi) - &a;
+    if below < above || (below == above && lo.significand() % 2 == 0) {
+      lo
+    } else {
+      hi
+    }
+  };
+  if x.is_negative() {
+    -mag
+  } else {
+    mag
+  }
+}
+
+pub fn add(a: F8, b: F8) -> F8 { round(&(to_rational(a) + to_rational(b))) }
+pub fn sub(a: F8, b: F8) -> F8 { round(&(to_rational(a) - to_rational(b))) }
+pub fn mul(a: F8, b: F8) -> F8 { round(&(to_rational(a) * to_rational(b))) }
+
+/// `a / b` rounded once, or `None` when `b` is zero
+pub fn div(a: F8, b: F8) -> Option<F8> {
+  let b = to_rational(b);
+  if b.is_zero() {
+    return None;
+  }
+  Some(round(&(to_rational(a) / b)))
+}
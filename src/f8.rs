@@ -1,9 +1,9 @@
 #![allow(clippy::suspicious_arithmetic_impl)]
 
-use num_traits::{Float, One, Zero};
+use num_traits::{One, Zero};
 /// A fully self contained 8 bit float
-use std::ops::{Add, Mul, Neg, Sub};
-use std::{cmp::Ordering};
+use core::cmp::Ordering;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// How much is the exponent for an F8 biased by?
 /// Heavily favoring representing numbers closer to 0
@@ -14,27 +14,17 @@ pub const BIAS: u8 = 2;
 /// 1 = neg, 0 = pos | exp - BIAS | significand
 /// Magnitude = 2^(exp - BIAS) * significand
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[repr(transparent)]
 pub struct F8(u8);
 
 const SIGN_MASK: u8 = 0b1000_0000;
 const EXP_MASK: u8 = 0b0111_0000;
 const SIGNIF_MASK: u8 = 0b0000_1111;
 
-fn normalize(mut exp: u8, mut signif: u8) -> (u8, u8) {
-  if exp >= 0b111 {
-    // infinity
-    return (0b1111, 0);
-  }
-  while signif > 0b1111 {
-    if exp == 0 {
-      return (0b1111, 1);
-    }
-    exp -= 1;
-    signif >>= 1;
-  }
-  (exp, signif)
-}
-
 impl Zero for F8 {
   #[inline]
   fn zero() -> Self { F8(0) }
@@ -50,58 +40,19 @@ impl One for F8 {
   fn is_one(&self) -> bool { self.0 == F8_ONE.0 }
 }
 
+/// The exact sum rounded to nearest, ties to even, saturating at `F8::MAX`.
+/// Every operation is total, and never panics.
+///
+/// Every F8 is a multiple of 1/4 below 2^9, so the sum or product of two F8 needs at most
+/// 22 significant bits and is exact in f32. Rounding the f32 result is therefore rounding
+/// once, which the rest of the crate relies on.
 impl Add for F8 {
   type Output = Self;
   fn add(self, o: Self) -> Self::Output {
-    let mut e0 = self.exponent();
-    let mut e1 = o.exponent();
-    let mut m0 = self.significand();
-    let mut m1 = o.significand();
-    match e0.cmp(&e1) {
-      Ordering::Equal => (),
-      Ordering::Less => while e0 < e1 {
-        m0 <<= 1;
-        e0 += 1;
-      },
-      Ordering::Greater => while e1 < e0 {
-        m1 <<= 1;
-        e1 += 1;
-      },
-    }
-    assert_eq!(e0, e1, "Exponents not equal");
-    match (self.is_sign_positive(), o.is_sign_positive()) {
-      (true, true) => {
-        let (exp, signif) = normalize(e0, m0 + m1);
-        F8::new(0, exp, signif)
-      },
-      (false, false) => {
-        let (exp, signif) = normalize(e0, m0 + m1);
-        F8::new(1, exp, signif)
-      },
-      // self is positive, other is negative
-      (true, false) => match m0.cmp(&m1) {
-        Ordering::Equal => F8(0),
-        Ordering::Greater => {
-          let (exp, signif) = normalize(e0, m0 - m1);
-          F8::new(0, exp, signif)
-        },
-        Ordering::Less => {
-          let (exp, signif) = normalize(e0, m1 - m0);
-          F8::new(1, exp, signif)
-        },
-      },
-      (false, true) => match m1.cmp(&m0) {
-        Ordering::Equal => F8(0),
-        Ordering::Greater => {
-          let (exp, signif) = normalize(e0, m1 - m0);
-          F8::new(0, exp, signif)
-        },
-        Ordering::Less => {
-          let (exp, signif) = normalize(e0, m0 - m1);
-          F8::new(1, exp, signif)
-        },
-      },
+    if cfg!(feature = "soft-float") {
+      return crate::soft::add(self, o);
     }
+    F8::approx_from(self.v() + o.v())
   }
 }
 
@@ -116,25 +67,96 @@ impl Sub for F8 {
   fn sub(self, rhs: Self) -> Self::Output { self + (-rhs) }
 }
 
+/// The exact product rounded to nearest, ties to even, saturating at `F8::MAX`
 impl Mul for F8 {
   type Output = F8;
   #[inline]
   fn mul(self, rhs: Self) -> Self::Output {
-    let sign = (self.is_sign_negative() ^ rhs.is_sign_negative()) as u8;
-    let exp = self.exponent() + rhs.exponent() - BIAS;
-    let signif = self.significand() * rhs.significand();
-    let (exp, signif) = normalize(exp, signif);
-    F8::new(sign, exp, signif)
+    if cfg!(feature = "soft-float") {
+      return crate::soft::mul(self, rhs);
+    }
+    // exact in f32, as for `Add`
+    F8::approx_from(self.v() * rhs.v())
   }
 }
+
+impl AddAssign for F8 {
+  #[inline]
+  fn add_assign(&mut self, rhs: Self) { *self = *self + rhs }
+}
+
+impl SubAssign for F8 {
+  #[inline]
+  fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs }
+}
+
+impl MulAssign for F8 {
+  #[inline]
+  fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs }
+}
+
 impl F8 {
   pub const fn new(sign: u8, exp: u8, signif: u8) -> Self {
     F8(sign << 7 | ((exp << 4) & EXP_MASK) | (signif & SIGNIF_MASK))
   }
+  /// Constructs an F8 directly from its bit representation
+  pub const fn from_bits(bits: u8) -> Self { F8(bits) }
+  /// Returns the raw bit representation of this F8
+  pub const fn to_bits(self) -> u8 { self.0 }
+  /// Every bit pattern, in order of the bits
+  pub fn iter_all() -> impl Iterator<Item = F8> + Clone { (0..=255).map(F8::from_bits) }
+  /// Every finite bit pattern, which is every bit pattern, as F8 has no infinities or NaN
+  pub fn iter_finite() -> impl Iterator<Item = F8> + Clone { F8::iter_all() }
+  /// Each distinct value once, from `-F8::MAX` to `F8::MAX`, as the encodings of `ASCENDING`
+  /// and their negations
+  pub fn values_ascending() -> impl Iterator<Item = F8> + Clone {
+    let neg = ASCENDING[1..].iter().rev().map(|&f| -f);
+    neg.chain(ASCENDING.iter().copied())
+  }
+  /// The largest finite F8
+  pub const MAX: F8 = F8::new(0, 0b111, 0b1111);
+  /// The f32 value of every F8, indexed by its bit representation
+  pub const DECODE_TABLE: [f32; 256] = decode_table();
   pub const fn is_sign_positive(self) -> bool { self.0 & SIGN_MASK == 0 }
   pub const fn is_sign_negative(self) -> bool { self.0 & SIGN_MASK != 0 }
   pub const fn exponent(self) -> u8 { (self.0 & EXP_MASK) >> 4 }
   pub const fn significand(self) -> u8 { self.0 & SIGNIF_MASK }
+  /// An integer which orders F8 by value, `v() * 2^BIAS` exactly.
+  /// Both zeros and all encodings of the same value share one key.
+  #[inline]
+  pub const fn order_key(self) -> i16 {
+    let m = (self.significand() as i16) << self.exponent();
+    if self.is_sign_negative() {
+      -m
+    } else {
+      m
+    }
+  }
+  /// A total order over bit patterns, by value, then `-0` before `+0`, then by the
+  /// remaining bits among encodings of the same value
+  pub fn total_cmp(&self, other: &Self) -> Ordering {
+    let key = |f: &F8| (f.order_key(), f.is_sign_positive(), f.0 & !SIGN_MASK);
+    key(self).cmp(&key(other))
+  }
+  /// The position of this value among the distinct values, 0 for zero and negative below it
+  const fn rank(self) -> i16 {
+    let k = self.order_key().abs();
+    let r = if k < 16 {
+      k
+    } else {
+      // k = s * 2^e with s in 8..16, following the layout of `ASCENDING`
+      let e = 12 - k.leading_zeros() as i16;
+      e * 8 + (k >> e)
+    };
+    if self.order_key() < 0 {
+      -r
+    } else {
+      r
+    }
+  }
+  /// How many steps between adjacent representable values separate `self` and `other`,
+  /// with both zeros and all encodings of a value counting as one
+  pub fn ulp_distance(self, other: F8) -> u32 { (self.rank() - other.rank()).unsigned_abs() as u32 }
   pub fn signum(self) -> i8 {
     if self.significand() == 0 {
       return 0;
@@ -146,8 +168,11 @@ impl F8 {
     }
   }
   pub fn v(self) -> f32 {
+    if cfg!(feature = "soft-float") {
+      return f32::from_bits(crate::soft::to_f32_bits(self));
+    }
     let pos = self.is_sign_positive();
-    let v = 2f32.powi(self.exponent() as i32 - BIAS as i32) * (self.significand() as f32);
+    let v = crate::math::pow2(self.exponent() as i32 - BIAS as i32) * (self.significand() as f32);
     if pos {
       v
     } else {
@@ -161,32 +186,147 @@ impl F8 {
       self.signum(),
     )
   }
+  /// Converts an f32 to an F8 only if it is exactly representable.
   pub fn try_from(f: f32) -> Option<Self> {
-    let (mut signif, mut exp, _) = f.integer_decode();
-    let sign = f.is_sign_negative() as u8;
-    while signif & 1 != 1 {
-      signif >>= 1;
-      exp += 1;
+    let v = F8::approx_from(f);
+    if v.v() == f {
+      Some(v)
+    } else {
+      None
     }
-    let exp = exp + (BIAS as i16);
-    if exp < 0 {
-      return None;
+  }
+  /// Converts an f32 to the nearest F8, rounding ties to an even significand.
+  /// Magnitudes beyond `F8::MAX` saturate to it, and NaN becomes zero.
+  pub const fn approx_from(f: f32) -> Self {
+    let bits = f.to_bits();
+    let sign = (bits >> 31) as u8;
+    let exp = (bits >> 23) & 0xFF;
+    let is_nan = (exp == 0xFF) & (bits & 0x7F_FFFF != 0);
+    // f32 denormals share the exponent of the smallest normal, without the implicit bit
+    let implicit = ((exp != 0) as u32) << 23;
+    let exp = if exp == 0 { 1 } else { exp };
+    let m = (bits & 0x7F_FFFF) | implicit;
+    // exponent of the F8 such that the significand keeps the top 4 bits of m
+    let e = exp.saturating_sub(130 - BIAS as u32);
+    let shift = 150 - BIAS as u32 + e - exp;
+    let shift = if shift > 31 { 31 } else { shift };
+    let mut signif = m >> shift;
+    let rem = m & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    signif += ((rem > half) | ((rem == half) & (signif & 1 == 1))) as u32;
+    // rounding up may carry into a fifth bit
+    let carry = signif >> 4;
+    let signif = signif >> carry;
+    let e = e + carry;
+    if is_nan {
+      F8(0)
+    } else if e > 0b111 {
+      F8::new(sign, F8::MAX.exponent(), F8::MAX.significand())
+    } else {
+      F8::new(sign, e as u8, signif as u8)
     }
-    let (exp, signif) = normalize(exp as u8, signif as u8);
-    Some(F8::new(sign, exp, signif))
-  }
-  pub fn approx_from(f: f32) -> Self {
-    let (mut signif, mut exp, _) = f.integer_decode();
-    let sign = f.is_sign_negative() as u8;
-    while exp < -(BIAS as i16) {
-      signif >>= 1;
-      exp += 1;
+  }
+}
+
+const fn decode_table() -> [f32; 256] {
+  let mut table = [0f32; 256];
+  let mut i = 0;
+  while i < 256 {
+    let f = F8(i as u8);
+    let v = ((f.significand() as u32) << f.exponent()) as f32 / (1u32 << BIAS) as f32;
+    table[i] = if f.is_sign_positive() { v } else { -v };
+    i += 1;
+  }
+  table
+}
+
+/// Decodes through `F8::DECODE_TABLE`, for kernels which decode every element they accumulate
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn dec(f: F8) -> f32 { F8::DECODE_TABLE[f.to_bits() as usize] }
+
+const fn ascending() -> [F8; 72] {
+  let mut out = [F8(0); 72];
+  let mut i = 0;
+  while i < 16 {
+    out[i] = F8::new(0, 0, i as u8);
+    i += 1;
+  }
+  while i < 72 {
+    let exp = (i - 16) / 8 + 1;
+    let signif = (i - 16) % 8 + 8;
+    out[i] = F8::new(0, exp as u8, signif as u8);
+    i += 1;
+  }
+  out
+}
+
+/// Every distinct non-negative value an F8 can represent, in ascending order
+pub(crate) const ASCENDING: [F8; 72] = ascending();
+
+/// Returns the closest F8 magnitudes at or below and at or above `a`.
+/// Magnitudes past the largest F8 saturate to it, and negative or NaN inputs give zero.
+pub(crate) fn bracket(a: f32) -> (F8, F8) {
+  let i = ASCENDING.partition_point(|f| f.v() <= a);
+  if i == 0 {
+    return (ASCENDING[0], ASCENDING[0]);
+  }
+  let lo = ASCENDING[i - 1];
+  if lo.v() == a || i == ASCENDING.len() {
+    (lo, lo)
+  } else {
+    (lo, ASCENDING[i])
+  }
+}
+
+/// Rounds an f64 to the nearest F8 with ties to even, saturating, and NaN to zero
+#[cfg(feature = "std")]
+pub(crate) fn round_f64(v: f64) -> F8 {
+  if v.is_nan() {
+    return F8::from_bits(0);
+  }
+  let a = v.abs();
+  let i = ASCENDING.partition_point(|f| (f.v() as f64) <= a);
+  let q = if i == ASCENDING.len() {
+    F8::MAX
+  } else {
+    let (lo, hi) = (ASCENDING[i - 1], ASCENDING[i]);
+    let (dl, dh) = (a - lo.v() as f64, hi.v() as f64 - a);
+    if dl < dh || (dl == dh && lo.significand() & 1 == 0) {
+      lo
+    } else {
+      hi
     }
-    let (exp, signif) = normalize((exp + (BIAS as i16)) as u8, signif as u8);
-    F8::new(sign, exp, signif)
+  };
+  if v.is_sign_negative() {
+    -q
+  } else {
+    q
   }
 }
 
+/// Lookup table from the top 16 bits of an f32 to the F8 `approx_from` produces for it.
+/// Built on first use, 64KiB.
+#[cfg(feature = "encode-table")]
+pub fn encode_table() -> &'static [F8] {
+  use std::sync::OnceLock;
+  static TABLE: OnceLock<Box<[F8]>> = OnceLock::new();
+  TABLE.get_or_init(|| {
+    (0..=u16::MAX)
+      .map(|hi| F8::approx_from(f32::from_bits((hi as u32) << 16)))
+      .collect()
+  })
+}
+
+#[cfg(feature = "encode-table")]
+impl F8 {
+  /// Converts an f32 to an F8 using only its top 16 bits as a key into the encode table.
+  /// The discarded low mantissa bits can only matter when the kept bits land exactly on a
+  /// rounding boundary.
+  #[inline]
+  pub fn from_f32_table(f: f32) -> Self { encode_table()[(f.to_bits() >> 16) as usize] }
+}
+
 impl From<F8> for f32 {
   fn from(f8: F8) -> f32 { f8.v() }
 }
@@ -0,0 +1,82 @@
+//! A C interface for conversion and arithmetic, matching `include/f8.h`.
+//!
+//! `F8` is a transparent wrapper of its bits, so it crosses the boundary as a `uint8_t`. Build a
+//! linkable library with `cargo rustc --release --crate-type staticlib`.
+
+use crate::f8::F8;
+use core::slice;
+
+#[no_mangle]
+pub extern "C" fn f8_from_f32(f: f32) -> F8 { F8::approx_from(f) }
+
+#[no_mangle]
+pub extern "C" fn f8_to_f32(f: F8) -> f32 { f.v() }
+
+#[no_mangle]
+pub extern "C" fn f8_add(a: F8, b: F8) -> F8 { a + b }
+
+#[no_mangle]
+pub extern "C" fn f8_sub(a: F8, b: F8) -> F8 { a - b }
+
+#[no_mangle]
+pub extern "C" fn f8_mul(a: F8, b: F8) -> F8 { a * b }
+
+#[no_mangle]
+pub extern "C" fn f8_neg(a: F8) -> F8 { -a }
+
+/// Converts `len` floats from `src` into `dst`.
+///
+/// # Safety
+/// `src` and `dst` must be valid for `len` elements and must not overlap. Either may be null
+/// when `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn f8_from_f32_slice(src: *const f32, dst: *mut F8, len: usize) {
+  if len == 0 {
+    return;
+  }
+  let (src, dst) = (
+    slice::from_raw_parts(src, len),
+    slice::from_raw_parts_mut(dst, len),
+  );
+  for (d, &s) in dst.iter_mut().zip(src) {
+    *d = F8::approx_from(s);
+  }
+}
+
+/// Converts `len` F8 from `src` into `dst`.
+///
+/// # Safety
+/// `src` and `dst` must be valid for `len` elements and must not overlap. Either may be null
+/// when `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn f8_to_f32_slice(src: *const F8, dst: *mut f32, len: usize) {
+  if len == 0 {
+    return;
+  }
+  let (src, dst) = (
+    slice::from_raw_parts(src, len),
+    slice::from_raw_parts_mut(dst, len),
+  );
+  for (d, s) in dst.iter_mut().zip(src) {
+    *d = s.v();
+  }
+}
+
+/// Adds `b` into `a` element-wise over `len` elements.
+///
+/// # Safety
+/// `a` and `b` must be valid for `len` elements, and `b` must not overlap `a`. Either may be
+/// null when `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn f8_add_slice(a: *mut F8, b: *const F8, len: usize) {
+  if len == 0 {
+    return;
+  }
+  let (a, b) = (
+    slice::from_raw_parts_mut(a, len),
+    slice::from_raw_parts(b, len),
+  );
+  for (a, &b) in a.iter_mut().zip(b) {
+    *a += b;
+  }
+}
@@ -0,0 +1,73 @@
+//! Radix 2 FFTs of F8 signals, computed in f32 with twiddles evaluated in f64.
+//!
+//! Spectra are returned as `(re, im)` f32 pairs, and can be requantized with one scale, since
+//! the magnitude of a spectrum grows with the length of the signal.
+
+use crate::{complex::ComplexF8, f8::F8, scaled::absmax_scale};
+use std::f64::consts::PI;
+
+/// The spectrum of a real signal, whose length must be a power of two
+pub fn fft(input: &[F8]) -> Vec<(f32, f32)> {
+  let mut data: Vec<_> = input.iter().map(|f| (f.v(), 0.0)).collect();
+  fft_in_place(&mut data);
+  data
+}
+
+/// The spectrum of a complex signal, whose length must be a power of two
+pub fn fft_complex(input: &[ComplexF8]) -> Vec<(f32, f32)> {
+  let mut data: Vec<_> = input.iter().map(|c| c.to_f32()).collect();
+  fft_in_place(&mut data);
+  data
+}
+
+/// Rounds a spectrum to F8 with one absmax scale, returning `(scale, spectrum)` where each
+/// value is `scale * spectrum[i]`
+pub fn quantize_spectrum(spectrum: &[(f32, f32)]) -> (f32, Vec<ComplexF8>) {
+  let parts: Vec<f32> = spectrum.iter().flat_map(|&(re, im)| [re, im]).collect();
+  let scale = absmax_scale(&parts);
+  let q = spectrum
+    .iter()
+    .map(|&(re, im)| ComplexF8::from_f32(re / scale, im / scale))
+    .collect();
+  (scale, q)
+}
+
+/// An in place decimation in time FFT, with the sign convention `X_k = sum x_n e^(-2 pi i k n / N)`
+pub fn fft_in_place(data: &mut [(f32, f32)]) {
+  let n = data.len();
+  assert!(
+    n == 0 || n.is_power_of_two(),
+    "Length {} is not a power of two",
+    n
+  );
+  if n <= 1 {
+    return;
+  }
+  let bits = n.trailing_zeros();
+  for i in 0..n {
+    let j = i.reverse_bits() >> (usize::BITS - bits);
+    if i < j {
+      data.swap(i, j);
+    }
+  }
+  let twiddles: Vec<(f32, f32)> = (0..n / 2)
+    .map(|k| {
+      let t = -2.0 * PI * k as f64 / n as f64;
+      (t.cos() as f32, t.sin() as f32)
+    })
+    .collect();
+  let mut len = 2;
+  while len <= n {
+    let stride = n / len;
+    for chunk in data.chunks_exact_mut(len) {
+      let (lo, hi) = chunk.split_at_mut(len / 2);
+      for (k, (a, b)) in lo.iter_mut().zip(hi).enumerate() {
+        let (wr, wi) = twiddles[k * stride];
+        let t = (b.0 * wr - b.1 * wi, b.0 * wi + b.1 * wr);
+        *b = (a.0 - t.0, a.1 - t.1);
+        *a = (a.0 + t.0, a.1 + t.1);
+      }
+    }
+    len *= 2;
+  }
+}
@@ -0,0 +1,160 @@
+//! Filters over F8 samples. State is kept in f32 and only outputs are rounded to F8, so
+//! rounding does not feed back into later outputs.
+
+use crate::f8::F8;
+
+/// The mean of the last `window` samples, or of all samples until the window has filled
+#[derive(Debug, Clone, PartialEq)]
+pub struct MovingAverage {
+  samples: Vec<f32>,
+  /// Where the next sample is written once the window is full
+  next: usize,
+  window: usize,
+  sum: f32,
+}
+
+impl MovingAverage {
+  pub fn new(window: usize) -> Self {
+    assert_ne!(window, 0, "Window must not be empty");
+    MovingAverage {
+      samples: Vec::with_capacity(window),
+      next: 0,
+      window,
+      sum: 0.0,
+    }
+  }
+  pub fn process(&mut self, x: F8) -> F8 {
+    let x = x.v();
+    if self.samples.len() < self.window {
+      self.samples.push(x);
+      self.sum += x;
+    } else {
+      let old = std::mem::replace(&mut self.samples[self.next], x);
+      self.next = (self.next + 1) % self.window;
+      // resum once per window, so cancellation error does not accumulate
+      self.sum = if self.next == 0 {
+        self.samples.iter().sum()
+      } else {
+        self.sum - old + x
+      };
+    }
+    F8::approx_from(self.sum / self.samples.len() as f32)
+  }
+  pub fn process_slice(&mut self, input: &[F8], out: &mut [F8]) {
+    assert_eq!(input.len(), out.len(), "Mismatched lengths");
+    for (o, &x) in out.iter_mut().zip(input) {
+      *o = self.process(x);
+    }
+  }
+  pub fn reset(&mut self) {
+    self.samples.clear();
+    self.next = 0;
+    self.sum = 0.0;
+  }
+}
+
+/// The one pole low pass `y = y + alpha * (x - y)`, starting from the first sample
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct OnePole {
+  pub alpha: f32,
+  state: Option<f32>,
+}
+
+impl OnePole {
+  pub fn new(alpha: f32) -> Self {
+    assert!((0.0..=1.0).contains(&alpha), "alpha must be in [0, 1]");
+    OnePole { alpha, state: None }
+  }
+  /// The filter whose step response reaches `1 - 1/e` after `tau` samples
+  pub fn from_time_constant(tau: f32) -> Self {
+    Self::new(1.0 - (-1.0 / tau.max(f32::MIN_POSITIVE)).exp())
+  }
+  pub fn process(&mut self, x: F8) -> F8 {
+    let x = x.v();
+    let y = self.state.map_or(x, |y| y + self.alpha * (x - y));
+    self.state = Some(y);
+    F8::approx_from(y)
+  }
+  pub fn process_slice(&mut self, input: &[F8], out: &mut [F8]) {
+    assert_eq!(input.len(), out.len(), "Mismatched lengths");
+    for (o, &x) in out.iter_mut().zip(input) {
+      *o = self.process(x);
+    }
+  }
+  /// The unrounded output, or None before any sample
+  pub fn state(&self) -> Option<f32> { self.state }
+  pub fn reset(&mut self) { self.state = None }
+}
+
+/// Writes the full convolution of `signal` and `kernel` to `out`, of length
+/// `signal.len() + kernel.len() - 1`, or 0 if either is empty, accumulating in f32.
+///
+/// With the `simd` feature on x86_64 the inner products use SSE.
+pub fn convolve(signal: &[F8], kernel: &[F8], out: &mut [f32]) {
+  let len = if signal.is_empty() || kernel.is_empty() {
+    0
+  } else {
+    signal.len() + kernel.len() - 1
+  };
+  assert_eq!(
+    out.len(),
+    len,
+    "out is not of length signal.len() + kernel.len() - 1"
+  );
+  if len == 0 {
+    return;
+  }
+  let k = kernel.len();
+  let mut padded = vec![0f32; signal.len() + 2 * (k - 1)];
+  for (p, &v) in padded[k - 1..].iter_mut().zip(signal) {
+    *p = v.v();
+  }
+  let reversed: Vec<f32> = kernel.iter().rev().map(|f| f.v()).collect();
+  for (i, o) in out.iter_mut().enumerate() {
+    *o = dot_f32(&padded[i..i + k], &reversed);
+  }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+fn dot_f32(a: &[f32], b: &[f32]) -> f32 {
+  let mut acc = [0f32; 4];
+  let (ca, cb) = (a.chunks_exact(4), b.chunks_exact(4));
+  let tail: f32 = ca
+    .remainder()
+    .iter()
+    .zip(cb.remainder())
+    .map(|(x, y)| x * y)
+    .sum();
+  for (x, y) in ca.zip(cb) {
+    for i in 0..4 {
+      acc[i] += x[i] * y[i];
+    }
+  }
+  acc.iter().sum::<f32>() + tail
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+fn dot_f32(a: &[f32], b: &[f32]) -> f32 {
+  use std::arch::x86_64::{_mm_add_ps, _mm_loadu_ps, _mm_mul_ps, _mm_setzero_ps, _mm_storeu_ps};
+  assert_eq!(a.len(), b.len());
+  let (ca, cb) = (a.chunks_exact(4), b.chunks_exact(4));
+  let tail: f32 = ca
+    .remainder()
+    .iter()
+    .zip(cb.remainder())
+    .map(|(x, y)| x * y)
+    .sum();
+  let mut acc = [0f32; 4];
+  // SAFETY: SSE is part of the x86_64 baseline, and every load reads one whole chunk of 4
+  unsafe {
+    let mut v = _mm_setzero_ps();
+    for (x, y) in ca.zip(cb) {
+      v = _mm_add_ps(
+        v,
+        _mm_mul_ps(_mm_loadu_ps(x.as_ptr()), _mm_loadu_ps(y.as_ptr())),
+      );
+    }
+    _mm_storeu_ps(acc.as_mut_ptr(), v);
+  }
+  acc.iter().sum::<f32>() + tail
+}
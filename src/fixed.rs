@@ -0,0 +1,95 @@
+//! 8 bit fixed point Q formats, and how they compare with F8 on a dataset.
+
+use crate::f8::F8;
+
+/// A signed 8 bit fixed point number with `FRAC` fractional bits, `Q(7 - FRAC).FRAC`
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Q8<const FRAC: u32>(pub i8);
+
+#[allow(non_camel_case_types)]
+pub type Q3_4 = Q8<4>;
+#[allow(non_camel_case_types)]
+pub type Q1_6 = Q8<6>;
+
+/// Rounds `v * 2^frac` to the nearest i8, ties to even, saturating, with NaN as zero
+fn to_fixed(v: f32, frac: u32) -> i8 {
+  let r = (v * (1u32 << frac) as f32).round_ties_even();
+  if r.is_nan() {
+    0
+  } else {
+    r.clamp(-128.0, 127.0) as i8
+  }
+}
+
+fn from_fixed(n: i8, frac: u32) -> f32 { n as f32 / (1u32 << frac) as f32 }
+
+impl<const FRAC: u32> Q8<FRAC> {
+  /// The smallest and largest values
+  pub const MIN: f32 = -128.0 / (1u32 << FRAC) as f32;
+  pub const MAX: f32 = 127.0 / (1u32 << FRAC) as f32;
+  /// Rounds to nearest, ties to even, saturating
+  pub fn from_f32(v: f32) -> Self { Q8(to_fixed(v, FRAC)) }
+  pub fn to_f32(self) -> f32 { from_fixed(self.0, FRAC) }
+  pub fn from_f8(f: F8) -> Self { Q8::from_f32(f.v()) }
+  /// Rounds to the nearest F8, which is exact when the value needs at most 4 significant bits
+  pub fn to_f8(self) -> F8 { F8::approx_from(self.to_f32()) }
+}
+
+/// The error of storing a dataset in one format
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct FitStats {
+  pub rms: f32,
+  pub max_abs: f32,
+  /// Values beyond the range of the format
+  pub clipped: usize,
+}
+
+fn fit(data: &[f32], max: f32, round: impl Fn(f32) -> f32) -> FitStats {
+  let (mut sq, mut max_abs, mut clipped) = (0f64, 0f32, 0);
+  for &v in data {
+    let e = (round(v) - v).abs();
+    sq += (e as f64).powi(2);
+    max_abs = max_abs.max(e);
+    clipped += (v.abs() > max) as usize;
+  }
+  FitStats {
+    rms: (sq / data.len().max(1) as f64).sqrt() as f32,
+    max_abs,
+    clipped,
+  }
+}
+
+/// F8 against every Q format on the same data, unscaled
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct QComparison {
+  pub f8: FitStats,
+  /// Indexed by the number of fractional bits, from Q7.0 to Q0.7
+  pub fixed: [FitStats; 8],
+  /// The fractional bits of the Q format with the lowest RMS error
+  pub best_frac: u32,
+}
+
+impl QComparison {
+  /// Whether F8 has a lower RMS error than the best Q format
+  pub fn f8_fits_better(&self) -> bool { self.f8.rms < self.fixed[self.best_frac as usize].rms }
+}
+
+/// Measures how well `data` fits F8 and each Q format
+pub fn compare(data: &[f32]) -> QComparison {
+  let f8 = fit(data, F8::MAX.v(), |v| F8::approx_from(v).v());
+  let mut fixed = [FitStats::default(); 8];
+  for (frac, s) in fixed.iter_mut().enumerate() {
+    let frac = frac as u32;
+    *s = fit(data, 127.0 / (1u32 << frac) as f32, |v| {
+      from_fixed(to_fixed(v, frac), frac)
+    });
+  }
+  let best_frac = (0..8)
+    .min_by(|&a, &b| fixed[a].rms.total_cmp(&fixed[b].rms))
+    .unwrap() as u32;
+  QComparison {
+    f8,
+    fixed,
+    best_frac,
+  }
+}
@@ -0,0 +1,270 @@
+//! Text formatting and parsing for F8.
+
+#[cfg(feature = "alloc")]
+use crate::f8::bracket;
+use crate::f8::{ASCENDING, F8};
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+/// The decimal with the fewest characters which parses back to each of `ASCENDING`, nearest to
+/// the value among those of equal length. `F8::MAX` is not given a shorter string that only reaches it by
+/// saturating.
+const SHORTEST: [&str; 72] = [
+  "0", "0.2", "0.5", "0.7", "1", "1.2", "1.5", "1.7", "2", "2.2", "2.5", "2.7", "3", "3.2", "3.5",
+  "3.7", "4", "4.5", "5", "5.5", "6", "6.5", "7", "7.5", "8", "9", "10", "11", "12", "13", "14",
+  "15", "16", "18", "20", "22", "24", "26", "28", "30", "32", "36", "40", "44", "48", "52", "56",
+  "60", "64", "72", "80", "88", "96", "104", "112", "120", "128", "144", "160", "176", "192",
+  "208", "224", "240", "256", "288", "320", "352", "384", "416", "448", "480",
+];
+
+/// Prints the exact decimal value. Every F8 is a multiple of a quarter, so there are never
+/// more than two decimal places, and width, precision and sign flags act as they do for f32.
+///
+/// The alternate flag, `{:#}`, instead prints the shortest decimal which parses back to the
+/// same value, such as `0.7` for 0.75.
+impl fmt::Display for F8 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if f.alternate() {
+      // the longest is a sign and three characters
+      let (sign, digits) = self.shortest_parts();
+      let mut buf = [0u8; 4];
+      buf[..sign.len()].copy_from_slice(sign.as_bytes());
+      buf[sign.len()..sign.len() + digits.len()].copy_from_slice(digits.as_bytes());
+      f.pad(core::str::from_utf8(&buf[..sign.len() + digits.len()]).unwrap())
+    } else {
+      fmt::Display::fmt(&self.v(), f)
+    }
+  }
+}
+
+/// Scientific notation of the exact value, as for f32, such as `1.5e0`
+impl fmt::LowerExp for F8 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::LowerExp::fmt(&self.v(), f) }
+}
+
+/// Scientific notation of the exact value, as for f32, such as `1.5E0`
+impl fmt::UpperExp for F8 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::UpperExp::fmt(&self.v(), f) }
+}
+
+/// The bit pattern, as for u8
+impl fmt::Binary for F8 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Binary::fmt(&self.to_bits(), f) }
+}
+
+/// The bit pattern, as for u8
+impl fmt::LowerHex for F8 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::LowerHex::fmt(&self.to_bits(), f)
+  }
+}
+
+/// The bit pattern, as for u8
+impl fmt::UpperHex for F8 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::UpperHex::fmt(&self.to_bits(), f)
+  }
+}
+
+impl F8 {
+  /// The sign and digits of the shortest decimal which parses back to the same value
+  fn shortest_parts(self) -> (&'static str, &'static str) {
+    let key = self.order_key().abs();
+    let i = ASCENDING.iter().position(|a| a.order_key() == key).unwrap();
+    let sign = if self.is_sign_negative() { "-" } else { "" };
+    (sign, SHORTEST[i])
+  }
+  /// The shortest decimal string which parses back to the same value, as printed by `{:#}`
+  #[cfg(feature = "alloc")]
+  pub fn to_shortest_string(self) -> String {
+    let (sign, digits) = self.shortest_parts();
+    format!("{}{}", sign, digits)
+  }
+  /// The sign, exponent and significand fields in binary, separated as `s|eee|mmmm`
+  #[cfg(feature = "alloc")]
+  pub fn fmt_fields(self) -> String {
+    format!(
+      "{:01b}|{:03b}|{:04b}",
+      self.is_sign_negative() as u8,
+      self.exponent(),
+      self.significand()
+    )
+  }
+}
+
+/// An error from parsing an F8
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseF8Error(());
+
+impl fmt::Display for ParseF8Error {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("invalid F8 literal") }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseF8Error {}
+
+/// Splits a decimal literal into its sign, digits and the position of the decimal point
+/// relative to the start of the digits
+#[cfg(feature = "alloc")]
+fn split_decimal(s: &str) -> Option<(bool, Vec<u8>, i64)> {
+  let (neg, s) = match s.as_bytes().first() {
+    Some(b'-') => (true, &s[1..]),
+    Some(b'+') => (false, &s[1..]),
+    _ => (false, s),
+  };
+  let (mantissa, exp) = match s.find(['e', 'E']) {
+    Some(i) => (&s[..i], s[i + 1..].parse::<i64>().ok()?),
+    None => (s, 0),
+  };
+  let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+  if int.is_empty() && frac.is_empty() {
+    return None;
+  }
+  let mut digits = Vec::with_capacity(int.len() + frac.len());
+  for b in int.bytes().chain(frac.bytes()) {
+    if !b.is_ascii_digit() {
+      return None;
+    }
+    digits.push(b - b'0');
+  }
+  Some((neg, digits, (int.len() as i64).saturating_add(exp)))
+}
+
+/// Rounds the non-negative decimal `0.digits * 10^point` to the nearest F8, ties to even
+#[cfg(feature = "alloc")]
+fn round_decimal(digits: &[u8], point: i64) -> F8 {
+  let lead = digits.iter().take_while(|&&d| d == 0).count();
+  let (digits, point) = (&digits[lead..], point.saturating_sub(lead as i64));
+  if digits.is_empty() || point < 0 {
+    // below a tenth, which rounds to zero
+    return F8::from_bits(0);
+  }
+  if point > 3 {
+    return F8::MAX;
+  }
+  let int_len = point as usize;
+  let int = (0..int_len).fold(0u32, |acc, i| {
+    acc * 10 + *digits.get(i).unwrap_or(&0) as u32
+  });
+  let frac = digits.get(int_len..).unwrap_or(&[]);
+  // 8 * 0.frac, split into its integer part and whether anything is left over
+  let (mut carry, mut rem) = (0u32, false);
+  for &d in frac.iter().rev() {
+    let p = d as u32 * 8 + carry;
+    rem |= !p.is_multiple_of(10);
+    carry = p / 10;
+  }
+  // the value in eighths lies in [k, k + 1), exactly k when there is no remainder
+  let k = int * 8 + carry;
+  if k > 8 * 480 {
+    return F8::MAX;
+  }
+  let (lo, _) = bracket(k as f32 / 8.0);
+  let lo8 = (lo.v() * 8.0) as u32;
+  if lo8 == k && !rem {
+    return lo;
+  }
+  let hi = match ASCENDING.iter().find(|a| a.v() > lo.v()) {
+    Some(&hi) => hi,
+    None => return F8::MAX,
+  };
+  let mid = (lo8 + (hi.v() * 8.0) as u32) / 2;
+  let above = k > mid || (k == mid && rem);
+  let tie = k == mid && !rem;
+  if above || (tie && lo.significand() % 2 == 1) {
+    hi
+  } else {
+    lo
+  }
+}
+
+/// Parses a decimal literal, rounding to the nearest F8 with ties to even. Magnitudes past
+/// `F8::MAX`, including `inf` and `infinity`, saturate to it. F8 has no NaN, so `nan` does
+/// not parse.
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for F8 {
+  type Err = ParseF8Error;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (neg, mag) = match split_decimal(s) {
+      Some((neg, digits, point)) => (neg, round_decimal(&digits, point)),
+      None => {
+        let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+        if !unsigned.eq_ignore_ascii_case("inf") && !unsigned.eq_ignore_ascii_case("infinity") {
+          return Err(ParseF8Error(()));
+        }
+        (s.starts_with('-'), F8::MAX)
+      },
+    };
+    Ok(if neg { -mag } else { mag })
+  }
+}
+
+impl F8 {
+  /// Parses a C99 hexadecimal floating literal such as `0x1.8p-1`, rounding to the nearest F8
+  /// with ties to even and saturating past `F8::MAX`. The binary exponent may be omitted.
+  pub fn from_hex_str(s: &str) -> Result<Self, ParseF8Error> {
+    let err = ParseF8Error(());
+    let (neg, s) = match s.as_bytes().first() {
+      Some(b'-') => (true, &s[1..]),
+      Some(b'+') => (false, &s[1..]),
+      _ => (false, s),
+    };
+    let s = s
+      .strip_prefix("0x")
+      .or_else(|| s.strip_prefix("0X"))
+      .ok_or_else(|| err.clone())?;
+    let (mantissa, exp) = match s.find(['p', 'P']) {
+      Some(i) => (&s[..i], s[i + 1..].parse::<i32>().map_err(|_| err.clone())?),
+      None => (s, 0),
+    };
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if int.is_empty() && frac.is_empty() {
+      return Err(err);
+    }
+    // keeps at most 23 bits of mantissa, with any dropped nonzero bits folded into a sticky bit
+    // so the value converts to f32 exactly enough to round once
+    let (mut m, mut e, mut sticky) = (0u32, 0i64, false);
+    for (i, c) in int.chars().chain(frac.chars()).enumerate() {
+      let d = c.to_digit(16).ok_or_else(|| err.clone())?;
+      let in_frac = i >= int.len();
+      if m < 1 << 19 {
+        m = m * 16 + d;
+        e -= 4 * in_frac as i64;
+      } else {
+        sticky |= d != 0;
+        e += 4 * !in_frac as i64;
+      }
+    }
+    if sticky {
+      m = m << 1 | 1;
+      e -= 1;
+    }
+    let e = e + exp as i64;
+    let mag = if m == 0 || e < -200 {
+      F8::from_bits(0)
+    } else if e > 200 {
+      F8::MAX
+    } else {
+      F8::approx_from((m as f64 * crate::math::pow2_f64(e as i32)) as f32)
+    };
+    Ok(if neg { -mag } else { mag })
+  }
+  /// Formats as a normalized C99 hexadecimal floating literal, such as `0x1.8p-1`
+  #[cfg(feature = "alloc")]
+  pub fn to_hex_string(self) -> String {
+    let sign = if self.is_sign_negative() { "-" } else { "" };
+    let s = self.significand();
+    if s == 0 {
+      return format!("{}0x0p+0", sign);
+    }
+    let k = 7 - s.leading_zeros() as i32;
+    let e = k + self.exponent() as i32 - crate::f8::BIAS as i32;
+    let frac = (s - (1 << k)) << (4 - k);
+    if frac == 0 {
+      format!("{}0x1p{:+}", sign, e)
+    } else {
+      format!("{}0x1.{:x}p{:+}", sign, frac, e)
+    }
+  }
+}
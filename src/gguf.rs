@@ -0,0 +1,202 @@
+//! GGUF (llama.cpp) quantization blocks, and conversion between them and `MxBlock`.
+//!
+//! Both `Q8_0` and `Q4_0` blocks hold 32 values with one f16 scale `d`:
+//! - `Q8_0`: `d | qs: [i8; 32]`, value = d * q
+//! - `Q4_0`: `d | qs: [u8; 16]`, value = d * (nibble - 8), where the low nibbles hold elements
+//!   0..16 and the high nibbles elements 16..32
+
+use crate::mx::{MxBlock, MX_BLOCK};
+use std::io;
+
+/// Elements per GGUF block
+pub const QK: usize = 32;
+
+pub(crate) fn f16_to_f32(h: u16) -> f32 {
+  let sign = ((h & 0x8000) as u32) << 16;
+  let exp = ((h >> 10) & 0x1F) as u32;
+  let mant = (h & 0x3FF) as u32;
+  match exp {
+    0 => {
+      let v = mant as f32 * 2f32.powi(-24);
+      if sign != 0 {
+        -v
+      } else {
+        v
+      }
+    },
+    0x1F => f32::from_bits(sign | 0x7F80_0000 | (mant << 13)),
+    _ => f32::from_bits(sign | ((exp + 112) << 23) | (mant << 13)),
+  }
+}
+
+/// Rounds an f32 to the nearest f16, ties to even
+pub(crate) fn f32_to_f16(f: f32) -> u16 {
+  let bits = f.to_bits();
+  let sign = ((bits >> 16) & 0x8000) as u16;
+  let exp = ((bits >> 23) & 0xFF) as i32;
+  let mant = bits & 0x7F_FFFF;
+  if exp == 0xFF {
+    return sign | 0x7C00 | if mant != 0 { 0x200 } else { 0 };
+  }
+  let e = exp - 127 + 15;
+  if e >= 0x1F {
+    return sign | 0x7C00;
+  }
+  let round =
+    |r: u32, rem: u32, half: u32| r + ((rem > half) | ((rem == half) & (r & 1 == 1))) as u32;
+  if e <= 0 {
+    if e < -10 {
+      return sign;
+    }
+    let m = mant | 0x80_0000;
+    let shift = (14 - e) as u32;
+    let r = round(m >> shift, m & ((1 << shift) - 1), 1 << (shift - 1));
+    return sign | r as u16;
+  }
+  // a carry out of the mantissa correctly increments the exponent, up to infinity
+  let r = round(((e as u32) << 10) | (mant >> 13), mant & 0x1FFF, 0x1000);
+  sign | r as u16
+}
+
+fn invalid(msg: &str) -> io::Error { io::Error::new(io::ErrorKind::InvalidData, msg) }
+
+fn absmax(v: &[f32]) -> f32 { v.iter().fold(0f32, |m, v| m.max(v.abs())) }
+
+/// GGUF `Q8_0` block
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub struct BlockQ8_0 {
+  /// f16 bits of the scale
+  pub d: u16,
+  pub qs: [i8; QK],
+}
+
+impl BlockQ8_0 {
+  pub const BYTES: usize = 2 + QK;
+  pub fn quantize(v: &[f32; QK]) -> Self {
+    let d = absmax(v) / 127.0;
+    let inv = if d == 0.0 { 0.0 } else { 1.0 / d };
+    let mut qs = [0; QK];
+    for (q, &v) in qs.iter_mut().zip(v.iter()) {
+      *q = (v * inv).round() as i8;
+    }
+    BlockQ8_0 {
+      d: f32_to_f16(d),
+      qs,
+    }
+  }
+  pub fn dequantize(&self) -> [f32; QK] {
+    let d = f16_to_f32(self.d);
+    let mut out = [0.0; QK];
+    for (o, &q) in out.iter_mut().zip(self.qs.iter()) {
+      *o = q as f32 * d;
+    }
+    out
+  }
+  pub fn from_bytes(b: &[u8; Self::BYTES]) -> Self {
+    let mut qs = [0; QK];
+    for (q, &b) in qs.iter_mut().zip(&b[2..]) {
+      *q = b as i8;
+    }
+    BlockQ8_0 {
+      d: u16::from_le_bytes([b[0], b[1]]),
+      qs,
+    }
+  }
+  pub fn to_bytes(&self) -> [u8; Self::BYTES] {
+    let mut out = [0; Self::BYTES];
+    out[..2].copy_from_slice(&self.d.to_le_bytes());
+    for (o, &q) in out[2..].iter_mut().zip(self.qs.iter()) {
+      *o = q as u8;
+    }
+    out
+  }
+  pub fn to_mx(&self) -> MxBlock { MxBlock::quantize(&self.dequantize()) }
+  pub fn from_mx(b: &MxBlock) -> Self { Self::quantize(&b.dequantize()) }
+}
+
+/// GGUF `Q4_0` block
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub struct BlockQ4_0 {
+  /// f16 bits of the scale
+  pub d: u16,
+  pub qs: [u8; QK / 2],
+}
+
+impl BlockQ4_0 {
+  pub const BYTES: usize = 2 + QK / 2;
+  pub fn quantize(v: &[f32; QK]) -> Self {
+    // matches ggml, which maps the signed extreme onto -8
+    let max = v
+      .iter()
+      .fold(0f32, |m, &v| if v.abs() > m.abs() { v } else { m });
+    let d = max / -8.0;
+    let inv = if d == 0.0 { 0.0 } else { 1.0 / d };
+    let q = |v: f32| ((v * inv + 8.5) as u8).min(15);
+    let mut qs = [0; QK / 2];
+    for (j, q_j) in qs.iter_mut().enumerate() {
+      *q_j = q(v[j]) | (q(v[j + QK / 2]) << 4);
+    }
+    BlockQ4_0 {
+      d: f32_to_f16(d),
+      qs,
+    }
+  }
+  pub fn dequantize(&self) -> [f32; QK] {
+    let d = f16_to_f32(self.d);
+    let mut out = [0.0; QK];
+    for (j, &q) in self.qs.iter().enumerate() {
+      out[j] = ((q & 0xF) as f32 - 8.0) * d;
+      out[j + QK / 2] = ((q >> 4) as f32 - 8.0) * d;
+    }
+    out
+  }
+  pub fn from_bytes(b: &[u8; Self::BYTES]) -> Self {
+    let mut qs = [0; QK / 2];
+    qs.copy_from_slice(&b[2..]);
+    BlockQ4_0 {
+      d: u16::from_le_bytes([b[0], b[1]]),
+      qs,
+    }
+  }
+  pub fn to_bytes(&self) -> [u8; Self::BYTES] {
+    let mut out = [0; Self::BYTES];
+    out[..2].copy_from_slice(&self.d.to_le_bytes());
+    out[2..].copy_from_slice(&self.qs);
+    out
+  }
+  pub fn to_mx(&self) -> MxBlock { MxBlock::quantize(&self.dequantize()) }
+  pub fn from_mx(b: &MxBlock) -> Self { Self::quantize(&b.dequantize()) }
+}
+
+macro_rules! block_io {
+  ($read: ident, $write: ident, $block: ty) => {
+    /// Parses a packed run of blocks, as stored in a GGUF tensor's data
+    pub fn $read(bytes: &[u8]) -> io::Result<Vec<$block>> {
+      const N: usize = <$block>::BYTES;
+      if bytes.len() % N != 0 {
+        return Err(invalid("data is not a whole number of blocks"));
+      }
+      Ok(
+        bytes
+          .chunks_exact(N)
+          .map(|c| {
+            let mut b = [0; N];
+            b.copy_from_slice(c);
+            <$block>::from_bytes(&b)
+          })
+          .collect(),
+      )
+    }
+    /// Packs blocks in the layout stored in a GGUF tensor's data
+    pub fn $write(blocks: &[$block]) -> Vec<u8> {
+      blocks.iter().flat_map(|b| b.to_bytes()).collect()
+    }
+  };
+}
+
+block_io!(read_q8_0, write_q8_0, BlockQ8_0);
+block_io!(read_q4_0, write_q4_0, BlockQ4_0);
+
+const _: () = assert!(QK == MX_BLOCK);
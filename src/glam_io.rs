@@ -0,0 +1,75 @@
+//! Conversion between `glam` vectors and matrices and scaled F8 arrays.
+
+use crate::{
+  f8::F8,
+  scaled::{absmax_scale, ScaledF8Tensor},
+};
+use glam::{Mat4, Vec3, Vec4};
+
+/// `N` F8 sharing one scale, representing `scale * data[i]`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ScaledF8Array<const N: usize> {
+  pub scale: f32,
+  pub data: [F8; N],
+}
+
+impl<const N: usize> ScaledF8Array<N> {
+  /// Quantizes with a scale mapping the largest magnitude onto `F8::MAX`
+  pub fn quantize(v: [f32; N]) -> Self {
+    let scale = absmax_scale(&v);
+    ScaledF8Array {
+      scale,
+      data: v.map(|c| F8::approx_from(c / scale)),
+    }
+  }
+  pub fn dequantize(&self) -> [f32; N] { self.data.map(|f| f.v() * self.scale) }
+}
+
+impl From<Vec3> for ScaledF8Array<3> {
+  fn from(v: Vec3) -> Self { Self::quantize(v.to_array()) }
+}
+
+impl From<ScaledF8Array<3>> for Vec3 {
+  fn from(a: ScaledF8Array<3>) -> Self { Vec3::from_array(a.dequantize()) }
+}
+
+impl From<Vec4> for ScaledF8Array<4> {
+  fn from(v: Vec4) -> Self { Self::quantize(v.to_array()) }
+}
+
+impl From<ScaledF8Array<4>> for Vec4 {
+  fn from(a: ScaledF8Array<4>) -> Self { Vec4::from_array(a.dequantize()) }
+}
+
+/// Column major, as `Mat4::to_cols_array`
+impl From<Mat4> for ScaledF8Array<16> {
+  fn from(m: Mat4) -> Self { Self::quantize(m.to_cols_array()) }
+}
+
+impl From<ScaledF8Array<16>> for Mat4 {
+  fn from(a: ScaledF8Array<16>) -> Self { Mat4::from_cols_array(&a.dequantize()) }
+}
+
+/// Converts each component to the nearest F8, without scaling
+pub fn vec3_to_f8(v: Vec3) -> [F8; 3] { v.to_array().map(F8::approx_from) }
+
+pub fn f8_to_vec3(a: [F8; 3]) -> Vec3 { Vec3::from_array(a.map(F8::v)) }
+
+/// Converts each component to the nearest F8, without scaling
+pub fn vec4_to_f8(v: Vec4) -> [F8; 4] { v.to_array().map(F8::approx_from) }
+
+pub fn f8_to_vec4(a: [F8; 4]) -> Vec4 { Vec4::from_array(a.map(F8::v)) }
+
+/// Quantizes a buffer of vectors, such as vertex positions, with one scale shared by all
+pub fn quantize_vec3s(vs: &[Vec3]) -> ScaledF8Tensor {
+  let flat: Vec<f32> = vs.iter().flat_map(|v| v.to_array()).collect();
+  ScaledF8Tensor::quantize(&flat)
+}
+
+/// The inverse of `quantize_vec3s`
+pub fn dequantize_vec3s(t: &ScaledF8Tensor) -> Vec<Vec3> {
+  t.data
+    .chunks_exact(3)
+    .map(|c| Vec3::new(c[0].v(), c[1].v(), c[2].v()) * t.scale)
+    .collect()
+}
@@ -0,0 +1,153 @@
+//! How finely F8 covers the number line, for judging whether data fits the format.
+
+use crate::f8::{ASCENDING, F8};
+
+/// The non-negative values sharing one exponent
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Bucket {
+  pub exponent: u8,
+  /// The smallest and largest value with this exponent
+  pub min: f32,
+  pub max: f32,
+  /// The gap between adjacent values
+  pub spacing: f32,
+  /// The number of distinct values
+  pub count: usize,
+}
+
+/// How F8 covers `lo..=hi`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Coverage {
+  pub lo: f32,
+  pub hi: f32,
+  /// The number of distinct values in the range
+  pub values: usize,
+  /// The fraction of the range beyond `F8::MAX` in magnitude, which saturates
+  pub clipped: f32,
+  /// The largest gap between adjacent values within the range, so rounding a value in range
+  /// errs by at most half of it
+  pub max_gap: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridReport {
+  /// Ordered by exponent, and so by magnitude
+  pub buckets: Vec<Bucket>,
+  pub coverage: Coverage,
+}
+
+/// The spacing of F8 values per exponent, and how they cover `lo..=hi`
+pub fn grid_report(lo: f32, hi: f32) -> GridReport {
+  assert!(lo <= hi, "Empty range");
+  let mut buckets: Vec<Bucket> = vec![];
+  for f in ASCENDING.iter() {
+    match buckets.last_mut() {
+      Some(b) if b.exponent == f.exponent() => {
+        b.max = f.v();
+        b.count += 1;
+      },
+      _ => buckets.push(Bucket {
+        exponent: f.exponent(),
+        min: f.v(),
+        max: f.v(),
+        spacing: 2f32.powi(f.exponent() as i32 - crate::f8::BIAS as i32),
+        count: 1,
+      }),
+    }
+  }
+  let values: Vec<f32> = F8::values_ascending()
+    .map(F8::v)
+    .filter(|v| (lo..=hi).contains(v))
+    .collect();
+  let max = F8::MAX.v();
+  let inside = hi.min(max) - lo.max(-max);
+  let clipped = if hi > lo {
+    1.0 - inside.max(0.0) / (hi - lo)
+  } else {
+    (lo.abs() > max) as u8 as f32
+  };
+  // gaps between the values in range, and from the range's ends to its nearest values
+  let max_gap = F8::values_ascending()
+    .map(F8::v)
+    .collect::<Vec<_>>()
+    .windows(2)
+    .filter(|w| w[1] > lo.max(-max) && w[0] < hi.min(max))
+    .map(|w| w[1] - w[0])
+    .fold(0.0, f32::max);
+  GridReport {
+    buckets,
+    coverage: Coverage {
+      lo,
+      hi,
+      values: values.len(),
+      clipped,
+      max_gap,
+    },
+  }
+}
+
+/// Relative error of `F8::approx_from` over the f32 values in `[2^exponent, 2^(exponent + 1))`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BinadeError {
+  pub exponent: i32,
+  pub max_rel: f32,
+  pub mean_rel: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionProfile {
+  /// Ordered by exponent
+  pub binades: Vec<BinadeError>,
+  /// The largest magnitude which rounds to zero
+  pub underflow: f32,
+  /// The smallest magnitude which is clipped to `F8::MAX`, rather than rounded within half a
+  /// step of it
+  pub overflow: f32,
+}
+
+/// Binades swept, from one wholly rounding to zero to one wholly saturating
+const PROFILE_EXPONENTS: std::ops::RangeInclusive<i32> = -5..=10;
+/// Values sampled in each binade, evenly spaced
+const PROFILE_SAMPLES: u32 = 1 << 12;
+
+/// The smallest positive f32 for which `f` holds, where it holds for everything above it too
+fn first_positive(f: impl Fn(f32) -> bool) -> f32 {
+  let (mut lo, mut hi) = (0u32, f32::MAX.to_bits());
+  while lo < hi {
+    let mid = lo + (hi - lo) / 2;
+    if f(f32::from_bits(mid)) {
+      hi = mid;
+    } else {
+      lo = mid + 1;
+    }
+  }
+  f32::from_bits(lo)
+}
+
+/// The relative error of converting f32 to F8 across its range, and where it stops rounding
+pub fn conversion_error_profile() -> ConversionProfile {
+  let binades = PROFILE_EXPONENTS
+    .map(|exponent| {
+      let base = 2f32.powi(exponent);
+      let (mut max_rel, mut sum) = (0f32, 0f64);
+      for i in 0..PROFILE_SAMPLES {
+        let v = base * (1.0 + i as f32 / PROFILE_SAMPLES as f32);
+        let rel = (F8::approx_from(v).v() - v).abs() / v;
+        max_rel = max_rel.max(rel);
+        sum += rel as f64;
+      }
+      BinadeError {
+        exponent,
+        max_rel,
+        mean_rel: (sum / PROFILE_SAMPLES as f64) as f32,
+      }
+    })
+    .collect();
+  let zero = first_positive(|v| F8::approx_from(v).v() != 0.0);
+  let half_step = (F8::MAX.v() - ASCENDING[ASCENDING.len() - 2].v()) / 2.0;
+  ConversionProfile {
+    binades,
+    underflow: f32::from_bits(zero.to_bits() - 1),
+    overflow: first_positive(|v| v - F8::MAX.v() > half_step),
+  }
+}
@@ -0,0 +1,87 @@
+//! Heightfield quantization which avoids terracing.
+//!
+//! Heights are centered on the middle of their range and scaled onto `[-F8::MAX, F8::MAX]`,
+//! then each scanline is quantized with error diffusion, alternating direction between rows.
+//! In flat regions where terraces are most visible the error carries fully to the next
+//! texel, and with a slope weight it carries less where the terrain is steep, where rounding
+//! error hides in the relief instead.
+
+use crate::f8::F8;
+
+/// A quantized heightfield, where height is `offset + scale * data[i]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeightmapF8 {
+  pub width: usize,
+  pub height: usize,
+  pub offset: f32,
+  pub scale: f32,
+  pub data: Vec<F8>,
+}
+
+impl HeightmapF8 {
+  /// Quantizes with full error diffusion along each scanline
+  pub fn quantize(src: &[f32], width: usize, height: usize) -> Self {
+    Self::quantize_slope_weighted(src, width, height, 0.0)
+  }
+  /// Quantizes while scaling the diffused error at each texel by `1 / (1 + slope_weight * s)`,
+  /// where `s` is the magnitude of the local gradient in height units per texel.
+  pub fn quantize_slope_weighted(
+    src: &[f32],
+    width: usize,
+    height: usize,
+    slope_weight: f32,
+  ) -> Self {
+    assert_eq!(src.len(), width * height, "Mismatched size");
+    let (lo, hi) = src
+      .iter()
+      .fold((f32::INFINITY, f32::NEG_INFINITY), |(l, h), &v| {
+        (l.min(v), h.max(v))
+      });
+    let (offset, range) = if lo <= hi {
+      ((lo + hi) / 2.0, hi - lo)
+    } else {
+      (0.0, 0.0)
+    };
+    let scale = if range > 0.0 {
+      range / 2.0 / F8::MAX.v()
+    } else {
+      1.0
+    };
+    let at = |x: usize, y: usize| src[y * width + x];
+    let slope = |x: usize, y: usize| {
+      let dx = at((x + 1).min(width - 1), y) - at(x.saturating_sub(1), y);
+      let dy = at(x, (y + 1).min(height - 1)) - at(x, y.saturating_sub(1));
+      (dx * dx + dy * dy).sqrt() / 2.0
+    };
+    let mut data = vec![F8::from_bits(0); src.len()];
+    for y in 0..height {
+      let mut carry = 0.0;
+      for i in 0..width {
+        let x = if y % 2 == 0 { i } else { width - 1 - i };
+        let v = (at(x, y) - offset) / scale + carry;
+        let q = F8::approx_from(v);
+        let w = if slope_weight > 0.0 {
+          1.0 / (1.0 + slope_weight * slope(x, y))
+        } else {
+          1.0
+        };
+        carry = (v - q.v()) * w;
+        data[y * width + x] = q;
+      }
+    }
+    HeightmapF8 {
+      width,
+      height,
+      offset,
+      scale,
+      data,
+    }
+  }
+  pub fn dequantize(&self) -> Vec<f32> {
+    self
+      .data
+      .iter()
+      .map(|f| self.offset + f.v() * self.scale)
+      .collect()
+  }
+}
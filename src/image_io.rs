@@ -0,0 +1,95 @@
+//! Conversion between `image` f32 buffers and F8 backed pixel buffers.
+
+use crate::{f8::F8, scaled::absmax_scale};
+use image::{ImageBuffer, Luma, Pixel, Rgb};
+
+/// How pixel values are scaled before conversion to F8
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ImageScale {
+  /// One scale shared by every channel of the image
+  PerImage,
+  /// One scale for each channel
+  PerChannel,
+}
+
+/// An image with F8 channels stored interleaved, one byte per channel
+#[derive(Debug, Clone, PartialEq)]
+pub struct F8Image {
+  pub width: u32,
+  pub height: u32,
+  pub channels: usize,
+  /// Either one scale, or one per channel
+  pub scales: Vec<f32>,
+  pub data: Vec<F8>,
+}
+
+impl F8Image {
+  /// Quantizes interleaved channel data
+  pub fn from_interleaved(
+    width: u32,
+    height: u32,
+    channels: usize,
+    src: &[f32],
+    mode: ImageScale,
+  ) -> Self {
+    assert_eq!(
+      src.len(),
+      width as usize * height as usize * channels,
+      "Mismatched size"
+    );
+    let scales: Vec<f32> = match mode {
+      ImageScale::PerImage => vec![absmax_scale(src)],
+      ImageScale::PerChannel => (0..channels)
+        .map(|c| {
+          let ch: Vec<f32> = src.iter().skip(c).step_by(channels).copied().collect();
+          absmax_scale(&ch)
+        })
+        .collect(),
+    };
+    let data = src
+      .iter()
+      .enumerate()
+      .map(|(i, &v)| F8::approx_from(v / scales[(i % channels) % scales.len()]))
+      .collect();
+    F8Image {
+      width,
+      height,
+      channels,
+      scales,
+      data,
+    }
+  }
+  /// Dequantizes into interleaved channel data
+  pub fn to_interleaved(&self) -> Vec<f32> {
+    let n = self.scales.len();
+    self
+      .data
+      .iter()
+      .enumerate()
+      .map(|(i, f)| f.v() * self.scales[(i % self.channels) % n])
+      .collect()
+  }
+  fn from_buffer<P: Pixel<Subpixel = f32>>(
+    img: &ImageBuffer<P, Vec<f32>>,
+    mode: ImageScale,
+  ) -> Self {
+    let (w, h) = img.dimensions();
+    Self::from_interleaved(w, h, P::CHANNEL_COUNT as usize, img.as_raw(), mode)
+  }
+  fn to_buffer<P: Pixel<Subpixel = f32>>(&self) -> Option<ImageBuffer<P, Vec<f32>>> {
+    if self.channels != P::CHANNEL_COUNT as usize {
+      return None;
+    }
+    ImageBuffer::from_raw(self.width, self.height, self.to_interleaved())
+  }
+  pub fn from_rgb32f(img: &ImageBuffer<Rgb<f32>, Vec<f32>>, mode: ImageScale) -> Self {
+    Self::from_buffer(img, mode)
+  }
+  pub fn from_luma32f(img: &ImageBuffer<Luma<f32>, Vec<f32>>) -> Self {
+    Self::from_buffer(img, ImageScale::PerImage)
+  }
+  /// Converts back to an RGB image, or None if this image does not have 3 channels
+  pub fn to_rgb32f(&self) -> Option<ImageBuffer<Rgb<f32>, Vec<f32>>> { self.to_buffer() }
+  /// Converts back to a single channel image, or None if this image has more channels
+  pub fn to_luma32f(&self) -> Option<ImageBuffer<Luma<f32>, Vec<f32>>> { self.to_buffer() }
+}
@@ -0,0 +1,78 @@
+//! Interval arithmetic over F8, rounding every bound outward so the result always encloses
+//! the exact result for any values in the operands.
+
+use crate::f8::{bracket, F8};
+use core::ops::Neg;
+
+/// The closed interval `lo..=hi`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct F8Interval {
+  pub lo: F8,
+  pub hi: F8,
+}
+
+/// The largest F8 at or below `x`, if any
+fn round_down(x: f32) -> Option<F8> {
+  if x < -F8::MAX.v() {
+    None
+  } else if x >= 0.0 {
+    Some(bracket(x).0)
+  } else {
+    Some(-bracket(-x).1)
+  }
+}
+
+/// The smallest F8 at or above `x`, if any
+fn round_up(x: f32) -> Option<F8> { round_down(-x).map(|f| -f) }
+
+impl F8Interval {
+  pub fn new(lo: F8, hi: F8) -> Self {
+    assert!(lo.v() <= hi.v(), "Interval bounds out of order");
+    F8Interval { lo, hi }
+  }
+  /// The interval holding exactly `f`
+  pub fn point(f: F8) -> Self { F8Interval { lo: f, hi: f } }
+  /// The narrowest interval holding `v`, unless it is beyond `F8::MAX` in magnitude or NaN
+  pub fn enclosing(v: f32) -> Option<Self> { F8Interval::from_bounds(v, v) }
+  /// Rounds `lo` down and `hi` up, failing if either leaves the range of F8 or is NaN
+  fn from_bounds(lo: f32, hi: f32) -> Option<Self> {
+    if lo.is_nan() || hi.is_nan() {
+      return None;
+    }
+    Some(F8Interval {
+      lo: round_down(lo)?,
+      hi: round_up(hi)?,
+    })
+  }
+  pub fn contains(self, v: f32) -> bool { self.lo.v() <= v && v <= self.hi.v() }
+  pub fn width(self) -> f32 { self.hi.v() - self.lo.v() }
+  /// An enclosure of every sum, or `None` when it does not fit within `F8::MAX`
+  pub fn checked_add(self, o: Self) -> Option<Self> {
+    // exact in f32, see `impl Add for F8`, so only the final rounding is directed
+    F8Interval::from_bounds(self.lo.v() + o.lo.v(), self.hi.v() + o.hi.v())
+  }
+  /// An enclosure of every difference, or `None` when it does not fit within `F8::MAX`
+  pub fn checked_sub(self, o: Self) -> Option<Self> { self.checked_add(-o) }
+  /// An enclosure of every product, or `None` when it does not fit within `F8::MAX`
+  pub fn checked_mul(self, o: Self) -> Option<Self> {
+    let p = [
+      self.lo.v() * o.lo.v(),
+      self.lo.v() * o.hi.v(),
+      self.hi.v() * o.lo.v(),
+      self.hi.v() * o.hi.v(),
+    ];
+    let lo = p.iter().copied().fold(f32::INFINITY, f32::min);
+    let hi = p.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    F8Interval::from_bounds(lo, hi)
+  }
+}
+
+impl Neg for F8Interval {
+  type Output = Self;
+  fn neg(self) -> Self {
+    F8Interval {
+      lo: -self.hi,
+      hi: -self.lo,
+    }
+  }
+}
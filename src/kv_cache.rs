@@ -0,0 +1,115 @@
+use crate::{f8::F8, scaled::absmax_scale};
+
+/// An append only attention key/value cache stored as F8, with one scale per token and head.
+///
+/// Entries are laid out `[token][head][dim]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KvCacheF8 {
+  num_heads: usize,
+  head_dim: usize,
+  keys: Vec<F8>,
+  values: Vec<F8>,
+  key_scales: Vec<f32>,
+  value_scales: Vec<f32>,
+}
+
+fn append_scaled(src: &[f32], head_dim: usize, data: &mut Vec<F8>, scales: &mut Vec<f32>) {
+  for head in src.chunks_exact(head_dim) {
+    let scale = absmax_scale(head);
+    data.extend(head.iter().map(|&v| F8::approx_from(v / scale)));
+    scales.push(scale);
+  }
+}
+
+impl KvCacheF8 {
+  pub fn new(num_heads: usize, head_dim: usize) -> Self {
+    assert!(num_heads > 0 && head_dim > 0, "Empty heads");
+    KvCacheF8 {
+      num_heads,
+      head_dim,
+      keys: vec![],
+      values: vec![],
+      key_scales: vec![],
+      value_scales: vec![],
+    }
+  }
+  pub fn num_heads(&self) -> usize { self.num_heads }
+  pub fn head_dim(&self) -> usize { self.head_dim }
+  /// Number of tokens stored
+  pub fn len(&self) -> usize { self.key_scales.len() / self.num_heads }
+  pub fn is_empty(&self) -> bool { self.key_scales.is_empty() }
+  /// Appends one token's keys and values, each `num_heads * head_dim` long
+  pub fn append(&mut self, k: &[f32], v: &[f32]) {
+    let n = self.num_heads * self.head_dim;
+    assert_eq!(k.len(), n, "Keys are not num_heads * head_dim");
+    assert_eq!(v.len(), n, "Values are not num_heads * head_dim");
+    append_scaled(k, self.head_dim, &mut self.keys, &mut self.key_scales);
+    append_scaled(v, self.head_dim, &mut self.values, &mut self.value_scales);
+  }
+  /// Drops every token past the first `len`
+  pub fn truncate(&mut self, len: usize) {
+    let n = len * self.num_heads;
+    self.key_scales.truncate(n);
+    self.value_scales.truncate(n);
+    self.keys.truncate(n * self.head_dim);
+    self.values.truncate(n * self.head_dim);
+  }
+  fn entry(&self, token: usize, head: usize) -> (usize, std::ops::Range<usize>) {
+    assert!(head < self.num_heads, "Head {} out of range", head);
+    let i = token * self.num_heads + head;
+    (i, i * self.head_dim..(i + 1) * self.head_dim)
+  }
+  /// The dequantized key for `token` and `head`
+  pub fn key(&self, token: usize, head: usize, out: &mut [f32]) {
+    assert_eq!(out.len(), self.head_dim, "out is not head_dim long");
+    let (i, r) = self.entry(token, head);
+    for (o, f) in out.iter_mut().zip(&self.keys[r]) {
+      *o = f.v() * self.key_scales[i];
+    }
+  }
+  /// The dequantized value for `token` and `head`
+  pub fn value(&self, token: usize, head: usize, out: &mut [f32]) {
+    assert_eq!(out.len(), self.head_dim, "out is not head_dim long");
+    let (i, r) = self.entry(token, head);
+    for (o, f) in out.iter_mut().zip(&self.values[r]) {
+      *o = f.v() * self.value_scales[i];
+    }
+  }
+  /// Computes the scaled dot product `q . k_t / sqrt(head_dim)` of a query against every
+  /// cached key of `head`, into `out` which has one entry per token.
+  pub fn scores(&self, head: usize, q: &[f32], out: &mut [f32]) {
+    assert_eq!(q.len(), self.head_dim, "Query is not head_dim long");
+    assert_eq!(
+      out.len(),
+      self.len(),
+      "out does not have one entry per token"
+    );
+    let norm = 1.0 / (self.head_dim as f32).sqrt();
+    for (t, o) in out.iter_mut().enumerate() {
+      let (i, r) = self.entry(t, head);
+      let dot: f32 = self.keys[r].iter().zip(q).map(|(k, &q)| k.v() * q).sum();
+      *o = dot * self.key_scales[i] * norm;
+    }
+  }
+  /// Computes softmax attention of a query over every cached token of `head`, writing the
+  /// weighted sum of values into `out`.
+  pub fn attend(&self, head: usize, q: &[f32], out: &mut [f32]) {
+    assert_eq!(out.len(), self.head_dim, "out is not head_dim long");
+    let mut w = vec![0.0; self.len()];
+    self.scores(head, q, &mut w);
+    let max = w.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let mut sum = 0.0;
+    for s in w.iter_mut() {
+      *s = (*s - max).exp();
+      sum += *s;
+    }
+    out.iter_mut().for_each(|o| *o = 0.0);
+    for (t, &wt) in w.iter().enumerate() {
+      let (i, r) = self.entry(t, head);
+      let s = wt / sum * self.value_scales[i];
+      for (o, v) in out.iter_mut().zip(&self.values[r]) {
+        *o += s * v.v();
+      }
+    }
+  }
+}
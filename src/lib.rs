@@ -1,3 +1,334 @@
+//! 8 bit floating point numbers.
+//!
+//! Without the default `std` feature the crate is `no_std`, keeping the formats in `f8`,
+//! `ofp8`, `e8m0`, `d8` and `packed`, their arithmetic, and the `interval`, `tracked`,
+//! `complex`, `minifloat`, `literal`, `bytes` and `ffi` modules. The `alloc` feature adds
+//! parsing and the helpers which return strings or vectors, and `libm` supplies `sqrt`.
+//! Conversions are integer only, so need neither.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod activation;
+#[cfg(feature = "std")]
+pub mod adaround;
+#[cfg(feature = "arrow")]
+pub mod arrow_io;
+#[cfg(feature = "bytemuck")]
+pub mod bytemuck_io;
+pub mod bytes;
+#[cfg(feature = "std")]
+pub mod calibration;
+#[cfg(feature = "std")]
+pub mod channel;
+#[cfg(feature = "std")]
+pub mod checksum;
+#[cfg(feature = "std")]
+pub mod codebook;
+#[cfg(feature = "std")]
+pub mod codec;
+#[cfg(feature = "std")]
+pub mod color;
+#[cfg(feature = "std")]
+pub mod companding;
+pub mod complex;
+#[cfg(feature = "std")]
+pub mod conv;
+pub mod d8;
+#[cfg(feature = "std")]
+pub mod distance;
+#[cfg(feature = "std")]
+pub mod dual;
+pub mod e8m0;
+#[cfg(feature = "std")]
+pub mod embedding;
+#[cfg(feature = "exact")]
+pub mod exact;
 pub mod f8;
+pub mod ffi;
+#[cfg(feature = "std")]
+pub mod fft;
+#[cfg(feature = "std")]
+pub mod filter;
+#[cfg(feature = "std")]
+pub mod fixed;
+pub mod format;
+#[cfg(feature = "std")]
+pub mod gguf;
+#[cfg(feature = "glam")]
+pub mod glam_io;
+#[cfg(feature = "std")]
+pub mod grid;
+#[cfg(feature = "std")]
+pub mod heightmap;
+#[cfg(feature = "image")]
+pub mod image_io;
+pub mod interval;
+#[cfg(feature = "std")]
+pub mod kv_cache;
+#[cfg(feature = "std")]
+pub mod linalg;
+pub mod literal;
+#[cfg(feature = "std")]
+pub mod loss_scale;
+mod math;
+pub mod minifloat;
+#[cfg(feature = "std")]
+pub mod mx;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra_io;
+#[cfg(feature = "ndarray")]
+pub mod ndarray_io;
+#[cfg(feature = "std")]
+pub mod norm;
+#[cfg(feature = "std")]
+pub mod normal;
+#[cfg(feature = "std")]
+pub mod npy;
+#[cfg(feature = "std")]
+pub mod number_line;
+pub mod ofp8;
+#[cfg(feature = "std")]
+pub mod onnx;
+#[cfg(feature = "std")]
+pub mod outlier;
+pub mod packed;
+#[cfg(feature = "polars")]
+pub mod polars_io;
+#[cfg(feature = "std")]
+pub mod pq;
+#[cfg(feature = "std")]
+pub mod projection;
+#[cfg(feature = "proptest")]
+pub mod proptest_io;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "std")]
+pub mod quantize;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck_io;
+#[cfg(feature = "rand")]
+pub mod rand_io;
+#[cfg(feature = "num-rational")]
+pub mod rational_io;
+#[cfg(feature = "std")]
+pub mod rgbe;
+#[cfg(feature = "rkyv")]
+pub mod rkyv_io;
+#[cfg(feature = "mpfr")]
+pub mod rug_io;
+#[cfg(feature = "safetensors")]
+pub mod safetensors_io;
+#[cfg(feature = "std")]
+pub mod scaled;
+#[cfg(feature = "std")]
+pub mod select;
+#[cfg(feature = "serde")]
+pub mod serde_io;
+pub mod soft;
+#[cfg(feature = "std")]
+pub mod sparse;
+#[cfg(feature = "std")]
+pub mod srgb;
+#[cfg(feature = "std")]
+pub mod stats;
+#[cfg(feature = "std")]
+pub mod storage;
+#[cfg(feature = "std")]
+pub mod stream;
+#[cfg(feature = "std")]
+pub mod tables;
+#[cfg(feature = "std")]
+pub mod tensor;
+#[cfg(all(test, feature = "std"))]
+mod test_activation;
+#[cfg(all(test, feature = "std"))]
+mod test_adaround;
+#[cfg(all(test, feature = "arrow"))]
+mod test_arrow_io;
+#[cfg(all(test, feature = "bytemuck"))]
+mod test_bytemuck_io;
+#[cfg(all(test, feature = "alloc"))]
+mod test_bytes;
+#[cfg(all(test, feature = "std"))]
+mod test_calibration;
+#[cfg(all(test, feature = "std"))]
+mod test_channel;
+#[cfg(all(test, feature = "std"))]
+mod test_checksum;
+#[cfg(all(test, feature = "std"))]
+mod test_codebook;
+#[cfg(all(test, feature = "std"))]
+mod test_codec;
+#[cfg(all(test, feature = "std"))]
+mod test_color;
+#[cfg(all(test, feature = "std"))]
+mod test_companding;
+#[cfg(all(test, feature = "alloc"))]
+mod test_complex;
+#[cfg(all(test, feature = "std"))]
+mod test_conv;
+#[cfg(all(test, feature = "std"))]
+mod test_d8;
+#[cfg(all(test, feature = "std"))]
+mod test_distance;
+#[cfg(all(test, feature = "std"))]
+mod test_dual;
+#[cfg(all(test, feature = "std"))]
+mod test_embedding;
+#[cfg(all(test, feature = "exact"))]
+mod test_exact;
 #[cfg(test)]
 mod test_f8;
+#[cfg(all(test, feature = "std"))]
+mod test_ffi;
+#[cfg(all(test, feature = "std"))]
+mod test_fft;
+#[cfg(all(test, feature = "std"))]
+mod test_filter;
+#[cfg(all(test, feature = "std"))]
+mod test_fixed;
+#[cfg(all(test, feature = "alloc"))]
+mod test_format;
+#[cfg(all(test, feature = "std"))]
+mod test_gguf;
+#[cfg(all(test, feature = "glam"))]
+mod test_glam_io;
+#[cfg(all(test, feature = "std"))]
+mod test_grid;
+#[cfg(all(test, feature = "std"))]
+mod test_heightmap;
+#[cfg(all(test, feature = "image"))]
+mod test_image_io;
+#[cfg(test)]
+mod test_interval;
+#[cfg(all(test, feature = "std"))]
+mod test_kv_cache;
+#[cfg(all(test, feature = "std"))]
+mod test_linalg;
+#[cfg(test)]
+mod test_literal;
+#[cfg(all(test, feature = "std"))]
+mod test_loss_scale;
+#[cfg(all(test, feature = "std"))]
+mod test_minifloat;
+#[cfg(all(test, feature = "nalgebra"))]
+mod test_nalgebra_io;
+#[cfg(all(test, feature = "ndarray"))]
+mod test_ndarray_io;
+#[cfg(all(test, feature = "std"))]
+mod test_norm;
+#[cfg(all(test, feature = "std"))]
+mod test_normal;
+#[cfg(all(test, feature = "std"))]
+mod test_npy;
+#[cfg(all(test, feature = "std"))]
+mod test_number_line;
+#[cfg(test)]
+mod test_ofp8;
+#[cfg(all(test, feature = "std"))]
+mod test_onnx;
+#[cfg(all(test, feature = "std"))]
+mod test_outlier;
+#[cfg(all(test, feature = "alloc"))]
+mod test_packed;
+#[cfg(all(test, feature = "polars"))]
+mod test_polars_io;
+#[cfg(all(test, feature = "std"))]
+mod test_pq;
+#[cfg(all(test, feature = "std"))]
+mod test_projection;
+#[cfg(all(test, feature = "proptest"))]
+mod test_proptest_io;
+#[cfg(all(test, feature = "python"))]
+mod test_python;
+#[cfg(all(test, feature = "std"))]
+mod test_quantize;
+#[cfg(all(test, feature = "quickcheck"))]
+mod test_quickcheck_io;
+#[cfg(all(test, feature = "rand"))]
+mod test_rand_io;
+#[cfg(all(test, feature = "num-rational"))]
+mod test_rational_io;
+#[cfg(all(test, feature = "std"))]
+mod test_rgbe;
+#[cfg(all(test, feature = "rkyv"))]
+mod test_rkyv_io;
+#[cfg(all(test, feature = "mpfr"))]
+mod test_rug_io;
+#[cfg(all(test, feature = "safetensors"))]
+mod test_safetensors_io;
+#[cfg(all(test, feature = "std"))]
+mod test_scaled;
+#[cfg(all(test, feature = "std"))]
+mod test_select;
+#[cfg(all(test, feature = "serde"))]
+mod test_serde_io;
+#[cfg(test)]
+mod test_soft;
+#[cfg(all(test, feature = "std"))]
+mod test_sparse;
+#[cfg(all(test, feature = "std"))]
+mod test_srgb;
+#[cfg(all(test, feature = "std"))]
+mod test_stats;
+#[cfg(all(test, feature = "std"))]
+mod test_storage;
+#[cfg(all(test, feature = "std"))]
+mod test_stream;
+#[cfg(all(test, feature = "std"))]
+mod test_tables;
+#[cfg(all(test, feature = "std"))]
+mod test_tensor;
+#[cfg(all(test, feature = "std"))]
+mod test_texture;
+#[cfg(test)]
+mod test_tracked;
+#[cfg(all(test, feature = "std"))]
+mod test_vector_store;
+#[cfg(all(test, feature = "wasm"))]
+mod test_wasm;
+#[cfg(all(test, feature = "zerocopy"))]
+mod test_zerocopy;
+#[cfg(feature = "std")]
+pub mod texture;
+pub mod tracked;
+#[cfg(feature = "std")]
+pub mod vector_store;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub use bytes::{as_bytes, from_bytes};
+#[cfg(feature = "std")]
+pub use calibration::{calibrate, Calibration};
+#[cfg(feature = "std")]
+pub use checksum::{fingerprint, fingerprint_ordered};
+#[cfg(feature = "std")]
+pub use codec::{delta_decode, delta_encode};
+#[cfg(feature = "std")]
+pub use distance::{
+  cosine_similarity, cosine_similarity_rows, l2_distance_sq, l2_distance_sq_rows,
+};
+#[cfg(feature = "std")]
+pub use filter::convolve;
+#[cfg(feature = "std")]
+pub use grid::{conversion_error_profile, grid_report};
+#[cfg(feature = "std")]
+pub use linalg::{cumsum, gemv};
+#[cfg(feature = "std")]
+pub use minifloat::check_properties;
+pub use minifloat::Minifloat;
+#[cfg(feature = "std")]
+pub use norm::rms_norm;
+#[cfg(feature = "std")]
+pub use quantize::{quantize_dithered_2d, quantize_stochastic};
+#[cfg(feature = "std")]
+pub use select::quantiles;
+#[cfg(feature = "std")]
+pub use stream::{F8Reader, F8Writer};
+#[cfg(feature = "std")]
+pub use tables::export_tables;
+#[cfg(feature = "std")]
+pub use tensor::F8Tensor;
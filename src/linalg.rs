@@ -0,0 +1,238 @@
+use crate::f8::{dec, F8};
+
+/// Dot product of two F8 slices of equal length, accumulated in f32
+pub fn dot(a: &[F8], b: &[F8]) -> f32 {
+  assert_eq!(a.len(), b.len(), "Mismatched lengths");
+  let mut acc = [0f32; 4];
+  let (ca, cb) = (a.chunks_exact(4), b.chunks_exact(4));
+  let tail: f32 = ca
+    .remainder()
+    .iter()
+    .zip(cb.remainder())
+    .map(|(&x, &y)| dec(x) * dec(y))
+    .sum();
+  for (x, y) in ca.zip(cb) {
+    for i in 0..4 {
+      acc[i] += dec(x[i]) * dec(y[i]);
+    }
+  }
+  acc.iter().sum::<f32>() + tail
+}
+
+/// The sum of magnitudes, accumulated in f64
+pub fn norm_l1(x: &[F8]) -> f32 { x.iter().map(|&v| dec(v).abs() as f64).sum::<f64>() as f32 }
+
+/// The Euclidean norm, accumulated in f64 with elements scaled by the largest magnitude so
+/// the sum of squares cannot overflow
+pub fn norm_l2(x: &[F8]) -> f32 {
+  let max = norm_inf(x) as f64;
+  if max == 0.0 {
+    return 0.0;
+  }
+  let sq: f64 = x.iter().map(|&v| (dec(v) as f64 / max).powi(2)).sum();
+  (max * sq.sqrt()) as f32
+}
+
+/// The largest magnitude
+pub fn norm_inf(x: &[F8]) -> f32 { x.iter().map(|&v| dec(v).abs()).fold(0.0, f32::max) }
+
+/// Writes the running totals of `x` to `out`, carrying the total in f32 and rounding only
+/// each output, so later outputs do not inherit the rounding of earlier ones
+pub fn cumsum(x: &[F8], out: &mut [F8]) {
+  assert_eq!(x.len(), out.len(), "Mismatched lengths");
+  let mut total = 0f32;
+  for (o, &v) in out.iter_mut().zip(x) {
+    total += dec(v);
+    *o = F8::approx_from(total);
+  }
+}
+
+/// Computes `y += a * x`, decoding `x` as it is accumulated
+pub fn axpy(a: f32, x: &[F8], y: &mut [f32]) {
+  assert_eq!(x.len(), y.len(), "Mismatched lengths");
+  for (y, &x) in y.iter_mut().zip(x) {
+    *y += a * dec(x);
+  }
+}
+
+/// Computes `y = a * x + b * y`, decoding `x` as it is accumulated
+pub fn axpby(a: f32, x: &[F8], b: f32, y: &mut [f32]) {
+  assert_eq!(x.len(), y.len(), "Mismatched lengths");
+  for (y, &x) in y.iter_mut().zip(x) {
+    *y = a * dec(x) + b * *y;
+  }
+}
+
+/// Computes `out = a * b` where `a` is `m x k`, `b` is `k x n`, and `out` is `m x n`,
+/// all row major, accumulating in f32.
+///
+/// Both matrices are decoded into panels by `pack_a_f32` and `pack_b_f32`, and each
+/// `MR x NR` block of `out` is accumulated from one panel of each.
+pub fn gemm(a: &[F8], b: &[F8], m: usize, k: usize, n: usize, out: &mut [f32]) {
+  assert_eq!(a.len(), m * k, "a is not m x k");
+  assert_eq!(b.len(), k * n, "b is not k x n");
+  assert_eq!(out.len(), m * n, "out is not m x n");
+  if k == 0 {
+    out.iter_mut().for_each(|o| *o = 0.0);
+    return;
+  }
+  let (pa, pb) = (pack_a_f32(a, k, m, k), pack_b_f32(b, n, k, n));
+  for (pi, a_panel) in pa.chunks_exact(k * MR).enumerate() {
+    let rows = MR.min(m - pi * MR);
+    for (pj, b_panel) in pb.chunks_exact(k * NR).enumerate() {
+      let mut acc = [[0f32; NR]; MR];
+      for (a_k, b_k) in a_panel.chunks_exact(MR).zip(b_panel.chunks_exact(NR)) {
+        for (acc, &a_ik) in acc.iter_mut().zip(a_k) {
+          for (acc, &b_kj) in acc.iter_mut().zip(b_k) {
+            *acc += a_ik * b_kj;
+          }
+        }
+      }
+      let cols = NR.min(n - pj * NR);
+      for (i, acc) in acc.iter().enumerate().take(rows) {
+        let start = (pi * MR + i) * n + pj * NR;
+        out[start..start + cols].copy_from_slice(&acc[..cols]);
+      }
+    }
+  }
+}
+
+/// Rows of `a` in each panel packed by `pack_a`
+pub const MR: usize = 4;
+/// Columns of `b` in each panel packed by `pack_b`
+pub const NR: usize = 4;
+
+/// Packs panels of `width` consecutive `rows` of a strided matrix, where element `(r, c)` is
+/// `src[r * row_stride + c * col_stride]`.
+///
+/// Each panel stores its `depth` columns one after another, `width` elements each, and the
+/// last panel is padded with `zero`.
+#[allow(clippy::too_many_arguments)]
+fn pack_panels<T: Copy>(
+  src: &[F8],
+  row_stride: usize,
+  col_stride: usize,
+  rows: usize,
+  depth: usize,
+  width: usize,
+  zero: T,
+  f: impl Fn(F8) -> T,
+) -> Vec<T> {
+  let panels = rows.div_ceil(width);
+  let mut packed = vec![zero; panels * depth * width];
+  for (p, panel) in packed.chunks_exact_mut((depth * width).max(1)).enumerate() {
+    for (d, dst) in panel.chunks_exact_mut(width).enumerate() {
+      for (i, v) in dst.iter_mut().enumerate().take(rows - p * width) {
+        *v = f(src[(p * width + i) * row_stride + d * col_stride]);
+      }
+    }
+  }
+  packed
+}
+
+/// Checks that a `rows x cols` matrix with rows `ld` apart lies within `len` elements
+fn check_strided(len: usize, ld: usize, rows: usize, cols: usize) {
+  assert!(ld >= cols, "Leading dimension is less than the row length");
+  assert!(
+    rows == 0 || len >= (rows - 1) * ld + cols,
+    "Matrix does not fit in the slice"
+  );
+}
+
+/// Packs the `m x k` matrix `a`, with rows `lda` apart, into panels of `MR` rows.
+///
+/// Panel `p` holds rows `p * MR..(p + 1) * MR`, column by column, so element `(i, kk)` of the
+/// panel is at `(p * k + kk) * MR + i`. The last panel is padded with zeros.
+pub fn pack_a(a: &[F8], lda: usize, m: usize, k: usize) -> Vec<F8> {
+  check_strided(a.len(), lda, m, k);
+  pack_panels(a, lda, 1, m, k, MR, F8::from_bits(0), |f| f)
+}
+
+/// `pack_a`, decoding each element to f32
+pub fn pack_a_f32(a: &[F8], lda: usize, m: usize, k: usize) -> Vec<f32> {
+  check_strided(a.len(), lda, m, k);
+  pack_panels(a, lda, 1, m, k, MR, 0.0, dec)
+}
+
+/// Packs the `k x n` matrix `b`, with rows `ldb` apart, into panels of `NR` columns.
+///
+/// Panel `p` holds columns `p * NR..(p + 1) * NR`, row by row, so element `(kk, j)` of the
+/// panel is at `(p * k + kk) * NR + j`. The last panel is padded with zeros.
+pub fn pack_b(b: &[F8], ldb: usize, k: usize, n: usize) -> Vec<F8> {
+  check_strided(b.len(), ldb, k, n);
+  pack_panels(b, 1, ldb, n, k, NR, F8::from_bits(0), |f| f)
+}
+
+/// `pack_b`, decoding each element to f32
+pub fn pack_b_f32(b: &[F8], ldb: usize, k: usize, n: usize) -> Vec<f32> {
+  check_strided(b.len(), ldb, k, n);
+  pack_panels(b, 1, ldb, n, k, NR, 0.0, dec)
+}
+
+/// Computes `out[i] = a[i] * b` for each of `batch` matrices `a[i]`, where `a` is
+/// `batch x m x k`, `b` is `k x n`, and `out` is `batch x m x n`, all row major, accumulating
+/// in f32.
+///
+/// The batch is multiplied as one `batch * m x k` matrix, so `b` is decoded and packed once.
+pub fn gemm_batched(
+  a: &[F8],
+  b: &[F8],
+  batch: usize,
+  m: usize,
+  k: usize,
+  n: usize,
+  out: &mut [f32],
+) {
+  assert_eq!(a.len(), batch * m * k, "a is not batch x m x k");
+  assert_eq!(b.len(), k * n, "b is not k x n");
+  assert_eq!(out.len(), batch * m * n, "out is not batch x m x n");
+  gemm(a, b, batch * m, k, n, out)
+}
+
+/// Rows of `a` handled together by `gemv`, so each element of `x` is decoded once per block
+const GEMV_ROWS: usize = 4;
+
+/// Computes `out = a * x` where `a` is `m x n` row major, `x` has length `n` and `out` length
+/// `m`, accumulating in f32.
+///
+/// Blocks of rows share each decoded element of `x`, and every row keeps several accumulators
+/// to keep the adds independent.
+pub fn gemv(a: &[F8], x: &[F8], m: usize, n: usize, out: &mut [f32]) {
+  assert_eq!(a.len(), m * n, "a is not m x n");
+  assert_eq!(x.len(), n, "x is not of length n");
+  assert_eq!(out.len(), m, "out is not of length m");
+  if n == 0 {
+    out.iter_mut().for_each(|o| *o = 0.0);
+    return;
+  }
+  let blocks = a.chunks_exact(n * GEMV_ROWS);
+  let rest = blocks.remainder();
+  let mut out_blocks = out.chunks_exact_mut(GEMV_ROWS);
+  for (block, o) in blocks.zip(&mut out_blocks) {
+    let rows: [&[F8]; GEMV_ROWS] = core::array::from_fn(|r| &block[r * n..(r + 1) * n]);
+    let mut acc = [[0f32; 4]; GEMV_ROWS];
+    let xs = x.chunks_exact(4);
+    let x_tail = xs.remainder();
+    for (j, xc) in xs.enumerate() {
+      let xc = [dec(xc[0]), dec(xc[1]), dec(xc[2]), dec(xc[3])];
+      for (acc, row) in acc.iter_mut().zip(&rows) {
+        let rc = &row[j * 4..j * 4 + 4];
+        for l in 0..4 {
+          acc[l] += dec(rc[l]) * xc[l];
+        }
+      }
+    }
+    let tail_start = n - x_tail.len();
+    for ((o, acc), row) in o.iter_mut().zip(&acc).zip(&rows) {
+      let tail: f32 = row[tail_start..]
+        .iter()
+        .zip(x_tail)
+        .map(|(&a, &x)| dec(a) * dec(x))
+        .sum();
+      *o = acc.iter().sum::<f32>() + tail;
+    }
+  }
+  for (row, o) in rest.chunks_exact(n).zip(out_blocks.into_remainder()) {
+    *o = dot(row, x);
+  }
+}
@@ -0,0 +1,41 @@
+//! The `f8!` macro for checked F8 constants.
+
+use crate::f8::F8;
+
+/// Converts a constant expression to an F8 at compile time, failing to compile unless the value
+/// is exactly representable. `f8!(approx v)` rounds to the nearest F8 instead.
+///
+/// ```
+/// use f8::f8;
+/// assert_eq!(f8!(1.5).v(), 1.5);
+/// assert_eq!(f8!(approx 1.1).v(), 1.0);
+/// ```
+///
+/// ```compile_fail
+/// let _ = f8::f8!(0.1);
+/// ```
+#[macro_export]
+macro_rules! f8 {
+  (approx $v:expr) => {{
+    const V: $crate::f8::F8 = $crate::f8::F8::approx_from($v as f32);
+    V
+  }};
+  ($v:expr) => {{
+    const V: $crate::f8::F8 = match $crate::literal::exact($v as f64) {
+      Some(v) => v,
+      None => panic!("literal is not exactly representable as an F8"),
+    };
+    V
+  }};
+}
+
+/// The F8 equal to `v`, if there is one
+#[doc(hidden)]
+pub const fn exact(v: f64) -> Option<F8> {
+  let f = F8::approx_from(v as f32);
+  if F8::DECODE_TABLE[f.to_bits() as usize] as f64 == v {
+    Some(f)
+  } else {
+    None
+  }
+}
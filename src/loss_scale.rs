@@ -0,0 +1,84 @@
+use crate::f8::F8;
+
+/// Dynamic loss scaling for gradients stored as F8.
+///
+/// Gradients are multiplied by the current scale before conversion. Any value which does not
+/// fit in an F8 without clipping counts as an overflow, and a step with overflows shrinks the
+/// scale while a run of clean steps grows it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LossScaler {
+  scale: f32,
+  pub growth_factor: f32,
+  pub backoff_factor: f32,
+  /// Number of consecutive steps without overflow before the scale grows
+  pub growth_interval: u32,
+  clean_steps: u32,
+  step_overflows: u64,
+  total_overflows: u64,
+}
+
+impl Default for LossScaler {
+  fn default() -> Self { LossScaler::new(1.0) }
+}
+
+/// Whether converting `v` to an F8 loses its magnitude, either by clipping or NaN
+#[inline]
+fn overflows(v: f32) -> bool { v.is_nan() || v.abs() > F8::MAX.v() }
+
+impl LossScaler {
+  pub fn new(init_scale: f32) -> Self {
+    LossScaler {
+      scale: init_scale,
+      growth_factor: 2.0,
+      backoff_factor: 0.5,
+      growth_interval: 100,
+      clean_steps: 0,
+      step_overflows: 0,
+      total_overflows: 0,
+    }
+  }
+  pub fn scale(&self) -> f32 { self.scale }
+  /// Scales and converts `grads` into `out`, recording overflows for the current step.
+  /// Returns the number of values which overflowed.
+  pub fn quantize(&mut self, grads: &[f32], out: &mut [F8]) -> usize {
+    assert_eq!(grads.len(), out.len(), "Mismatched lengths");
+    let mut count = 0;
+    for (&g, o) in grads.iter().zip(out.iter_mut()) {
+      let v = g * self.scale;
+      count += overflows(v) as usize;
+      *o = F8::approx_from(v);
+    }
+    self.step_overflows += count as u64;
+    count
+  }
+  /// Converts a quantized gradient back to its unscaled value
+  pub fn unscale(&self, q: F8) -> f32 { q.v() / self.scale }
+  pub fn unscale_into(&self, q: &[F8], out: &mut [f32]) {
+    assert_eq!(q.len(), out.len(), "Mismatched lengths");
+    let inv = 1.0 / self.scale;
+    for (o, &f) in out.iter_mut().zip(q) {
+      *o = f.v() * inv;
+    }
+  }
+  /// Whether the current step has overflowed, meaning its gradients should be skipped
+  pub fn overflowed(&self) -> bool { self.step_overflows > 0 }
+  /// Overflows recorded over all steps
+  pub fn total_overflows(&self) -> u64 { self.total_overflows }
+  /// Ends the current step, adjusting the scale. Returns whether the step overflowed.
+  pub fn update(&mut self) -> bool {
+    let overflowed = self.overflowed();
+    self.total_overflows += self.step_overflows;
+    self.step_overflows = 0;
+    if overflowed {
+      self.scale *= self.backoff_factor;
+      self.clean_steps = 0;
+    } else {
+      self.clean_steps += 1;
+      if self.clean_steps >= self.growth_interval {
+        self.scale *= self.growth_factor;
+        self.clean_steps = 0;
+      }
+    }
+    overflowed
+  }
+}
@@ -0,0 +1,13 @@
+//! The f32 functions the core modules need, which core alone lacks.
+
+/// `2^n` for `n` within the normal range of f32
+pub(crate) const fn pow2(n: i32) -> f32 { f32::from_bits(((n + 127) as u32) << 23) }
+
+/// `2^n` for `n` within the normal range of f64
+pub(crate) const fn pow2_f64(n: i32) -> f64 { f64::from_bits(((n + 1023) as u64) << 52) }
+
+#[cfg(feature = "std")]
+pub(crate) fn sqrt(v: f32) -> f32 { v.sqrt() }
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+pub(crate) fn sqrt(v: f32) -> f32 { libm::sqrtf(v) }
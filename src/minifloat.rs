@@ -0,0 +1,156 @@
+//! A common interface over the 8 bit float formats, and exhaustive checks of the algebraic
+//! properties their arithmetic should have.
+
+use crate::{
+  f8::F8,
+  ofp8::{E4M3, E5M2},
+};
+#[cfg(feature = "std")]
+use std::panic::{self, AssertUnwindSafe};
+
+/// An 8 bit float. The arithmetic defaults to rounding the exact f32 result, which is correct
+/// for any format whose sums and products of two values are exact in f32.
+pub trait Minifloat: Copy {
+  fn from_bits(bits: u8) -> Self;
+  fn to_bits(self) -> u8;
+  fn to_f32(self) -> f32;
+  /// Rounds to the nearest value of the format
+  fn from_f32(f: f32) -> Self;
+  fn add(self, o: Self) -> Self { Self::from_f32(self.to_f32() + o.to_f32()) }
+  fn mul(self, o: Self) -> Self { Self::from_f32(self.to_f32() * o.to_f32()) }
+  fn neg(self) -> Self { Self::from_f32(-self.to_f32()) }
+}
+
+/// F8 is checked through its own operators
+impl Minifloat for F8 {
+  fn from_bits(bits: u8) -> Self { F8::from_bits(bits) }
+  fn to_bits(self) -> u8 { F8::to_bits(self) }
+  fn to_f32(self) -> f32 { self.v() }
+  fn from_f32(f: f32) -> Self { F8::approx_from(f) }
+  fn add(self, o: Self) -> Self { self + o }
+  fn mul(self, o: Self) -> Self { self * o }
+  fn neg(self) -> Self { -self }
+}
+
+impl Minifloat for E4M3 {
+  fn from_bits(bits: u8) -> Self { E4M3(bits) }
+  fn to_bits(self) -> u8 { self.0 }
+  fn to_f32(self) -> f32 { E4M3::to_f32(self) }
+  fn from_f32(f: f32) -> Self { E4M3::from_f32(f) }
+}
+
+impl Minifloat for E5M2 {
+  fn from_bits(bits: u8) -> Self { E5M2(bits) }
+  fn to_bits(self) -> u8 { self.0 }
+  fn to_f32(self) -> f32 { E5M2::to_f32(self) }
+  fn from_f32(f: f32) -> Self { E5M2::from_f32(f) }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Property {
+  /// `a + b == b + a`
+  AddCommutes,
+  /// `a * b == b * a`
+  MulCommutes,
+  /// `-(a + b) == -a + -b`
+  AddSignSymmetric,
+  /// `-(a * b) == -a * b`
+  MulSignSymmetric,
+  /// `a + c <= b + c` for adjacent values `a < b`
+  AddMonotone,
+  /// `a * c <= b * c` for adjacent values `a < b` and non-negative `c`, reversed for negative
+  MulMonotone,
+  /// `from_f32(a.to_f32())` has the value of `a`
+  RoundTrip,
+  /// Computing an operation on the operands panicked
+  Panics,
+}
+
+/// Operands, as bits, for which a property does not hold. For monotonicity, `a` is the smaller
+/// of the adjacent values and `b` the other operand.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+  pub property: Property,
+  pub a: u8,
+  pub b: u8,
+}
+
+/// Equal values, treating both zeros as one and all NaNs as one
+#[cfg(feature = "std")]
+fn same(a: f32, b: f32) -> bool { a == b || (a.is_nan() && b.is_nan()) }
+
+/// Exhaustively checks every property over all operand pairs. Operations which panic are
+/// caught and reported as counterexamples, though the panic hook still prints them.
+#[cfg(feature = "std")]
+pub fn check_properties<T: Minifloat>() -> Vec<Counterexample> {
+  let mut out = vec![];
+  let mut fail = |property, a: T, b: T| {
+    out.push(Counterexample {
+      property,
+      a: a.to_bits(),
+      b: b.to_bits(),
+    })
+  };
+  let eval = |f: &dyn Fn() -> T| panic::catch_unwind(AssertUnwindSafe(f)).ok().map(T::to_f32);
+  let all: Vec<T> = (0..=255).map(T::from_bits).collect();
+  // non-NaN values in ascending order, each paired with the next larger or equal one
+  let mut sorted: Vec<T> = all
+    .iter()
+    .copied()
+    .filter(|v| !v.to_f32().is_nan())
+    .collect();
+  sorted.sort_by(|x, y| x.to_f32().partial_cmp(&y.to_f32()).unwrap());
+  for &a in &all {
+    if !same(T::from_f32(a.to_f32()).to_f32(), a.to_f32()) {
+      fail(Property::RoundTrip, a, a);
+    }
+    for &b in &all {
+      let ops = (
+        eval(&|| a.add(b)),
+        eval(&|| b.add(a)),
+        eval(&|| a.neg().add(b.neg()).neg()),
+        eval(&|| a.mul(b)),
+        eval(&|| b.mul(a)),
+        eval(&|| a.neg().mul(b).neg()),
+      );
+      let (ab, ba, nab, mab, mba, nmab) = match ops {
+        (Some(ab), Some(ba), Some(nab), Some(mab), Some(mba), Some(nmab)) => {
+          (ab, ba, nab, mab, mba, nmab)
+        },
+        _ => {
+          fail(Property::Panics, a, b);
+          continue;
+        },
+      };
+      if !same(ab, ba) {
+        fail(Property::AddCommutes, a, b);
+      }
+      if !same(mab, mba) {
+        fail(Property::MulCommutes, a, b);
+      }
+      if !same(ab, nab) {
+        fail(Property::AddSignSymmetric, a, b);
+      }
+      if !same(mab, nmab) {
+        fail(Property::MulSignSymmetric, a, b);
+      }
+    }
+  }
+  for &c in &sorted {
+    for w in sorted.windows(2) {
+      let (a, b) = (w[0], w[1]);
+      if let (Some(x), Some(y)) = (eval(&|| a.add(c)), eval(&|| b.add(c))) {
+        if x > y {
+          fail(Property::AddMonotone, a, c);
+        }
+      }
+      if let (Some(x), Some(y)) = (eval(&|| a.mul(c)), eval(&|| b.mul(c))) {
+        let positive = c.to_f32() >= 0.0;
+        if (positive && x > y) || (!positive && x < y) {
+          fail(Property::MulMonotone, a, c);
+        }
+      }
+    }
+  }
+  out
+}
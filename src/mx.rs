@@ -0,0 +1,58 @@
+use crate::{channel::Scale, e8m0::E8M0, f8::F8};
+
+/// Number of elements sharing one scale in an MX block
+pub const MX_BLOCK: usize = 32;
+
+/// A microscaling block: 32 F8 values sharing one power of two scale
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MxBlock {
+  pub scale: E8M0,
+  pub data: [F8; MX_BLOCK],
+}
+
+impl MxBlock {
+  /// Quantizes 32 values, choosing the smallest power of two scale which avoids clipping
+  pub fn quantize(v: &[f32; MX_BLOCK]) -> Self {
+    let amax = v.iter().fold(0f32, |m, v| m.max(v.abs()));
+    let scale = E8M0::for_absmax(amax);
+    Self::quantize_with_scale(v, scale)
+  }
+  pub fn quantize_with_scale(v: &[f32; MX_BLOCK], scale: E8M0) -> Self {
+    let inv = 1.0 / scale.to_f32();
+    let mut data = [F8::from_bits(0); MX_BLOCK];
+    for (d, &v) in data.iter_mut().zip(v.iter()) {
+      *d = F8::approx_from(v * inv);
+    }
+    MxBlock { scale, data }
+  }
+  pub fn dequantize(&self) -> [f32; MX_BLOCK] {
+    let s = self.scale.to_f32();
+    let mut out = [0.0; MX_BLOCK];
+    for (o, d) in out.iter_mut().zip(self.data.iter()) {
+      *o = d.v() * s;
+    }
+    out
+  }
+}
+
+/// Quantizes a slice into MX blocks, padding the final block with zeros.
+pub fn quantize_blocks(v: &[f32]) -> Vec<MxBlock> {
+  v.chunks(MX_BLOCK)
+    .map(|c| {
+      let mut block = [0.0; MX_BLOCK];
+      block[..c.len()].copy_from_slice(c);
+      MxBlock::quantize(&block)
+    })
+    .collect()
+}
+
+/// Dequantizes MX blocks into `out`, which may be shorter than the blocks to drop padding.
+pub fn dequantize_blocks(blocks: &[MxBlock], out: &mut [f32]) {
+  assert!(
+    out.len() <= blocks.len() * MX_BLOCK,
+    "out is longer than the blocks"
+  );
+  for (o, b) in out.chunks_mut(MX_BLOCK).zip(blocks) {
+    o.copy_from_slice(&b.dequantize()[..o.len()]);
+  }
+}
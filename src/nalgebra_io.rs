@@ -0,0 +1,40 @@
+//! Support for F8 as an `nalgebra` matrix element.
+//!
+//! F8 already satisfies `nalgebra::Scalar`, so any matrix can store it, and its `Zero`, `One`
+//! and arithmetic impls make constructors and element-wise operations available. It is a
+//! subset of f32 and f64 in nalgebra's sense, so `m.cast::<f32>()` widens exactly.
+
+use crate::f8::F8;
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, OMatrix};
+use simba::scalar::SubsetOf;
+
+impl SubsetOf<f32> for F8 {
+  fn to_superset(&self) -> f32 { self.v() }
+  fn from_superset_unchecked(v: &f32) -> Self { F8::approx_from(*v) }
+  fn is_in_subset(v: &f32) -> bool { F8::try_from(*v).is_some() }
+}
+
+impl SubsetOf<f64> for F8 {
+  fn to_superset(&self) -> f64 { self.v() as f64 }
+  fn from_superset_unchecked(v: &f64) -> Self { F8::approx_from(*v as f32) }
+  fn is_in_subset(v: &f64) -> bool {
+    let f = *v as f32;
+    f as f64 == *v && F8::try_from(f).is_some()
+  }
+}
+
+/// Rounds every element to the nearest F8
+pub fn from_f32_matrix<R: Dim, C: Dim>(m: &OMatrix<f32, R, C>) -> OMatrix<F8, R, C>
+where
+  DefaultAllocator: Allocator<R, C>,
+{
+  m.map(F8::approx_from)
+}
+
+/// Widens every element to f32, the same as `m.cast::<f32>()`
+pub fn to_f32_matrix<R: Dim, C: Dim>(m: &OMatrix<F8, R, C>) -> OMatrix<f32, R, C>
+where
+  DefaultAllocator: Allocator<R, C>,
+{
+  m.map(F8::v)
+}
@@ -0,0 +1,79 @@
+//! Conversion between `ndarray` f32 arrays and scaled F8 arrays.
+
+use crate::{f8::F8, scaled::absmax_scale};
+use ndarray::{ArrayD, ArrayViewD, Axis};
+
+/// How values are scaled before conversion to F8
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Scaling {
+  /// One scale for the whole array
+  PerTensor,
+  /// One scale for each index along the axis
+  PerAxis(usize),
+}
+
+/// An F8 array with its scales, where an element is `scale * data[idx]`, and `scale` is
+/// `scales[idx[axis]]` when scaled per axis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantizedArray {
+  pub scaling: Scaling,
+  pub scales: Vec<f32>,
+  pub data: ArrayD<F8>,
+}
+
+impl QuantizedArray {
+  /// Quantizes with scales mapping the largest magnitude of each group onto `F8::MAX`
+  pub fn quantize(a: ArrayViewD<'_, f32>, scaling: Scaling) -> Self {
+    let scales = match scaling {
+      Scaling::PerTensor => vec![absmax_scale(&a.iter().copied().collect::<Vec<_>>())],
+      Scaling::PerAxis(axis) => a
+        .axis_iter(Axis(axis))
+        .map(|lane| absmax_scale(&lane.iter().copied().collect::<Vec<_>>()))
+        .collect(),
+    };
+    let data = match scaling {
+      Scaling::PerTensor => a.mapv(|v| F8::approx_from(v / scales[0])),
+      Scaling::PerAxis(axis) => {
+        let mut data = ArrayD::from_elem(a.raw_dim(), F8::from_bits(0));
+        let lanes = data.axis_iter_mut(Axis(axis)).zip(a.axis_iter(Axis(axis)));
+        for ((mut d, src), &s) in lanes.zip(&scales) {
+          d.zip_mut_with(&src, |d, &v| *d = F8::approx_from(v / s));
+        }
+        data
+      },
+    };
+    QuantizedArray {
+      scaling,
+      scales,
+      data,
+    }
+  }
+  pub fn dequantize(&self) -> ArrayD<f32> {
+    let mut out = self.data.mapv(F8::v);
+    match self.scaling {
+      Scaling::PerTensor => out *= self.scales[0],
+      Scaling::PerAxis(axis) => {
+        for (mut lane, &s) in out.axis_iter_mut(Axis(axis)).zip(&self.scales) {
+          lane *= s;
+        }
+      },
+    }
+    out
+  }
+}
+
+/// Rounds every element to the nearest F8, without scaling
+pub fn to_f8(a: ArrayViewD<'_, f32>) -> ArrayD<F8> { a.mapv(F8::approx_from) }
+
+/// Widens every element to f32
+pub fn to_f32(a: ArrayViewD<'_, F8>) -> ArrayD<f32> { a.mapv(F8::v) }
+
+/// Applies `f` to the f32 value of every element, rounding each result to F8
+pub fn map_f8(a: ArrayViewD<'_, F8>, f: impl Fn(f32) -> f32) -> ArrayD<F8> {
+  a.mapv(|v| F8::approx_from(f(v.v())))
+}
+
+/// `map_f8` in place
+pub fn map_f8_inplace(a: &mut ArrayD<F8>, f: impl Fn(f32) -> f32) {
+  a.mapv_inplace(|v| F8::approx_from(f(v.v())))
+}
@@ -0,0 +1,49 @@
+//! Normalization layers over F8 activations. Reductions are carried out in f32, as summing
+//! squares in 8 bits saturates almost immediately, and only the outputs are requantized.
+
+use crate::f8::F8;
+
+fn mean_square(x: &[F8]) -> f32 {
+  x.iter().map(|f| f.v() * f.v()).sum::<f32>() / x.len().max(1) as f32
+}
+
+/// Computes `out = x / rms(x)`
+pub fn rms_norm(x: &[F8], eps: f32, out: &mut [F8]) { rms_norm_impl(x, None, eps, out) }
+
+/// Computes `out = x / rms(x) * weight`
+pub fn rms_norm_weighted(x: &[F8], weight: &[F8], eps: f32, out: &mut [F8]) {
+  rms_norm_impl(x, Some(weight), eps, out)
+}
+
+fn rms_norm_impl(x: &[F8], weight: Option<&[F8]>, eps: f32, out: &mut [F8]) {
+  assert_eq!(x.len(), out.len(), "Mismatched lengths");
+  if let Some(w) = weight {
+    assert_eq!(w.len(), x.len(), "Mismatched parameter length");
+  }
+  let inv = 1.0 / (mean_square(x) + eps).sqrt();
+  for (i, (o, f)) in out.iter_mut().zip(x).enumerate() {
+    let w = weight.map_or(1.0, |w| w[i].v());
+    *o = F8::approx_from(f.v() * inv * w);
+  }
+}
+
+/// Computes `out = (x - mean(x)) / std(x) * weight + bias`
+pub fn layer_norm(x: &[F8], weight: Option<&[F8]>, bias: Option<&[F8]>, eps: f32, out: &mut [F8]) {
+  assert_eq!(x.len(), out.len(), "Mismatched lengths");
+  for p in weight.iter().chain(bias.iter()) {
+    assert_eq!(p.len(), x.len(), "Mismatched parameter length");
+  }
+  let n = x.len().max(1) as f32;
+  let mean = x.iter().map(|f| f.v()).sum::<f32>() / n;
+  let var = x
+    .iter()
+    .map(|f| (f.v() - mean) * (f.v() - mean))
+    .sum::<f32>()
+    / n;
+  let inv = 1.0 / (var + eps).sqrt();
+  for (i, (o, f)) in out.iter_mut().zip(x).enumerate() {
+    let w = weight.map_or(1.0, |w| w[i].v());
+    let b = bias.map_or(0.0, |b| b[i].v());
+    *o = F8::approx_from((f.v() - mean) * inv * w + b);
+  }
+}
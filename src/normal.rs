@@ -0,0 +1,114 @@
+//! Unit vectors packed into two F8 by octahedral mapping.
+//!
+//! The octahedral coordinates in [-1, 1] are stored multiplied by `F8::MAX`, so precision is
+//! densest near the octahedron's vertices on each axis, and coarsest along its edges. The
+//! worst case angular error is around 0.09 radians.
+
+use crate::f8::{bracket, F8};
+
+const SCALE: f32 = F8::DECODE_TABLE[F8::MAX.to_bits() as usize];
+
+/// A unit vector stored in 2 bytes
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[repr(transparent)]
+pub struct PackedNormal(pub [F8; 2]);
+
+fn sign(v: f32) -> f32 {
+  if v < 0.0 {
+    -1.0
+  } else {
+    1.0
+  }
+}
+
+fn normalize(v: [f32; 3]) -> [f32; 3] {
+  let n = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+  if n > 0.0 && n.is_finite() {
+    v.map(|c| c / n)
+  } else {
+    [0.0, 0.0, 1.0]
+  }
+}
+
+fn oct_encode(v: [f32; 3]) -> [f32; 2] {
+  let l1 = v[0].abs() + v[1].abs() + v[2].abs();
+  let (x, y) = (v[0] / l1, v[1] / l1);
+  if v[2] < 0.0 {
+    [(1.0 - y.abs()) * sign(x), (1.0 - x.abs()) * sign(y)]
+  } else {
+    [x, y]
+  }
+}
+
+fn oct_decode([x, y]: [f32; 2]) -> [f32; 3] {
+  let z = 1.0 - x.abs() - y.abs();
+  let (x, y) = if z < 0.0 {
+    ((1.0 - y.abs()) * sign(x), (1.0 - x.abs()) * sign(y))
+  } else {
+    (x, y)
+  };
+  normalize([x, y, z])
+}
+
+fn cos_between(a: [f32; 3], b: [f32; 3]) -> f32 { a[0] * b[0] + a[1] * b[1] + a[2] * b[2] }
+
+impl PackedNormal {
+  /// Encodes a vector, normalizing it first. Zero and non-finite vectors encode +Z.
+  ///
+  /// Of the up to four F8 pairs surrounding the exact octahedral coordinates, keeps the one
+  /// which decodes closest in angle.
+  pub fn encode(v: [f32; 3]) -> Self {
+    let v = normalize(v);
+    let oct = oct_encode(v);
+    let candidates = oct.map(|c| {
+      let (lo, hi) = bracket(c.abs() * SCALE);
+      if c.is_sign_negative() {
+        [-lo, -hi]
+      } else {
+        [lo, hi]
+      }
+    });
+    let mut best = PackedNormal([candidates[0][0], candidates[1][0]]);
+    let mut best_cos = f32::NEG_INFINITY;
+    for &x in &candidates[0] {
+      for &y in &candidates[1] {
+        let p = PackedNormal([x, y]);
+        let c = cos_between(p.decode(), v);
+        if c > best_cos {
+          best = p;
+          best_cos = c;
+        }
+      }
+    }
+    best
+  }
+  /// Decodes to a unit vector
+  pub fn decode(self) -> [f32; 3] { oct_decode(self.0.map(|f| f.v() / SCALE)) }
+  /// Angle in radians between `v` and this normal
+  pub fn angular_error(self, v: [f32; 3]) -> f32 {
+    cos_between(self.decode(), normalize(v))
+      .clamp(-1.0, 1.0)
+      .acos()
+  }
+  pub fn to_bits(self) -> u16 { u16::from_le_bytes(self.0.map(F8::to_bits)) }
+  pub fn from_bits(bits: u16) -> Self { PackedNormal(bits.to_le_bytes().map(F8::from_bits)) }
+}
+
+/// Largest angular error in radians of `PackedNormal` over `n` directions spread evenly over
+/// the sphere on a Fibonacci lattice
+pub fn max_angular_error(n: usize) -> f32 {
+  let golden = std::f32::consts::PI * (3.0 - 5f32.sqrt());
+  (0..n)
+    .map(|i| {
+      let z = 1.0 - 2.0 * (i as f32 + 0.5) / n as f32;
+      let r = (1.0 - z * z).sqrt();
+      let t = golden * i as f32;
+      let v = [r * t.cos(), r * t.sin(), z];
+      PackedNormal::encode(v).angular_error(v)
+    })
+    .fold(0.0, f32::max)
+}
@@ -0,0 +1,109 @@
+//! NumPy `.npy` reading and writing of 1 byte float arrays.
+//!
+//! NumPy has no native 8 bit float dtype, so arrays are written with dtype `|u1` holding the
+//! raw bits, and any 1 byte dtype (`|u1`, `|i1`, `|b1`, `|V1`) is accepted when reading. From
+//! Python, view the loaded array with the matching dtype from `ml_dtypes`:
+//! - `E4M3`: `np.load(path).view(ml_dtypes.float8_e4m3fn)`
+//! - `E5M2`: `np.load(path).view(ml_dtypes.float8_e5m2)`
+//! - `F8` has no NumPy equivalent, and is kept as `uint8` bits.
+
+use crate::f8::F8;
+use std::io::{self, Write};
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+fn invalid(msg: &str) -> io::Error { io::Error::new(io::ErrorKind::InvalidData, msg) }
+
+/// Writes a C ordered `.npy` array of raw bytes with the given shape.
+pub fn write<W: Write>(mut w: W, shape: &[usize], bits: &[u8]) -> io::Result<()> {
+  if shape.iter().product::<usize>() != bits.len() {
+    return Err(io::Error::new(
+      io::ErrorKind::InvalidInput,
+      "shape does not match data length",
+    ));
+  }
+  let dims: String = shape.iter().map(|d| format!("{},", d)).collect();
+  let dims = if shape.len() == 1 {
+    dims
+  } else {
+    dims.trim_end_matches(',').to_string()
+  };
+  let mut header = format!(
+    "{{'descr': '|u1', 'fortran_order': False, 'shape': ({}), }}",
+    dims
+  );
+  // magic, version and length take 10 bytes, and the header ends with a newline
+  let total = (10 + header.len() + 1).div_ceil(64) * 64;
+  while 10 + header.len() + 1 < total {
+    header.push(' ');
+  }
+  header.push('\n');
+  w.write_all(MAGIC)?;
+  w.write_all(&[1, 0])?;
+  w.write_all(&(header.len() as u16).to_le_bytes())?;
+  w.write_all(header.as_bytes())?;
+  w.write_all(bits)
+}
+
+fn field<'a>(header: &'a str, key: &str) -> io::Result<&'a str> {
+  let start = header
+    .find(&format!("'{}':", key))
+    .ok_or_else(|| invalid("missing header field"))?;
+  Ok(header[start + key.len() + 3..].trim_start())
+}
+
+/// Parses a `.npy` file of a 1 byte dtype, returning its shape and raw bytes.
+pub fn read(bytes: &[u8]) -> io::Result<(Vec<usize>, &[u8])> {
+  if bytes.len() < 10 || &bytes[..6] != MAGIC {
+    return Err(invalid("not an npy file"));
+  }
+  let (len, start) = match bytes[6] {
+    1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+    2 | 3 if bytes.len() >= 12 => (
+      u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize,
+      12,
+    ),
+    _ => return Err(invalid("unsupported npy version")),
+  };
+  let header = bytes
+    .get(start..start + len)
+    .and_then(|h| std::str::from_utf8(h).ok())
+    .ok_or_else(|| invalid("bad header"))?;
+  let descr = field(header, "descr")?;
+  let descr = descr.get(1..4).ok_or_else(|| invalid("bad descr"))?;
+  if !matches!(&descr[1..], "u1" | "i1" | "b1" | "V1") {
+    return Err(invalid("dtype is not 1 byte"));
+  }
+  if field(header, "fortran_order")?.starts_with("True") {
+    return Err(invalid("fortran order arrays are not supported"));
+  }
+  let shape = field(header, "shape")?;
+  let end = shape.find(')').ok_or_else(|| invalid("bad shape"))?;
+  let shape = shape[1..end]
+    .split(',')
+    .map(str::trim)
+    .filter(|s| !s.is_empty())
+    .map(|s| s.parse().map_err(|_| invalid("bad shape")))
+    .collect::<io::Result<Vec<usize>>>()?;
+  let data = &bytes[start + len..];
+  let n = shape
+    .iter()
+    .try_fold(1usize, |n, &d| n.checked_mul(d))
+    .ok_or_else(|| invalid("too many elements"))?;
+  if data.len() < n {
+    return Err(invalid("truncated data"));
+  }
+  Ok((shape, &data[..n]))
+}
+
+/// Writes F8 values as a `.npy` array of their bits
+pub fn write_f8<W: Write>(w: W, shape: &[usize], data: &[F8]) -> io::Result<()> {
+  let bits: Vec<u8> = data.iter().map(|f| f.to_bits()).collect();
+  write(w, shape, &bits)
+}
+
+/// Reads a `.npy` array of bits as F8 values
+pub fn read_f8(bytes: &[u8]) -> io::Result<(Vec<usize>, Vec<F8>)> {
+  let (shape, bits) = read(bytes)?;
+  Ok((shape, bits.iter().map(|&b| F8::from_bits(b)).collect()))
+}
@@ -0,0 +1,89 @@
+//! The representable values of F8 laid out on a number line, for plotting how they are
+//! distributed.
+
+use crate::f8::{ASCENDING, F8};
+use std::fmt::Write;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Scale {
+  /// Every value from `-F8::MAX` to `F8::MAX`, evenly by value
+  Linear,
+  /// The positive values, evenly by their logarithm
+  Log,
+}
+
+/// A value and where it falls along the axis, from 0 at the start to 1 at the end
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Point {
+  pub value: F8,
+  pub position: f32,
+}
+
+/// Each distinct value shown on the axis, in ascending order
+pub fn points(scale: Scale) -> Vec<Point> {
+  match scale {
+    Scale::Linear => {
+      let max = F8::MAX.v();
+      F8::values_ascending()
+        .map(|value| Point {
+          value,
+          position: (value.v() + max) / (2.0 * max),
+        })
+        .collect()
+    },
+    Scale::Log => {
+      let (lo, hi) = (ASCENDING[1].v().log2(), F8::MAX.v().log2());
+      ASCENDING[1..]
+        .iter()
+        .map(|&value| Point {
+          value,
+          position: (value.v().log2() - lo) / (hi - lo),
+        })
+        .collect()
+    },
+  }
+}
+
+/// A `width` character axis with `|` at every column holding a value, above the values at
+/// each end
+pub fn ascii(points: &[Point], width: usize) -> String {
+  assert!(width >= 2, "Axis too narrow");
+  let mut line = vec!['-'; width];
+  for p in points {
+    line[(p.position * (width - 1) as f32).round() as usize] = '|';
+  }
+  let (first, last) = match (points.first(), points.last()) {
+    (Some(f), Some(l)) => (f.value.to_string(), l.value.to_string()),
+    _ => (String::new(), String::new()),
+  };
+  let pad = width.saturating_sub(first.len() + last.len());
+  let line: String = line.into_iter().collect();
+  format!("{}\n{}{:pad$}{}", line, first, "", last, pad = pad)
+}
+
+/// An SVG image of the axis with a tick at every value, taller at powers of two
+pub fn svg(points: &[Point], width: u32, height: u32) -> String {
+  let (w, h) = (width as f32, height as f32);
+  let mid = h / 2.0;
+  let mut s = format!(
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n\
+     <line x1=\"0\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"black\"/>\n",
+    width, height, mid, w, mid
+  );
+  for p in points {
+    let x = p.position * w;
+    let major = p.value.v().abs().log2().fract() == 0.0;
+    let t = if major { h * 0.4 } else { h * 0.2 };
+    writeln!(
+      s,
+      "<line x1=\"{x}\" y1=\"{}\" x2=\"{x}\" y2=\"{}\" stroke=\"black\"><title>{}</title></line>",
+      mid - t,
+      mid + t,
+      p.value,
+      x = x
+    )
+    .unwrap();
+  }
+  s.push_str("</svg>\n");
+  s
+}
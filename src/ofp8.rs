@@ -0,0 +1,146 @@
+//! The OCP 8 bit floating point formats E4M3 and E5M2, which unlike `F8` have an implicit
+//! leading significand bit. These exist for interchange with other tools; conversions between
+//! them and `F8` go through f32.
+
+/// Layout of an 8 bit float with an implicit leading bit
+#[derive(Copy, Clone)]
+pub(crate) struct Layout {
+  pub(crate) mant_bits: u32,
+  pub(crate) bias: i32,
+  /// Largest finite magnitude, as bits without the sign
+  pub(crate) max: u8,
+}
+
+const E4M3_LAYOUT: Layout = Layout {
+  mant_bits: 3,
+  bias: 7,
+  max: 0x7E,
+};
+
+const E5M2_LAYOUT: Layout = Layout {
+  mant_bits: 2,
+  bias: 15,
+  max: 0x7B,
+};
+
+/// Rounds the magnitude of a finite f32 into `l`, ties to even, saturating at `l.max`
+pub(crate) fn encode(f: f32, l: Layout) -> u8 { round_code(f, l).min(l.max as u32) as u8 }
+
+/// Rounds the magnitude of a finite f32 into `l`, ties to even, returning a code past `l.max`
+/// if the result is out of range
+pub(crate) fn round_code(f: f32, l: Layout) -> u32 {
+  let bits = f.to_bits() & 0x7FFF_FFFF;
+  let exp = (bits >> 23) as i32;
+  let implicit = ((exp != 0) as u32) << 23;
+  let exp = exp.max(1);
+  let m = (bits & 0x7F_FFFF) | implicit;
+  let ef = exp - 127 + l.bias;
+  let efc = ef.max(1);
+  let shift = ((23 - l.mant_bits as i32) + (efc - ef)).min(31) as u32;
+  let mut r = m >> shift;
+  let rem = m & ((1 << shift) - 1);
+  let half = 1 << (shift - 1);
+  r += ((rem > half) | ((rem == half) & (r & 1 == 1))) as u32;
+  (((efc - 1) as u32) << l.mant_bits) + r
+}
+
+/// Value of a magnitude in `l`, ignoring special values
+pub(crate) fn decode(c: u8, l: Layout) -> f32 {
+  let ef = (c >> l.mant_bits) as i32;
+  let frac = (c & ((1 << l.mant_bits) - 1)) as f32;
+  let unit = crate::math::pow2(-(l.mant_bits as i32));
+  if ef == 0 {
+    frac * unit * crate::math::pow2(1 - l.bias)
+  } else {
+    (1.0 + frac * unit) * crate::math::pow2(ef - l.bias)
+  }
+}
+
+pub(crate) fn sign_of(f: f32) -> u8 { ((f.to_bits() >> 31) as u8) << 7 }
+
+/// OCP E4M3 (also known as E4M3FN): bias 7, no infinities, NaN = S.1111.111, max 448
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[repr(transparent)]
+pub struct E4M3(pub u8);
+
+impl E4M3 {
+  pub const MAX: E4M3 = E4M3(E4M3_LAYOUT.max);
+  pub const NAN: E4M3 = E4M3(0x7F);
+  pub const fn from_bits(bits: u8) -> Self { E4M3(bits) }
+  pub const fn to_bits(self) -> u8 { self.0 }
+  pub const fn is_nan(self) -> bool { self.0 & 0x7F == 0x7F }
+  /// Rounds to nearest, ties to even. Out of range values, including infinities, saturate.
+  pub fn from_f32(f: f32) -> Self {
+    if f.is_nan() {
+      return E4M3::NAN;
+    }
+    E4M3(sign_of(f) | encode(f, E4M3_LAYOUT))
+  }
+  pub fn to_f32(self) -> f32 {
+    if self.is_nan() {
+      return f32::NAN;
+    }
+    let v = decode(self.0 & 0x7F, E4M3_LAYOUT);
+    if self.0 & 0x80 != 0 {
+      -v
+    } else {
+      v
+    }
+  }
+}
+
+/// OCP E5M2: bias 15, IEEE style infinities and NaNs, max 57344
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[repr(transparent)]
+pub struct E5M2(pub u8);
+
+impl E5M2 {
+  pub const MAX: E5M2 = E5M2(E5M2_LAYOUT.max);
+  pub const INFINITY: E5M2 = E5M2(0x7C);
+  pub const NAN: E5M2 = E5M2(0x7E);
+  pub const fn from_bits(bits: u8) -> Self { E5M2(bits) }
+  pub const fn to_bits(self) -> u8 { self.0 }
+  pub const fn is_nan(self) -> bool { self.0 & 0x7F > 0x7C }
+  pub const fn is_infinite(self) -> bool { self.0 & 0x7F == 0x7C }
+  /// Rounds to nearest, ties to even. Finite values beyond `MAX` saturate to it, while
+  /// infinities are preserved.
+  pub fn from_f32(f: f32) -> Self {
+    if f.is_nan() {
+      return E5M2::NAN;
+    }
+    let mag = if f.is_infinite() {
+      E5M2::INFINITY.0
+    } else {
+      encode(f, E5M2_LAYOUT)
+    };
+    E5M2(sign_of(f) | mag)
+  }
+  pub fn to_f32(self) -> f32 {
+    let v = match self.0 & 0x7F {
+      0x7C => f32::INFINITY,
+      0x7D..=0x7F => return f32::NAN,
+      c => decode(c, E5M2_LAYOUT),
+    };
+    if self.0 & 0x80 != 0 {
+      -v
+    } else {
+      v
+    }
+  }
+}
+
+impl From<E4M3> for f32 {
+  fn from(f: E4M3) -> f32 { f.to_f32() }
+}
+
+impl From<E5M2> for f32 {
+  fn from(f: E5M2) -> f32 { f.to_f32() }
+}
@@ -0,0 +1,142 @@
+//! Encoding and decoding of the ONNX FLOAT8 tensor element types.
+//!
+//! The "FN" types have no infinities, and the "UZ" types additionally have no negative zero,
+//! using `0x80` as their only NaN. Conversion follows the ONNX `Cast` operator, where
+//! `saturate` clamps out of range values to the largest finite value instead of producing
+//! infinity or NaN.
+
+use crate::ofp8::{decode, round_code, sign_of, Layout};
+
+/// The ONNX float8 `TensorProto.DataType`s
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum OnnxFloat8 {
+  E4M3FN = 17,
+  E4M3FNUZ = 18,
+  E5M2 = 19,
+  E5M2FNUZ = 20,
+}
+
+impl OnnxFloat8 {
+  /// Maps a `TensorProto.DataType` value to its float8 type, if it is one
+  pub fn from_data_type(t: i32) -> Option<Self> {
+    Some(match t {
+      17 => OnnxFloat8::E4M3FN,
+      18 => OnnxFloat8::E4M3FNUZ,
+      19 => OnnxFloat8::E5M2,
+      20 => OnnxFloat8::E5M2FNUZ,
+      _ => return None,
+    })
+  }
+  pub fn data_type(self) -> i32 { self as i32 }
+  fn layout(self) -> Layout {
+    match self {
+      OnnxFloat8::E4M3FN => Layout {
+        mant_bits: 3,
+        bias: 7,
+        max: 0x7E,
+      },
+      OnnxFloat8::E4M3FNUZ => Layout {
+        mant_bits: 3,
+        bias: 8,
+        max: 0x7F,
+      },
+      OnnxFloat8::E5M2 => Layout {
+        mant_bits: 2,
+        bias: 15,
+        max: 0x7B,
+      },
+      OnnxFloat8::E5M2FNUZ => Layout {
+        mant_bits: 2,
+        bias: 16,
+        max: 0x7F,
+      },
+    }
+  }
+  const fn is_uz(self) -> bool { matches!(self, OnnxFloat8::E4M3FNUZ | OnnxFloat8::E5M2FNUZ) }
+  /// Largest finite value of this type
+  pub fn max(self) -> f32 { decode(self.layout().max, self.layout()) }
+  /// Bits of this type's NaN, with the given sign where the type has signed NaNs
+  fn nan(self, sign: u8) -> u8 {
+    match self {
+      OnnxFloat8::E4M3FN => sign | 0x7F,
+      OnnxFloat8::E5M2 => sign | 0x7F,
+      OnnxFloat8::E4M3FNUZ | OnnxFloat8::E5M2FNUZ => 0x80,
+    }
+  }
+  pub fn is_nan(self, bits: u8) -> bool {
+    match self {
+      OnnxFloat8::E4M3FN => bits & 0x7F == 0x7F,
+      OnnxFloat8::E5M2 => bits & 0x7F > 0x7C,
+      OnnxFloat8::E4M3FNUZ | OnnxFloat8::E5M2FNUZ => bits == 0x80,
+    }
+  }
+  pub fn decode(self, bits: u8) -> f32 {
+    if self.is_nan(bits) {
+      return f32::NAN;
+    }
+    let mag = bits & 0x7F;
+    let v = if self == OnnxFloat8::E5M2 && mag == 0x7C {
+      f32::INFINITY
+    } else {
+      decode(mag, self.layout())
+    };
+    if bits & 0x80 != 0 {
+      -v
+    } else {
+      v
+    }
+  }
+  /// Rounds to nearest, ties to even, handling out of range values as ONNX `Cast` does
+  pub fn encode(self, f: f32, saturate: bool) -> u8 {
+    let sign = sign_of(f);
+    if f.is_nan() {
+      return self.nan(sign);
+    }
+    let l = self.layout();
+    let code = if f.is_infinite() {
+      u32::MAX
+    } else {
+      round_code(f, l)
+    };
+    let mag = if code <= l.max as u32 {
+      code as u8
+    } else if saturate && !(f.is_infinite() && self == OnnxFloat8::E5M2) {
+      l.max
+    } else if self == OnnxFloat8::E5M2 {
+      0x7C
+    } else {
+      return self.nan(sign);
+    };
+    if self.is_uz() && mag == 0 {
+      // no negative zero, as its bits are NaN
+      0
+    } else {
+      sign | mag
+    }
+  }
+}
+
+/// Decodes raw tensor bytes of the given type into f32
+pub fn decode_tensor(ty: OnnxFloat8, raw: &[u8], out: &mut [f32]) {
+  assert_eq!(raw.len(), out.len(), "Mismatched lengths");
+  for (o, &b) in out.iter_mut().zip(raw) {
+    *o = ty.decode(b);
+  }
+}
+
+/// Encodes f32 into raw tensor bytes of the given type
+pub fn encode_tensor(ty: OnnxFloat8, src: &[f32], saturate: bool, raw: &mut [u8]) {
+  assert_eq!(src.len(), raw.len(), "Mismatched lengths");
+  for (r, &v) in raw.iter_mut().zip(src) {
+    *r = ty.encode(v, saturate);
+  }
+}
+
+/// Decodes a tensor stored in `TensorProto.int32_data`, which holds one float8 per element
+/// in the low byte.
+pub fn decode_int32_data(ty: OnnxFloat8, data: &[i32], out: &mut [f32]) {
+  assert_eq!(data.len(), out.len(), "Mismatched lengths");
+  for (o, &b) in out.iter_mut().zip(data) {
+    *o = ty.decode(b as u8);
+  }
+}
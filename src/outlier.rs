@@ -0,0 +1,87 @@
+use crate::{f8::F8, scaled::absmax_scale};
+
+/// Values stored as scaled F8, except for a sparse side table of outliers kept at full
+/// precision, which would otherwise force a scale too coarse for everything else.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlierF8 {
+  pub scale: f32,
+  /// Quantized values, zero where an outlier was removed
+  pub data: Vec<F8>,
+  /// (index, value) of each outlier, sorted by index
+  pub outliers: Vec<(u32, f32)>,
+}
+
+impl OutlierF8 {
+  /// Quantizes `data`, keeping any value with magnitude above `threshold` as an outlier.
+  pub fn quantize(data: &[f32], threshold: f32) -> Self {
+    Self::split(data, |i| data[i].abs() > threshold || data[i].is_nan())
+  }
+  /// Quantizes `data`, keeping exactly the `k` largest magnitudes as outliers, with ties
+  /// broken by lower index.
+  pub fn quantize_top_k(data: &[f32], k: usize) -> Self {
+    let k = k.min(data.len());
+    let mut order: Vec<usize> = (0..data.len()).collect();
+    if k > 0 && k < data.len() {
+      order.select_nth_unstable_by(k - 1, |&a, &b| {
+        data[b].abs().total_cmp(&data[a].abs()).then(a.cmp(&b))
+      });
+    }
+    let mut is_outlier = vec![false; data.len()];
+    for &i in &order[..k] {
+      is_outlier[i] = true;
+    }
+    Self::split(data, |i| is_outlier[i])
+  }
+  /// Quantizes the values of `data` at indices where `is_outlier` is false
+  fn split(data: &[f32], is_outlier: impl Fn(usize) -> bool) -> Self {
+    let mut outliers = vec![];
+    let inliers: Vec<f32> = data
+      .iter()
+      .enumerate()
+      .map(|(i, &v)| {
+        if is_outlier(i) {
+          outliers.push((i as u32, v));
+          0.0
+        } else {
+          v
+        }
+      })
+      .collect();
+    let scale = absmax_scale(&inliers);
+    let data = inliers.iter().map(|&v| F8::approx_from(v / scale)).collect();
+    OutlierF8 {
+      scale,
+      data,
+      outliers,
+    }
+  }
+  pub fn len(&self) -> usize { self.data.len() }
+  pub fn is_empty(&self) -> bool { self.data.is_empty() }
+  pub fn get(&self, i: usize) -> f32 {
+    match self.outliers.binary_search_by_key(&(i as u32), |&(j, _)| j) {
+      Ok(o) => self.outliers[o].1,
+      Err(_) => self.data[i].v() * self.scale,
+    }
+  }
+  pub fn dequantize(&self) -> Vec<f32> {
+    let mut out = vec![0.0; self.data.len()];
+    self.dequantize_into(&mut out);
+    out
+  }
+  pub fn dequantize_into(&self, out: &mut [f32]) {
+    assert_eq!(out.len(), self.data.len(), "Mismatched lengths");
+    for (o, &f) in out.iter_mut().zip(&self.data) {
+      *o = f.v() * self.scale;
+    }
+    for &(i, v) in &self.outliers {
+      out[i as usize] = v;
+    }
+  }
+  /// Dot product with a dense vector, adding the outliers' contribution separately
+  pub fn dot(&self, x: &[f32]) -> f32 {
+    assert_eq!(x.len(), self.data.len(), "Mismatched lengths");
+    let dense: f32 = self.data.iter().zip(x).map(|(f, &x)| f.v() * x).sum();
+    let sparse: f32 = self.outliers.iter().map(|&(i, v)| v * x[i as usize]).sum();
+    dense * self.scale + sparse
+  }
+}
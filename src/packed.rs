@@ -0,0 +1,120 @@
+use crate::f8::F8;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::Neg;
+
+const SIGNS: u32 = 0x8080_8080;
+const LOW_BITS: u32 = 0x0101_0101;
+
+/// Four F8 packed into one u32, lane 0 in the least significant byte
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[repr(transparent)]
+pub struct F8x4(pub u32);
+
+impl F8x4 {
+  pub const fn new(lanes: [F8; 4]) -> Self {
+    F8x4(u32::from_le_bytes([
+      lanes[0].to_bits(),
+      lanes[1].to_bits(),
+      lanes[2].to_bits(),
+      lanes[3].to_bits(),
+    ]))
+  }
+  /// Broadcasts one F8 to all four lanes
+  pub const fn splat(f: F8) -> Self { F8x4(f.to_bits() as u32 * LOW_BITS) }
+  pub const fn to_array(self) -> [F8; 4] {
+    let b = self.0.to_le_bytes();
+    [
+      F8::from_bits(b[0]),
+      F8::from_bits(b[1]),
+      F8::from_bits(b[2]),
+      F8::from_bits(b[3]),
+    ]
+  }
+  /// Returns the F8 in lane `i`, which must be less than 4
+  #[inline]
+  pub const fn extract(self, i: usize) -> F8 {
+    assert!(i < 4, "Lane out of range");
+    F8::from_bits((self.0 >> (8 * i)) as u8)
+  }
+  /// Returns a copy with lane `i` replaced by `f`, `i` must be less than 4
+  #[inline]
+  pub const fn insert(self, i: usize, f: F8) -> Self {
+    assert!(i < 4, "Lane out of range");
+    let shift = 8 * i;
+    F8x4((self.0 & !(0xFF << shift)) | ((f.to_bits() as u32) << shift))
+  }
+  /// Converts each lane to f32
+  pub fn to_f32s(self) -> [f32; 4] {
+    let b = self.0.to_le_bytes();
+    [
+      F8::DECODE_TABLE[b[0] as usize],
+      F8::DECODE_TABLE[b[1] as usize],
+      F8::DECODE_TABLE[b[2] as usize],
+      F8::DECODE_TABLE[b[3] as usize],
+    ]
+  }
+  /// Converts each f32 to an F8 lane with `F8::approx_from`
+  pub fn from_f32s(v: [f32; 4]) -> Self {
+    F8x4::new([
+      F8::approx_from(v[0]),
+      F8::approx_from(v[1]),
+      F8::approx_from(v[2]),
+      F8::approx_from(v[3]),
+    ])
+  }
+  /// Clears the sign of every lane
+  pub const fn abs(self) -> Self { F8x4(self.0 & !SIGNS) }
+  /// Bitmask with bit `i` set if lane `i` is negative
+  pub const fn sign_mask(self) -> u8 {
+    let s = (self.0 & SIGNS) >> 7;
+    // gather bit 0 of each byte into the low nibble
+    ((s.wrapping_mul(0x0102_0408) >> 24) & 0xF) as u8
+  }
+  /// Bitmask with bit `i` set if lane `i` has a zero significand, i.e. is zero
+  pub const fn zero_mask(self) -> u8 {
+    let signif = self.0 & 0x0F0F_0F0F;
+    // a lane's high nibble becomes set iff its significand was nonzero
+    let nonzero = (signif + 0x7F7F_7F7F) & SIGNS;
+    F8x4(!nonzero).sign_mask()
+  }
+}
+
+impl Neg for F8x4 {
+  type Output = Self;
+  /// Negates every lane at once
+  fn neg(self) -> Self { F8x4(self.0 ^ SIGNS) }
+}
+
+impl From<[F8; 4]> for F8x4 {
+  fn from(lanes: [F8; 4]) -> Self { F8x4::new(lanes) }
+}
+
+impl From<F8x4> for [F8; 4] {
+  fn from(p: F8x4) -> Self { p.to_array() }
+}
+
+impl From<F8x4> for [f32; 4] {
+  fn from(p: F8x4) -> Self { p.to_f32s() }
+}
+
+/// Packs a slice of F8 into words of four lanes, padding the last word with zeros.
+#[cfg(feature = "alloc")]
+pub fn pack(src: &[F8]) -> Vec<F8x4> {
+  src
+    .chunks(4)
+    .map(|c| {
+      let mut lanes = [F8::from_bits(0); 4];
+      lanes[..c.len()].copy_from_slice(c);
+      F8x4::new(lanes)
+    })
+    .collect()
+}
+
+/// Unpacks words of four lanes back into F8, in lane order.
+#[cfg(feature = "alloc")]
+pub fn unpack(src: &[F8x4]) -> Vec<F8> { src.iter().flat_map(|p| p.to_array()).collect() }
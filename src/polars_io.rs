@@ -0,0 +1,91 @@
+//! Packing F8 columns into Polars `UInt8` series, with expressions to quantize and dequantize
+//! inside lazy queries.
+//!
+//! A Polars series cannot carry metadata of its own, so a packed column travels with its scale
+//! in `F8Series`.
+
+use crate::{f8::F8, scaled::absmax_scale};
+use polars::prelude::*;
+
+/// A series of F8 bits, representing `scale * F8::from_bits(bits[i])`
+#[derive(Debug, Clone)]
+pub struct F8Series {
+  pub scale: f32,
+  pub bits: Series,
+}
+
+impl F8Series {
+  /// Packs values with a scale mapping their largest magnitude to `F8::MAX`
+  pub fn pack(name: &str, data: &[f32]) -> Self {
+    let scale = absmax_scale(data);
+    let bits: Vec<u8> = data
+      .iter()
+      .map(|&v| F8::approx_from(v / scale).to_bits())
+      .collect();
+    F8Series {
+      scale,
+      bits: Series::new(name.into(), bits),
+    }
+  }
+  /// Packs F8 values which are already scaled
+  pub fn from_f8s(name: &str, data: &[F8], scale: f32) -> Self {
+    let bits: Vec<u8> = data.iter().map(|f| f.to_bits()).collect();
+    F8Series {
+      scale,
+      bits: Series::new(name.into(), bits),
+    }
+  }
+  /// Quantizes a `Float32` series with the given scale, keeping its nulls
+  pub fn quantize(s: &Series, scale: f32) -> PolarsResult<Self> {
+    Ok(F8Series {
+      scale,
+      bits: quantize_series(s, scale)?,
+    })
+  }
+  /// Dequantizes to a `Float32` series, keeping nulls
+  pub fn unpack(&self) -> PolarsResult<Series> { dequantize_series(&self.bits, self.scale) }
+  pub fn to_f8s(&self) -> PolarsResult<Vec<Option<F8>>> {
+    Ok(
+      self
+        .bits
+        .u8()?
+        .into_iter()
+        .map(|b| b.map(F8::from_bits))
+        .collect(),
+    )
+  }
+}
+
+fn quantize_series(s: &Series, scale: f32) -> PolarsResult<Series> {
+  let ca: UInt8Chunked = s
+    .f32()?
+    .into_iter()
+    .map(|v| v.map(|v| F8::approx_from(v / scale).to_bits()))
+    .collect();
+  Ok(ca.with_name(s.name().clone()).into_series())
+}
+
+fn dequantize_series(s: &Series, scale: f32) -> PolarsResult<Series> {
+  let ca: Float32Chunked = s
+    .u8()?
+    .into_iter()
+    .map(|b| b.map(|b| F8::from_bits(b).v() * scale))
+    .collect();
+  Ok(ca.with_name(s.name().clone()).into_series())
+}
+
+/// An expression quantizing a `Float32` column into F8 bits with the given scale
+pub fn quantize_expr(e: Expr, scale: f32) -> Expr {
+  e.map(
+    move |c| quantize_series(c.as_materialized_series(), scale).map(|s| Some(s.into())),
+    GetOutput::from_type(DataType::UInt8),
+  )
+}
+
+/// An expression dequantizing a column of F8 bits with the given scale
+pub fn dequantize_expr(e: Expr, scale: f32) -> Expr {
+  e.map(
+    move |c| dequantize_series(c.as_materialized_series(), scale).map(|s| Some(s.into())),
+    GetOutput::from_type(DataType::Float32),
+  )
+}
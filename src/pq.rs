@@ -0,0 +1,170 @@
+//! Product quantization, splitting vectors into subvectors which are each replaced by the
+//! index of the nearest of up to 256 centroids.
+//!
+//! The centroids of each subspace are stored as F8 with one absmax scale, and distances to a
+//! query are found by asymmetric distance computation, summing one table lookup per subspace.
+
+use crate::{f8::F8, scaled::absmax_scale};
+
+/// A trained product quantizer over `dim` dimensional vectors
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductQuantizer {
+  pub dim: usize,
+  /// The number of subvectors, each of `dim / subspaces` dimensions
+  pub subspaces: usize,
+  /// Centroids per subspace
+  pub k: usize,
+  /// `subspaces x k x sub_dim` centroids, where a centroid of subspace `s` is
+  /// `scales[s] * centroids[..]`
+  pub centroids: Vec<F8>,
+  pub scales: Vec<f32>,
+}
+
+/// Squared distances from one query to every centroid, `subspaces x k`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistanceTable {
+  k: usize,
+  table: Vec<f32>,
+}
+
+fn dist_sq(a: &[f32], b: &[f32]) -> f32 { a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum() }
+
+fn nearest(centroids: &[f32], sub_dim: usize, v: &[f32]) -> usize {
+  let mut best = (0, f32::INFINITY);
+  for (i, c) in centroids.chunks_exact(sub_dim).enumerate() {
+    let d = dist_sq(c, v);
+    if d < best.1 {
+      best = (i, d);
+    }
+  }
+  best.0
+}
+
+impl ProductQuantizer {
+  /// Learns `k` centroids per subspace from the row major `n x dim` `data` with `iters`
+  /// iterations of k-means, starting from evenly spaced rows of the data
+  pub fn train(data: &[f32], dim: usize, subspaces: usize, k: usize, iters: usize) -> Self {
+    assert!(
+      subspaces > 0 && dim.is_multiple_of(subspaces),
+      "dim is not a multiple of subspaces"
+    );
+    assert!((1..=256).contains(&k), "k must be in 1..=256");
+    assert!(dim > 0, "dim is zero");
+    assert!(
+      data.len().is_multiple_of(dim),
+      "data is not a multiple of dim"
+    );
+    let n = data.len() / dim;
+    assert!(n >= k, "Fewer rows than centroids");
+    let sub_dim = dim / subspaces;
+    let (mut centroids, mut scales) = (Vec::with_capacity(subspaces * k * sub_dim), vec![]);
+    for s in 0..subspaces {
+      let sub = |r: usize| &data[r * dim + s * sub_dim..r * dim + (s + 1) * sub_dim];
+      let mut c: Vec<f32> = (0..k).flat_map(|i| sub(i * n / k).to_vec()).collect();
+      for _ in 0..iters {
+        let mut sums = vec![0f64; k * sub_dim];
+        let mut counts = vec![0usize; k];
+        for r in 0..n {
+          let j = nearest(&c, sub_dim, sub(r));
+          counts[j] += 1;
+          for (acc, &v) in sums[j * sub_dim..(j + 1) * sub_dim].iter_mut().zip(sub(r)) {
+            *acc += v as f64;
+          }
+        }
+        for (j, &count) in counts.iter().enumerate().filter(|&(_, &c)| c > 0) {
+          for d in 0..sub_dim {
+            c[j * sub_dim + d] = (sums[j * sub_dim + d] / count as f64) as f32;
+          }
+        }
+      }
+      let scale = absmax_scale(&c);
+      centroids.extend(c.iter().map(|&v| F8::approx_from(v / scale)));
+      scales.push(scale);
+    }
+    ProductQuantizer {
+      dim,
+      subspaces,
+      k,
+      centroids,
+      scales,
+    }
+  }
+  pub fn sub_dim(&self) -> usize { self.dim / self.subspaces }
+  /// The dequantized centroids of subspace `s`, `k x sub_dim`
+  fn subspace(&self, s: usize) -> Vec<f32> {
+    let len = self.k * self.sub_dim();
+    let c = &self.centroids[s * len..(s + 1) * len];
+    c.iter().map(|f| f.v() * self.scales[s]).collect()
+  }
+  /// Writes the index of the nearest centroid of each subvector of `v` to `codes`
+  pub fn encode(&self, v: &[f32], codes: &mut [u8]) {
+    assert_eq!(v.len(), self.dim, "v is not of length dim");
+    assert_eq!(
+      codes.len(),
+      self.subspaces,
+      "codes is not of length subspaces"
+    );
+    let sub_dim = self.sub_dim();
+    for (s, (code, v)) in codes.iter_mut().zip(v.chunks_exact(sub_dim)).enumerate() {
+      *code = nearest(&self.subspace(s), sub_dim, v) as u8;
+    }
+  }
+  /// Writes the concatenated centroids of `codes` to `out`
+  ///
+  /// # Panics
+  /// If any code is not below `k`
+  pub fn decode(&self, codes: &[u8], out: &mut [f32]) {
+    assert_eq!(
+      codes.len(),
+      self.subspaces,
+      "codes is not of length subspaces"
+    );
+    assert_eq!(out.len(), self.dim, "out is not of length dim");
+    let sub_dim = self.sub_dim();
+    for (s, (&code, o)) in codes.iter().zip(out.chunks_exact_mut(sub_dim)).enumerate() {
+      assert!((code as usize) < self.k, "Code {} out of range", code);
+      let start = (s * self.k + code as usize) * sub_dim;
+      for (o, f) in o.iter_mut().zip(&self.centroids[start..start + sub_dim]) {
+        *o = f.v() * self.scales[s];
+      }
+    }
+  }
+  /// Precomputes the squared distance from each subvector of `query` to every centroid
+  pub fn distance_table(&self, query: &[f32]) -> DistanceTable {
+    assert_eq!(query.len(), self.dim, "query is not of length dim");
+    let sub_dim = self.sub_dim();
+    let table = query
+      .chunks_exact(sub_dim)
+      .enumerate()
+      .flat_map(|(s, q)| {
+        let c = self.subspace(s);
+        (0..self.k)
+          .map(|j| dist_sq(&c[j * sub_dim..(j + 1) * sub_dim], q))
+          .collect::<Vec<_>>()
+      })
+      .collect();
+    DistanceTable { k: self.k, table }
+  }
+}
+
+impl DistanceTable {
+  /// The squared distance from the query to the vector encoded as `codes`
+  ///
+  /// # Panics
+  /// If any code is not below `k`
+  pub fn distance(&self, codes: &[u8]) -> f32 {
+    assert_eq!(
+      codes.len() * self.k,
+      self.table.len(),
+      "codes is not of length subspaces"
+    );
+    codes
+      .iter()
+      .enumerate()
+      .map(|(s, &c)| {
+        assert!((c as usize) < self.k, "Code {} out of range", c);
+        self.table[s * self.k + c as usize]
+      })
+      .sum()
+  }
+}
@@ -0,0 +1,105 @@
+//! Random projections for dimensionality reduction, with the projection matrix stored as F8
+//! and one f32 scale.
+//!
+//! Matrices are generated from a seed with the same counter based generator as stochastic
+//! rounding, so a projection can be rebuilt from `(kind, rows, cols, seed)` alone.
+
+use crate::{
+  f8::F8,
+  linalg::gemv,
+  quantize::{splitmix64, uniform},
+  scaled::absmax_scale,
+};
+
+/// The distribution of the entries of a projection
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ProjectionKind {
+  /// Entries of `+1` or `-1` with equal probability
+  Sign,
+  /// Entries of `+1` or `-1` with probability `density / 2` each, and 0 otherwise
+  Sparse { density: f32 },
+  /// Standard normal entries
+  Gaussian,
+}
+
+/// A `rows x cols` row major projection from `cols` dimensions to `rows`, scaled so it
+/// preserves squared lengths in expectation
+#[derive(Debug, Clone, PartialEq)]
+pub struct RandomProjection {
+  pub kind: ProjectionKind,
+  pub rows: usize,
+  pub cols: usize,
+  /// Each entry of the matrix is `scale * weights[i]`
+  pub scale: f32,
+  pub weights: Vec<F8>,
+}
+
+impl RandomProjection {
+  pub fn new(kind: ProjectionKind, rows: usize, cols: usize, seed: u64) -> Self {
+    let n = rows * cols;
+    let norm = 1.0 / (rows.max(1) as f32).sqrt();
+    let one = F8::approx_from(1.0);
+    let sign = |i: usize| {
+      if splitmix64(seed ^ splitmix64(i as u64)) & 1 == 0 {
+        one
+      } else {
+        -one
+      }
+    };
+    let (scale, weights) = match kind {
+      ProjectionKind::Sign => (norm, (0..n).map(sign).collect()),
+      ProjectionKind::Sparse { density } => {
+        assert!(density > 0.0 && density <= 1.0, "density must be in (0, 1]");
+        let w = (0..n)
+          .map(|i| {
+            // a different seed from `sign`, so which entries are kept is independent of signs
+            if uniform(seed.rotate_left(32), i as u64) < density {
+              sign(i)
+            } else {
+              F8::from_bits(0)
+            }
+          })
+          .collect();
+        (norm / density.sqrt(), w)
+      },
+      ProjectionKind::Gaussian => {
+        let z: Vec<f32> = (0..n as u64)
+          .map(|i| {
+            // Box-Muller, with 1 - u in (0, 1] so the log is finite
+            let (u1, u2) = (1.0 - uniform(seed, 2 * i), uniform(seed, 2 * i + 1));
+            (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+          })
+          .collect();
+        let s = absmax_scale(&z);
+        (
+          norm * s,
+          z.iter().map(|&v| F8::approx_from(v / s)).collect(),
+        )
+      },
+    };
+    RandomProjection {
+      kind,
+      rows,
+      cols,
+      scale,
+      weights,
+    }
+  }
+  /// Computes `out = P x`
+  pub fn project(&self, x: &[f32], out: &mut [f32]) {
+    assert_eq!(x.len(), self.cols, "x is not of length cols");
+    assert_eq!(out.len(), self.rows, "out is not of length rows");
+    if self.cols == 0 {
+      out.iter_mut().for_each(|o| *o = 0.0);
+      return;
+    }
+    for (o, row) in out.iter_mut().zip(self.weights.chunks_exact(self.cols)) {
+      *o = self.scale * row.iter().zip(x).map(|(w, &x)| w.v() * x).sum::<f32>();
+    }
+  }
+  /// `project` for F8 input, with the products accumulated by `gemv`
+  pub fn project_f8(&self, x: &[F8], out: &mut [f32]) {
+    gemv(&self.weights, x, self.rows, self.cols, out);
+    out.iter_mut().for_each(|o| *o *= self.scale);
+  }
+}
@@ -0,0 +1,35 @@
+//! `proptest` strategies for F8.
+//!
+//! Every F8 bit pattern is a finite number, so `any_bits` and `finite` draw from the same
+//! values, and both are kept so tests read the same as for other float types. `normal` draws
+//! only nonzero encodings whose significand has its top bit set.
+
+use crate::f8::F8;
+use proptest::{
+  arbitrary::Arbitrary,
+  num,
+  strategy::{Map, Strategy},
+};
+use std::ops::RangeInclusive;
+
+/// Any bit pattern
+pub fn any_bits() -> impl Strategy<Value = F8> { num::u8::ANY.prop_map(F8::from_bits) }
+
+/// Any finite value, which for F8 is any bit pattern
+pub fn finite() -> impl Strategy<Value = F8> { any_bits() }
+
+/// Normalized encodings of either sign, 8 to 15 in the significand
+pub fn normal() -> impl Strategy<Value = F8> {
+  (0u8..=1, 0u8..=0b111, 8u8..=15).prop_map(|(s, e, m)| F8::new(s, e, m))
+}
+
+/// Finite values of magnitude at most `max`
+pub fn bounded(max: f32) -> impl Strategy<Value = F8> {
+  any_bits().prop_filter("magnitude out of range", move |f| f.v().abs() <= max)
+}
+
+impl Arbitrary for F8 {
+  type Parameters = ();
+  type Strategy = Map<RangeInclusive<u8>, fn(u8) -> F8>;
+  fn arbitrary_with(_: ()) -> Self::Strategy { (0..=255).prop_map(F8::from_bits) }
+}
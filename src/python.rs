@@ -0,0 +1,106 @@
+//! Python bindings, exposing F8 scalars and bulk conversion over NumPy arrays.
+//!
+//! Build the extension with maturin, enabling `python` and `pyo3/extension-module`. Arrays of
+//! F8 are NumPy `uint8` arrays of the raw bits, so they convert at full bit-level fidelity.
+
+use crate::{f8::F8, scaled::absmax_scale};
+use numpy::{
+  ndarray::{ArrayD, ArrayViewD},
+  IntoPyArray, PyArrayDyn, PyReadonlyArrayDyn,
+};
+use pyo3::{prelude::*, pyclass::CompareOp};
+
+/// A single F8 value
+#[pyclass(name = "F8", frozen)]
+#[derive(Clone, Copy)]
+pub struct PyF8(pub F8);
+
+#[pymethods]
+impl PyF8 {
+  /// Rounds a float to the nearest F8
+  #[new]
+  fn new(v: f32) -> Self { PyF8(F8::approx_from(v)) }
+  #[staticmethod]
+  fn from_bits(bits: u8) -> Self { PyF8(F8::from_bits(bits)) }
+  #[getter]
+  fn bits(&self) -> u8 { self.0.to_bits() }
+  fn __float__(&self) -> f32 { self.0.v() }
+  fn __repr__(&self) -> String { format!("F8({})", self.0.v()) }
+  fn __add__(&self, o: &Self) -> Self { PyF8(self.0 + o.0) }
+  fn __sub__(&self, o: &Self) -> Self { PyF8(self.0 - o.0) }
+  fn __mul__(&self, o: &Self) -> Self { PyF8(self.0 * o.0) }
+  fn __neg__(&self) -> Self { PyF8(-self.0) }
+  fn __richcmp__(&self, o: &Self, op: CompareOp) -> bool {
+    op.matches(self.0.order_key().cmp(&o.0.order_key()))
+  }
+}
+
+/// Rounds every element to the nearest F8, as bits
+pub(crate) fn quantize_array(a: ArrayViewD<'_, f32>) -> ArrayD<u8> {
+  a.mapv(|v| F8::approx_from(v).to_bits())
+}
+
+/// Decodes every element from F8 bits
+pub(crate) fn dequantize_array(a: ArrayViewD<'_, u8>) -> ArrayD<f32> {
+  a.mapv(|b| F8::from_bits(b).v())
+}
+
+/// Quantizes with one absmax scale, as `(scale, bits)`
+pub(crate) fn quantize_scaled_array(a: ArrayViewD<'_, f32>) -> (f32, ArrayD<u8>) {
+  let flat: Vec<f32> = a.iter().copied().collect();
+  let scale = absmax_scale(&flat);
+  (scale, a.mapv(|v| F8::approx_from(v / scale).to_bits()))
+}
+
+/// The `(max_abs, rms)` error of rounding every element to the nearest F8 after dividing by
+/// `scale`
+pub(crate) fn quantization_error_of(a: ArrayViewD<'_, f32>, scale: f32) -> (f32, f32) {
+  let (mut max, mut sq, mut n) = (0f32, 0f64, 0usize);
+  for &v in a.iter() {
+    let e = (v - F8::approx_from(v / scale).v() * scale).abs();
+    max = max.max(e);
+    sq += (e as f64).powi(2);
+    n += 1;
+  }
+  (max, (sq / n.max(1) as f64).sqrt() as f32)
+}
+
+/// Rounds every element to the nearest F8, returning the bits
+#[pyfunction]
+fn quantize<'py>(py: Python<'py>, a: PyReadonlyArrayDyn<'py, f32>) -> Bound<'py, PyArrayDyn<u8>> {
+  quantize_array(a.as_array()).into_pyarray_bound(py)
+}
+
+/// Decodes an array of F8 bits
+#[pyfunction]
+fn dequantize<'py>(py: Python<'py>, a: PyReadonlyArrayDyn<'py, u8>) -> Bound<'py, PyArrayDyn<f32>> {
+  dequantize_array(a.as_array()).into_pyarray_bound(py)
+}
+
+/// Quantizes with one absmax scale, returning `(scale, bits)`
+#[pyfunction]
+fn quantize_scaled<'py>(
+  py: Python<'py>,
+  a: PyReadonlyArrayDyn<'py, f32>,
+) -> (f32, Bound<'py, PyArrayDyn<u8>>) {
+  let (scale, bits) = quantize_scaled_array(a.as_array());
+  (scale, bits.into_pyarray_bound(py))
+}
+
+/// The `(max_abs, rms)` error of rounding every element to the nearest F8 after dividing by
+/// `scale`
+#[pyfunction]
+#[pyo3(signature = (a, scale = 1.0))]
+fn quantization_error(a: PyReadonlyArrayDyn<'_, f32>, scale: f32) -> (f32, f32) {
+  quantization_error_of(a.as_array(), scale)
+}
+
+#[pymodule]
+fn f8(m: &Bound<'_, PyModule>) -> PyResult<()> {
+  m.add_class::<PyF8>()?;
+  m.add_function(wrap_pyfunction!(quantize, m)?)?;
+  m.add_function(wrap_pyfunction!(dequantize, m)?)?;
+  m.add_function(wrap_pyfunction!(quantize_scaled, m)?)?;
+  m.add_function(wrap_pyfunction!(quantization_error, m)?)?;
+  Ok(())
+}
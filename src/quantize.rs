@@ -0,0 +1,144 @@
+use crate::f8::{bracket, F8};
+
+/// How a `Quantizer` rounds each value
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Rounding {
+  /// Each value is converted independently with `F8::approx_from`
+  Nearest,
+  /// The error of each conversion is added to the next value before it is converted
+  ErrorFeedback,
+  /// Rounds up or down with probability proportional to the distance to each neighbor,
+  /// driven by a deterministic generator seeded with the given value
+  Stochastic(u64),
+}
+
+/// Converts a stream of f32 into F8 in caller-sized chunks.
+///
+/// Any state the rounding mode needs is carried between chunks, so the output does not depend
+/// on how the input is split.
+#[derive(Debug, Clone)]
+pub struct Quantizer {
+  rounding: Rounding,
+  residual: f32,
+  count: u64,
+  buf: Vec<F8>,
+}
+
+/// SplitMix64 finalizer, used as a counter based generator
+pub(crate) fn splitmix64(mut x: u64) -> u64 {
+  x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+  x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+  x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+  x ^ (x >> 31)
+}
+
+/// Uniform sample in [0, 1) for the given seed and index
+pub(crate) fn uniform(seed: u64, index: u64) -> f32 {
+  let r = splitmix64(seed ^ splitmix64(index));
+  (r >> 40) as f32 / (1u32 << 24) as f32
+}
+
+/// Rounds `v` to one of its neighboring F8 with probability proportional to closeness,
+/// where `u` is uniform in [0, 1).
+pub(crate) fn round_stochastic(v: f32, u: f32) -> F8 {
+  let (lo, hi) = bracket(v.abs());
+  let (l, h) = (lo.v(), hi.v());
+  let q = if h > l && (v.abs() - l) / (h - l) > u {
+    hi
+  } else {
+    lo
+  };
+  if v.is_sign_negative() {
+    -q
+  } else {
+    q
+  }
+}
+
+/// Stochastically rounds `src` into `dst`, where the decision for element `i` depends only on
+/// `seed` and `i`, so results are reproducible regardless of how work is split.
+pub fn quantize_stochastic(src: &[f32], dst: &mut [F8], seed: u64) {
+  quantize_stochastic_at(src, dst, seed, 0)
+}
+
+/// Like `quantize_stochastic`, for a shard of a larger tensor which begins at index `offset`.
+pub fn quantize_stochastic_at(src: &[f32], dst: &mut [F8], seed: u64, offset: u64) {
+  assert_eq!(src.len(), dst.len(), "Mismatched lengths");
+  for (i, (&v, d)) in src.iter().zip(dst.iter_mut()).enumerate() {
+    *d = round_stochastic(v, uniform(seed, offset + i as u64));
+  }
+}
+
+impl Quantizer {
+  pub fn new(rounding: Rounding) -> Self {
+    Quantizer {
+      rounding,
+      residual: 0.0,
+      count: 0,
+      buf: vec![],
+    }
+  }
+  /// Converts the next chunk of the stream, returning the converted values.
+  /// The returned slice is reused by the next call.
+  pub fn push(&mut self, chunk: &[f32]) -> &[F8] {
+    let mut buf = std::mem::take(&mut self.buf);
+    buf.clear();
+    buf.resize(chunk.len(), F8::from_bits(0));
+    self.push_into(chunk, &mut buf);
+    self.buf = buf;
+    &self.buf
+  }
+  /// Converts the next chunk of the stream into `out`, which must be the same length.
+  pub fn push_into(&mut self, chunk: &[f32], out: &mut [F8]) {
+    assert_eq!(chunk.len(), out.len(), "Chunk and output lengths differ");
+    for (&v, o) in chunk.iter().zip(out.iter_mut()) {
+      *o = match self.rounding {
+        Rounding::Nearest => F8::approx_from(v),
+        Rounding::ErrorFeedback => {
+          let v = v + self.residual;
+          let q = F8::approx_from(v);
+          self.residual = if v.is_finite() { v - q.v() } else { 0.0 };
+          q
+        },
+        Rounding::Stochastic(seed) => round_stochastic(v, uniform(seed, self.count)),
+      };
+      self.count += 1;
+    }
+  }
+  /// Number of values converted so far
+  pub fn len(&self) -> u64 { self.count }
+  pub fn is_empty(&self) -> bool { self.count == 0 }
+  /// Ends the stream, returning the error which error feedback could not yet push into the
+  /// output. This is always zero for other rounding modes.
+  pub fn finish(self) -> f32 { self.residual }
+}
+
+/// Quantizes a row-major image `width` pixels wide with Floyd–Steinberg error diffusion, which
+/// spreads the error of each pixel onto its unvisited neighbors to avoid banding.
+pub fn quantize_dithered_2d(src: &[f32], width: usize, dst: &mut [F8]) {
+  assert_eq!(src.len(), dst.len(), "Mismatched lengths");
+  if width == 0 {
+    assert!(src.is_empty(), "Zero width with nonempty input");
+    return;
+  }
+  assert!(
+    src.len().is_multiple_of(width),
+    "Length not a multiple of width"
+  );
+  // errors carried into the current and next row, padded by one on each side
+  let mut cur = vec![0f32; width + 2];
+  let mut next = vec![0f32; width + 2];
+  for (row, out) in src.chunks_exact(width).zip(dst.chunks_exact_mut(width)) {
+    for (x, (&v, o)) in row.iter().zip(out.iter_mut()).enumerate() {
+      let v = v + cur[x + 1];
+      *o = F8::approx_from(v);
+      let e = if v.is_finite() { v - o.v() } else { 0.0 };
+      cur[x + 2] += e * (7.0 / 16.0);
+      next[x] += e * (3.0 / 16.0);
+      next[x + 1] += e * (5.0 / 16.0);
+      next[x + 2] += e * (1.0 / 16.0);
+    }
+    std::mem::swap(&mut cur, &mut next);
+    next.iter_mut().for_each(|e| *e = 0.0);
+  }
+}
@@ -0,0 +1,33 @@
+//! `quickcheck` support for F8.
+//!
+//! Values are generated uniformly over bit patterns, and shrink toward zero and one: first to
+//! zero, then one of the same sign, half the magnitude, the positive value, and finally the
+//! canonical encoding of the same value.
+
+use crate::f8::F8;
+use quickcheck::{Arbitrary, Gen};
+
+/// Strictly decreases along every shrink, so shrinking terminates
+fn size(f: F8) -> (i16, bool, u8) { (f.order_key().abs(), f.is_sign_negative(), f.to_bits()) }
+
+impl Arbitrary for F8 {
+  fn arbitrary(g: &mut Gen) -> Self { F8::from_bits(u8::arbitrary(g)) }
+  fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+    let f = *self;
+    let one = F8::approx_from(1f32.copysign(f.v()));
+    let candidates = [
+      F8::from_bits(0),
+      one,
+      F8::approx_from(f.v() / 2.0),
+      F8::from_bits(f.to_bits() & 0x7F),
+      F8::approx_from(f.v()),
+    ];
+    let mut out: Vec<F8> = vec![];
+    for c in candidates.iter().copied() {
+      if size(c) < size(f) && !out.contains(&c) {
+        out.push(c);
+      }
+    }
+    Box::new(out.into_iter())
+  }
+}
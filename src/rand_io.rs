@@ -0,0 +1,122 @@
+//! `rand` distributions over F8.
+//!
+//! Both distributions draw a uniform real number and round it down to the F8 at or below it,
+//! so each value is drawn with probability proportional to the gap to the next value above it.
+//! `StochasticF8` instead adapts any continuous f32 distribution.
+
+use crate::{
+  f8::{bracket, ASCENDING, F8},
+  quantize::round_stochastic,
+};
+use rand::{
+  distributions::{
+    uniform::{SampleBorrow, SampleUniform, UniformSampler},
+    Distribution, Standard,
+  },
+  Rng,
+};
+use rand_distr::{Exp, ExpError, Normal, NormalError};
+
+/// The largest F8 at or below `v`, saturating at `-F8::MAX`
+fn floor(v: f32) -> F8 {
+  if v >= 0.0 {
+    bracket(v).0
+  } else {
+    -bracket(-v).1
+  }
+}
+
+/// The gap above the largest F8, as if its significand could grow past the top
+const ABOVE_MAX: f32 = 512.0;
+
+/// The smallest value greater than `f`, which is past `F8::MAX` only for `F8::MAX` itself
+fn next_up(f: F8) -> f32 {
+  let v = f.v();
+  if v >= 0.0 {
+    ASCENDING
+      .iter()
+      .map(|a| a.v())
+      .find(|&a| a > v)
+      .unwrap_or(ABOVE_MAX)
+  } else {
+    -ASCENDING
+      .iter()
+      .rev()
+      .map(|a| a.v())
+      .find(|&a| a < -v)
+      .unwrap_or(0.0)
+  }
+}
+
+/// Samples [0, 1), which for F8 is 0, 0.25, 0.5 and 0.75 with equal probability
+impl Distribution<F8> for Standard {
+  fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F8 { floor(rng.gen::<f32>()) }
+}
+
+/// Samples F8 from a range, weighted by the interval each value covers
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct UniformF8 {
+  low: f32,
+  high: f32,
+}
+
+impl UniformSampler for UniformF8 {
+  type X = F8;
+  fn new<B1: SampleBorrow<F8> + Sized, B2: SampleBorrow<F8> + Sized>(low: B1, high: B2) -> Self {
+    let (low, high) = (low.borrow().v(), high.borrow().v());
+    assert!(low < high, "UniformF8::new called with `low >= high`");
+    UniformF8 { low, high }
+  }
+  fn new_inclusive<B1: SampleBorrow<F8> + Sized, B2: SampleBorrow<F8> + Sized>(
+    low: B1,
+    high: B2,
+  ) -> Self {
+    let (low, high) = (low.borrow().v(), *high.borrow());
+    assert!(
+      low <= high.v(),
+      "UniformF8::new_inclusive called with `low > high`"
+    );
+    UniformF8 {
+      low,
+      high: next_up(high),
+    }
+  }
+  fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F8 {
+    loop {
+      let v = self.low + rng.gen::<f32>() * (self.high - self.low);
+      // rounding can land on the excluded upper bound
+      if v < self.high {
+        return floor(v.max(self.low));
+      }
+    }
+  }
+}
+
+impl SampleUniform for F8 {
+  type Sampler = UniformF8;
+}
+
+/// Samples a continuous distribution in f32 and stochastically rounds each draw into F8, so
+/// the mean of the samples matches the mean of the distribution wherever it stays in range.
+///
+/// Rounding to nearest instead snaps the draws of a narrow distribution onto the closest one
+/// or two F8 values, biasing the mean toward them.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StochasticF8<D>(pub D);
+
+impl<D: Distribution<f32>> Distribution<F8> for StochasticF8<D> {
+  fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F8 {
+    let v = self.0.sample(rng);
+    round_stochastic(v, rng.gen())
+  }
+}
+
+impl StochasticF8<Normal<f32>> {
+  pub fn normal(mean: f32, std_dev: f32) -> Result<Self, NormalError> {
+    Normal::new(mean, std_dev).map(StochasticF8)
+  }
+}
+
+impl StochasticF8<Exp<f32>> {
+  pub fn exponential(lambda: f32) -> Result<Self, ExpError> { Exp::new(lambda).map(StochasticF8) }
+}
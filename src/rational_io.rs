@@ -0,0 +1,48 @@
+//! Exact conversion between F8 and `num_rational::Ratio<i32>`.
+
+use crate::f8::F8;
+use num_rational::Ratio;
+use std::{convert::TryFrom, fmt};
+
+/// The exact value, with both zeros becoming zero
+impl From<F8> for Ratio<i32> {
+  fn from(f: F8) -> Self {
+    let s = f.significand() as i32;
+    let s = if f.is_sign_negative() { -s } else { s };
+    let e = f.exponent() as i32 - crate::f8::BIAS as i32;
+    if e >= 0 {
+      Ratio::from_integer(s << e)
+    } else {
+      Ratio::new(s, 1 << -e)
+    }
+  }
+}
+
+/// An error from converting a ratio which is not exactly an F8
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InexactRatioError(());
+
+impl fmt::Display for InexactRatioError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("ratio is not exactly representable as an F8")
+  }
+}
+
+impl std::error::Error for InexactRatioError {}
+
+/// Converts only ratios exactly equal to some F8. The inherent `F8::try_from` takes an f32, so
+/// call this through `TryFrom::try_from` or `try_into`.
+impl TryFrom<Ratio<i32>> for F8 {
+  type Error = InexactRatioError;
+  fn try_from(r: Ratio<i32>) -> Result<Self, Self::Error> {
+    if *r.denom() == 0 {
+      return Err(InexactRatioError(()));
+    }
+    let f = F8::approx_from((*r.numer() as f64 / *r.denom() as f64) as f32);
+    if Ratio::from(f) == r {
+      Ok(f)
+    } else {
+      Err(InexactRatioError(()))
+    }
+  }
+}
@@ -0,0 +1,165 @@
+//! Shared exponent HDR pixels, in the style of Radiance RGBE.
+//!
+//! Each pixel stores three 8 bit significands and one biased exponent shared between them,
+//! decoding to `m * 2^(e - 136)`. The exponent is picked by the largest channel, so:
+//! - the largest channel keeps a relative error of at most `1/255`,
+//! - every channel has an absolute error of at most `2^(e - 137)`, half a step of the shared
+//!   exponent, so channels much dimmer than the largest lose relative precision,
+//! - negative and NaN channels become zero, and magnitudes past `RGBE_MAX` saturate.
+//!
+//! Per channel F8 instead has no dependence on the others, with a relative error of at most
+//! `2^-4` for magnitudes from 2 to `F8::MAX`, and worse below 2 where the significand has
+//! fewer bits, but needs a scale to cover more than its small dynamic range.
+
+use crate::{f8::F8, scaled::absmax_scale};
+
+/// Exponent bias of the stored exponent, with a further 8 for the significand
+const EXP_BIAS: i32 = 128;
+
+/// Three significands and a shared exponent, 4 bytes per pixel
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes)
+)]
+#[repr(C)]
+pub struct Rgbe8 {
+  pub rgb: [u8; 3],
+  /// Shared exponent, zero only for black
+  pub exp: u8,
+}
+
+/// The largest channel value an `Rgbe8` can hold
+pub const RGBE_MAX: f32 = (255u128 << 119) as f32;
+
+fn pow2(e: i32) -> f64 { 2f64.powi(e) }
+
+impl Rgbe8 {
+  pub const BLACK: Rgbe8 = Rgbe8 {
+    rgb: [0; 3],
+    exp: 0,
+  };
+  /// Encodes linear RGB, rounding each channel to the nearest step of the shared exponent
+  pub fn from_rgb(rgb: [f32; 3]) -> Self {
+    let c = rgb.map(|v| if v > 0.0 { v.min(RGBE_MAX) as f64 } else { 0.0 });
+    let m = c[0].max(c[1]).max(c[2]);
+    // smallest value which rounds to a nonzero significand at the lowest exponent
+    if m < pow2(1 - EXP_BIAS - 8 - 1) {
+      return Self::BLACK;
+    }
+    // m < 2^e, so the largest significand lands in [128, 256)
+    let mut e = (m.log2().floor() as i32 + 1).max(1 - EXP_BIAS);
+    if (m * pow2(8 - e)).round() >= 256.0 {
+      e += 1;
+    }
+    if e > 1 - EXP_BIAS && (m * pow2(8 - e)) < 127.5 {
+      e -= 1;
+    }
+    let e = e.min(255 - EXP_BIAS);
+    let s = pow2(8 - e);
+    Rgbe8 {
+      rgb: c.map(|v| (v * s).round().min(255.0) as u8),
+      exp: (e + EXP_BIAS) as u8,
+    }
+  }
+  /// Decodes to linear RGB
+  pub fn to_rgb(self) -> [f32; 3] {
+    if self.exp == 0 {
+      return [0.0; 3];
+    }
+    let s = pow2(self.exp as i32 - EXP_BIAS - 8);
+    self.rgb.map(|m| (m as f64 * s) as f32)
+  }
+  /// The absolute error bound of every channel of this pixel
+  pub fn max_abs_error(self) -> f32 {
+    if self.exp == 0 {
+      return pow2(-EXP_BIAS - 8) as f32;
+    }
+    pow2(self.exp as i32 - EXP_BIAS - 9) as f32
+  }
+  pub fn to_bytes(self) -> [u8; 4] { [self.rgb[0], self.rgb[1], self.rgb[2], self.exp] }
+  pub fn from_bytes(b: [u8; 4]) -> Self {
+    Rgbe8 {
+      rgb: [b[0], b[1], b[2]],
+      exp: b[3],
+    }
+  }
+}
+
+impl From<[f32; 3]> for Rgbe8 {
+  fn from(rgb: [f32; 3]) -> Self { Rgbe8::from_rgb(rgb) }
+}
+
+impl From<Rgbe8> for [f32; 3] {
+  fn from(p: Rgbe8) -> Self { p.to_rgb() }
+}
+
+/// Encodes a slice of linear RGB pixels
+pub fn encode(pixels: &[[f32; 3]]) -> Vec<Rgbe8> {
+  pixels.iter().map(|&p| Rgbe8::from_rgb(p)).collect()
+}
+
+/// Decodes a slice of `Rgbe8` pixels
+pub fn decode(pixels: &[Rgbe8]) -> Vec<[f32; 3]> { pixels.iter().map(|p| p.to_rgb()).collect() }
+
+/// Error of an encoding over a set of pixels
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct ErrorStats {
+  /// Root mean squared error over all channels
+  pub rms: f32,
+  /// Largest channel error of a pixel relative to its largest channel
+  pub max_rel: f32,
+}
+
+impl ErrorStats {
+  fn measure(src: &[[f32; 3]], approx: &[[f32; 3]]) -> Self {
+    let mut sq = 0f64;
+    let mut max_rel = 0f32;
+    for (p, q) in src.iter().zip(approx) {
+      let peak = p.iter().fold(0f32, |m, &v| m.max(v));
+      let mut worst = 0f32;
+      for c in 0..3 {
+        let d = p[c].max(0.0) - q[c];
+        sq += (d * d) as f64;
+        worst = worst.max(d.abs());
+      }
+      if peak > 0.0 {
+        max_rel = max_rel.max(worst / peak);
+      }
+    }
+    let n = (src.len() * 3).max(1);
+    ErrorStats {
+      rms: (sq / n as f64).sqrt() as f32,
+      max_rel,
+    }
+  }
+}
+
+/// The error of `Rgbe8` against per channel F8 with one absmax scale per channel
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Comparison {
+  pub rgbe: ErrorStats,
+  pub f8: ErrorStats,
+}
+
+/// Encodes `pixels` both as `Rgbe8` and as per channel scaled F8, and measures each
+pub fn compare(pixels: &[[f32; 3]]) -> Comparison {
+  let rgbe = decode(&encode(pixels));
+  let scales: Vec<f32> = (0..3)
+    .map(|c| absmax_scale(&pixels.iter().map(|p| p[c].max(0.0)).collect::<Vec<_>>()))
+    .collect();
+  let f8: Vec<[f32; 3]> = pixels
+    .iter()
+    .map(|p| {
+      let mut q = [0.0; 3];
+      for c in 0..3 {
+        q[c] = F8::approx_from(p[c].max(0.0) / scales[c]).v() * scales[c];
+      }
+      q
+    })
+    .collect();
+  Comparison {
+    rgbe: ErrorStats::measure(pixels, &rgbe),
+    f8: ErrorStats::measure(pixels, &f8),
+  }
+}
@@ -0,0 +1,44 @@
+//! `rkyv` support for the 8 bit formats.
+//!
+//! Each format archives as itself, since a single byte has no alignment or endianness to
+//! adjust, so an archived `Vec<F8>` can be read in place as a `&[F8]`.
+
+use crate::{
+  e8m0::E8M0,
+  f8::F8,
+  ofp8::{E4M3, E5M2},
+};
+use rkyv::{
+  bytecheck::CheckBytes,
+  rancor::Fallible,
+  traits::{CopyOptimization, NoUndef},
+  Archive, Deserialize, Place, Portable, Serialize,
+};
+
+macro_rules! impl_archive_self {
+  ($($t: ty),*) => {
+    $(
+      // SAFETY: each format is a transparent wrapper of a u8, for which every bit pattern is
+      // valid.
+      unsafe impl NoUndef for $t {}
+      unsafe impl Portable for $t {}
+      unsafe impl<C: Fallible + ?Sized> CheckBytes<C> for $t {
+        unsafe fn check_bytes(_: *const Self, _: &mut C) -> Result<(), C::Error> { Ok(()) }
+      }
+      impl Archive for $t {
+        const COPY_OPTIMIZATION: CopyOptimization<Self> = unsafe { CopyOptimization::enable() };
+        type Archived = Self;
+        type Resolver = ();
+        fn resolve(&self, _: (), out: Place<Self>) { out.write(*self) }
+      }
+      impl<S: Fallible + ?Sized> Serialize<S> for $t {
+        fn serialize(&self, _: &mut S) -> Result<(), S::Error> { Ok(()) }
+      }
+      impl<D: Fallible + ?Sized> Deserialize<$t, D> for $t {
+        fn deserialize(&self, _: &mut D) -> Result<$t, D::Error> { Ok(*self) }
+      }
+    )*
+  };
+}
+
+impl_archive_self!(F8, E4M3, E5M2, E8M0);
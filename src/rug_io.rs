@@ -0,0 +1,125 @@
+//! Differential testing of F8 arithmetic and the activation lookup tables against MPFR.
+//!
+//! Every reference result is computed by MPFR at 128 bits, rounding to nearest. That is exact
+//! for sums and products of F8 and far finer than F8 for the transcendental functions, and is
+//! then rounded once more to the nearest F8 with ties to even, the rounding F8 itself uses.
+//! Implementations which panic are counted rather than aborting the report.
+
+use crate::{
+  activation,
+  f8::{round_f64, F8},
+};
+use rug::Float;
+use std::{
+  fmt,
+  panic::{self, AssertUnwindSafe},
+};
+
+/// Precision of the MPFR reference, in bits
+const PREC: u32 = 128;
+
+/// How far the results of one function were from the reference
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UlpReport {
+  pub name: &'static str,
+  pub cases: usize,
+  /// Cases whose result differed from the reference
+  pub mismatches: usize,
+  /// The largest distance from the reference, in F8 steps
+  pub max_ulp: u32,
+  /// Cases which panicked, and are not counted as mismatches
+  pub panics: usize,
+}
+
+impl fmt::Display for UlpReport {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{:<8} {:>6} cases {:>6} wrong {:>6} panicked, max {} ulp",
+      self.name, self.cases, self.mismatches, self.panics, self.max_ulp
+    )
+  }
+}
+
+fn float(f: F8) -> Float { Float::with_val(PREC, f.v()) }
+
+fn reference(v: Float) -> F8 { round_f64(v.to_f64()) }
+
+/// Compares `op` on every pair of F8 bit patterns with `reference`
+pub fn check_binary(
+  name: &'static str,
+  op: impl Fn(F8, F8) -> F8,
+  reference_op: impl Fn(Float, Float) -> Float,
+) -> UlpReport {
+  let mut r = UlpReport {
+    name,
+    cases: 0,
+    mismatches: 0,
+    max_ulp: 0,
+    panics: 0,
+  };
+  for a in (0..=255).map(F8::from_bits) {
+    for b in (0..=255).map(F8::from_bits) {
+      let want = reference(reference_op(float(a), float(b)));
+      record(&mut r, want, || op(a, b));
+    }
+  }
+  r
+}
+
+/// Compares `op` on every F8 bit pattern with `reference`
+pub fn check_unary(
+  name: &'static str,
+  op: impl Fn(F8) -> F8,
+  reference_op: impl Fn(Float) -> Float,
+) -> UlpReport {
+  let mut r = UlpReport {
+    name,
+    cases: 0,
+    mismatches: 0,
+    max_ulp: 0,
+    panics: 0,
+  };
+  for a in (0..=255).map(F8::from_bits) {
+    record(&mut r, reference(reference_op(float(a))), || op(a));
+  }
+  r
+}
+
+fn record(r: &mut UlpReport, want: F8, op: impl FnOnce() -> F8) {
+  r.cases += 1;
+  match panic::catch_unwind(AssertUnwindSafe(op)) {
+    Ok(got) => {
+      let d = got.ulp_distance(want);
+      r.mismatches += (d != 0) as usize;
+      r.max_ulp = r.max_ulp.max(d);
+    },
+    Err(_) => r.panics += 1,
+  }
+}
+
+/// Checks F8 arithmetic and every activation lookup table
+pub fn report() -> Vec<UlpReport> {
+  let lut = |t: &'static [F8; 256]| move |a: F8| t[a.to_bits() as usize];
+  vec![
+    check_binary("add", |a, b| a + b, |x, y| x + y),
+    check_binary("sub", |a, b| a - b, |x, y| x - y),
+    check_binary("mul", |a, b| a * b, |x, y| x * y),
+    check_unary("neg", |a| -a, |x| -x),
+    check_unary("relu", lut(activation::relu_lut()), |x| {
+      if x < 0 {
+        Float::with_val(PREC, 0)
+      } else {
+        x
+      }
+    }),
+    check_unary("gelu", lut(activation::gelu_lut()), |x| {
+      let erf = (x.clone() / Float::with_val(PREC, 2).sqrt()).erf();
+      x * (erf + 1) / 2
+    }),
+    check_unary("silu", lut(activation::silu_lut()), |x| {
+      let d = (-x.clone()).exp() + 1;
+      x / d
+    }),
+  ]
+}
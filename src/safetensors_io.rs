@@ -0,0 +1,151 @@
+//! Reading and writing 8 bit float tensors in the safetensors format.
+//!
+//! E4M3 and E5M2 tensors use the `F8_E4M3` and `F8_E5M2` dtypes. `F8` has no safetensors
+//! dtype, so it is stored as `U8` and marked in the file's metadata under `f8.dtype.<name>`.
+
+use crate::{
+  f8::F8,
+  ofp8::{E4M3, E5M2},
+};
+use safetensors::{tensor::TensorView, Dtype, SafeTensorError, SafeTensors};
+use std::{collections::HashMap, fmt};
+
+const DTYPE_KEY: &str = "f8.dtype.";
+
+/// Elements of an 8 bit float tensor
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fp8Data {
+  F8(Vec<F8>),
+  E4M3(Vec<E4M3>),
+  E5M2(Vec<E5M2>),
+}
+
+impl Fp8Data {
+  pub fn len(&self) -> usize {
+    match self {
+      Fp8Data::F8(v) => v.len(),
+      Fp8Data::E4M3(v) => v.len(),
+      Fp8Data::E5M2(v) => v.len(),
+    }
+  }
+  pub fn is_empty(&self) -> bool { self.len() == 0 }
+  /// Decodes every element to f32
+  pub fn to_f32(&self) -> Vec<f32> {
+    match self {
+      Fp8Data::F8(v) => v.iter().map(|f| f.v()).collect(),
+      Fp8Data::E4M3(v) => v.iter().map(|f| f.to_f32()).collect(),
+      Fp8Data::E5M2(v) => v.iter().map(|f| f.to_f32()).collect(),
+    }
+  }
+  fn bits(&self) -> Vec<u8> {
+    match self {
+      Fp8Data::F8(v) => v.iter().map(|f| f.to_bits()).collect(),
+      Fp8Data::E4M3(v) => v.iter().map(|f| f.to_bits()).collect(),
+      Fp8Data::E5M2(v) => v.iter().map(|f| f.to_bits()).collect(),
+    }
+  }
+}
+
+/// An 8 bit float tensor with its shape
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fp8Tensor {
+  pub shape: Vec<usize>,
+  pub data: Fp8Data,
+}
+
+#[derive(Debug)]
+pub enum Error {
+  SafeTensor(SafeTensorError),
+  /// The named tensor is not stored in an 8 bit float dtype
+  UnsupportedDtype(String, Dtype),
+  /// The tensor's shape does not match its number of elements
+  ShapeMismatch(String),
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Error::SafeTensor(e) => write!(f, "{}", e),
+      Error::UnsupportedDtype(name, d) => write!(f, "tensor {} has non fp8 dtype {:?}", name, d),
+      Error::ShapeMismatch(name) => write!(f, "tensor {} does not match its shape", name),
+    }
+  }
+}
+
+impl std::error::Error for Error {}
+
+impl From<SafeTensorError> for Error {
+  fn from(e: SafeTensorError) -> Self { Error::SafeTensor(e) }
+}
+
+fn convert(name: &str, view: &TensorView<'_>, is_f8: bool) -> Result<Fp8Tensor, Error> {
+  let bytes = view.data().iter().copied();
+  let data = match view.dtype() {
+    Dtype::F8_E4M3 => Fp8Data::E4M3(bytes.map(E4M3).collect()),
+    Dtype::F8_E5M2 => Fp8Data::E5M2(bytes.map(E5M2).collect()),
+    Dtype::U8 if is_f8 => Fp8Data::F8(bytes.map(F8::from_bits).collect()),
+    d => return Err(Error::UnsupportedDtype(name.to_string(), d)),
+  };
+  Ok(Fp8Tensor {
+    shape: view.shape().to_vec(),
+    data,
+  })
+}
+
+fn f8_marked(bytes: &[u8]) -> Result<HashMap<String, String>, Error> {
+  let (_, meta) = SafeTensors::read_metadata(bytes)?;
+  Ok(meta.metadata().clone().unwrap_or_default())
+}
+
+/// Loads every 8 bit float tensor in a safetensors file, skipping tensors of other dtypes.
+pub fn load(bytes: &[u8]) -> Result<Vec<(String, Fp8Tensor)>, Error> {
+  let meta = f8_marked(bytes)?;
+  let st = SafeTensors::deserialize(bytes)?;
+  let mut out = vec![];
+  for (name, view) in st.tensors() {
+    let is_f8 = meta.contains_key(&format!("{}{}", DTYPE_KEY, name));
+    match convert(&name, &view, is_f8) {
+      Ok(t) => out.push((name, t)),
+      Err(Error::UnsupportedDtype(..)) => continue,
+      Err(e) => return Err(e),
+    }
+  }
+  out.sort_by(|a, b| a.0.cmp(&b.0));
+  Ok(out)
+}
+
+/// Loads a single named 8 bit float tensor from a safetensors file.
+pub fn load_tensor(bytes: &[u8], name: &str) -> Result<Fp8Tensor, Error> {
+  let is_f8 = f8_marked(bytes)?.contains_key(&format!("{}{}", DTYPE_KEY, name));
+  let st = SafeTensors::deserialize(bytes)?;
+  convert(name, &st.tensor(name)?, is_f8)
+}
+
+/// Serializes named 8 bit float tensors into a safetensors file.
+pub fn save<'a, I>(tensors: I) -> Result<Vec<u8>, Error>
+where
+  I: IntoIterator<Item = (&'a str, &'a Fp8Tensor)>,
+{
+  let mut meta = HashMap::new();
+  let mut owned = vec![];
+  for (name, t) in tensors {
+    if t.shape.iter().product::<usize>() != t.data.len() {
+      return Err(Error::ShapeMismatch(name.to_string()));
+    }
+    let dtype = match t.data {
+      Fp8Data::F8(_) => {
+        meta.insert(format!("{}{}", DTYPE_KEY, name), "F8".to_string());
+        Dtype::U8
+      },
+      Fp8Data::E4M3(_) => Dtype::F8_E4M3,
+      Fp8Data::E5M2(_) => Dtype::F8_E5M2,
+    };
+    owned.push((name, dtype, t.shape.clone(), t.data.bits()));
+  }
+  let views = owned
+    .iter()
+    .map(|(name, dtype, shape, bits)| Ok((*name, TensorView::new(*dtype, shape.clone(), bits)?)))
+    .collect::<Result<Vec<_>, SafeTensorError>>()?;
+  let meta = if meta.is_empty() { None } else { Some(meta) };
+  Ok(safetensors::serialize(views, &meta)?)
+}
@@ -0,0 +1,58 @@
+use crate::{f8::F8, linalg};
+
+/// A tensor of F8 values sharing one f32 scale, representing `scale * data[i]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaledF8Tensor {
+  pub scale: f32,
+  pub data: Vec<F8>,
+}
+
+/// Scale mapping the largest magnitude in `data` onto `F8::MAX`, or the smallest positive f32
+/// when that underflows, so the scale of nonzero data is never zero
+pub(crate) fn absmax_scale(data: &[f32]) -> f32 {
+  let amax = data.iter().fold(0f32, |m, v| m.max(v.abs()));
+  if !(amax > 0.0 && amax.is_finite()) {
+    return 1.0;
+  }
+  let s = amax / F8::MAX.v();
+  if s > 0.0 {
+    s
+  } else {
+    f32::from_bits(1)
+  }
+}
+
+impl ScaledF8Tensor {
+  /// Quantizes `data` with a scale chosen so its largest magnitude maps to `F8::MAX`
+  pub fn quantize(data: &[f32]) -> Self { Self::quantize_with_scale(data, absmax_scale(data)) }
+  /// Quantizes `data` with the given scale
+  pub fn quantize_with_scale(data: &[f32], scale: f32) -> Self {
+    // divided rather than multiplied by the inverse, which overflows for subnormal scales
+    let data = data.iter().map(|&v| F8::approx_from(v / scale)).collect();
+    ScaledF8Tensor { scale, data }
+  }
+  pub fn len(&self) -> usize { self.data.len() }
+  pub fn is_empty(&self) -> bool { self.data.is_empty() }
+  pub fn dequantize(&self) -> Vec<f32> {
+    let mut out = vec![0.0; self.data.len()];
+    self.dequantize_into(&mut out);
+    out
+  }
+  pub fn dequantize_into(&self, out: &mut [f32]) {
+    assert_eq!(out.len(), self.data.len(), "Mismatched lengths");
+    for (o, &f) in out.iter_mut().zip(&self.data) {
+      *o = f.v() * self.scale;
+    }
+  }
+  /// Computes `y += a * self` without materializing the dequantized tensor
+  pub fn axpy(&self, a: f32, y: &mut [f32]) { linalg::axpy(a * self.scale, &self.data, y) }
+  /// Dot product with another scaled tensor, applying both scales once at the end
+  pub fn dot(&self, o: &Self) -> f32 { linalg::dot(&self.data, &o.data) * (self.scale * o.scale) }
+  /// Computes `self * o` into `out`, where `self` is `m x k` and `o` is `k x n`,
+  /// applying both scales once at the end.
+  pub fn gemm(&self, o: &Self, m: usize, k: usize, n: usize, out: &mut [f32]) {
+    linalg::gemm(&self.data, &o.data, m, k, n, out);
+    let s = self.scale * o.scale;
+    out.iter_mut().for_each(|v| *v *= s);
+  }
+}
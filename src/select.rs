@@ -0,0 +1,118 @@
+//! Order statistics over F8 slices, extremes, quantiles and sorting, comparing by
+//! `F8::order_key` or `F8::total_cmp` rather than converting elements to f32.
+
+use crate::f8::F8;
+
+/// Index of the first largest element, or None if empty
+pub fn argmax(v: &[F8]) -> Option<usize> {
+  let mut best = (0, i16::MIN);
+  for (i, f) in v.iter().enumerate() {
+    let k = f.order_key();
+    if k > best.1 {
+      best = (i, k);
+    }
+  }
+  (!v.is_empty()).then_some(best.0)
+}
+
+/// Index of the first smallest element, or None if empty
+pub fn argmin(v: &[F8]) -> Option<usize> {
+  let mut best = (0, i16::MAX);
+  for (i, f) in v.iter().enumerate() {
+    let k = f.order_key();
+    if k < best.1 {
+      best = (i, k);
+    }
+  }
+  (!v.is_empty()).then_some(best.0)
+}
+
+/// Indices of the `k` largest elements, largest first with ties broken by lower index.
+///
+/// Runs in linear time by counting elements per bit pattern to find the cutoff value.
+pub fn top_k(v: &[F8], k: usize) -> Vec<usize> {
+  let k = k.min(v.len());
+  if k == 0 {
+    return vec![];
+  }
+  let mut counts = [0usize; 256];
+  for f in v {
+    counts[f.to_bits() as usize] += 1;
+  }
+  let mut by_key = [0i16; 256];
+  for (i, k) in by_key.iter_mut().enumerate() {
+    *k = F8::from_bits(i as u8).order_key();
+  }
+  let mut patterns: Vec<u8> = (0..=255).collect();
+  patterns.sort_by_key(|&b| std::cmp::Reverse(by_key[b as usize]));
+  let mut seen = 0;
+  let mut cutoff = i16::MIN;
+  for &b in &patterns {
+    seen += counts[b as usize];
+    if seen >= k {
+      cutoff = by_key[b as usize];
+      break;
+    }
+  }
+  let above = v.iter().filter(|f| f.order_key() > cutoff).count();
+  let mut at_cutoff = k - above;
+  let mut out = Vec::with_capacity(k);
+  for (i, f) in v.iter().enumerate() {
+    let key = f.order_key();
+    if key > cutoff {
+      out.push(i);
+    } else if key == cutoff && at_cutoff > 0 {
+      out.push(i);
+      at_cutoff -= 1;
+    }
+  }
+  out.sort_by_key(|&i| (std::cmp::Reverse(v[i].order_key()), i));
+  out
+}
+
+/// Sorts by `F8::total_cmp`
+pub fn sort_total(v: &mut [F8]) { v.sort_unstable_by(F8::total_cmp) }
+
+/// Whether `v` is sorted by `F8::total_cmp`
+pub fn is_sorted_total(v: &[F8]) -> bool { v.is_sorted_by(|a, b| a.total_cmp(b).is_le()) }
+
+/// Binary searches `v`, sorted by `F8::total_cmp`, for the bit pattern `x`, as
+/// `slice::binary_search` does
+pub fn binary_search_total(v: &[F8], x: F8) -> Result<usize, usize> {
+  v.binary_search_by(|f| f.total_cmp(&x))
+}
+
+/// The elements at fractions `qs` of the way through `v` in sorted order, each the element at
+/// index `floor(q * (len - 1))` with `q` clamped to `0..=1`, or empty if `v` is empty.
+///
+/// Runs in linear time from the count of each bit pattern, without sorting `v`.
+///
+/// # Panics
+/// If any of `qs` is NaN and `v` is not empty
+pub fn quantiles(v: &[F8], qs: &[f32]) -> Vec<F8> {
+  if v.is_empty() {
+    return vec![];
+  }
+  let mut counts = [0usize; 256];
+  for f in v {
+    counts[f.to_bits() as usize] += 1;
+  }
+  let mut patterns: Vec<F8> = F8::iter_all().collect();
+  sort_total(&mut patterns);
+  let mut ends = [0usize; 256];
+  let mut seen = 0;
+  for (end, f) in ends.iter_mut().zip(&patterns) {
+    seen += counts[f.to_bits() as usize];
+    *end = seen;
+  }
+  qs.iter()
+    .map(|&q| {
+      assert!(!q.is_nan(), "Quantile is NaN");
+      let idx = (q.clamp(0.0, 1.0) as f64 * (v.len() - 1) as f64) as usize;
+      patterns[ends.partition_point(|&e| e <= idx)]
+    })
+    .collect()
+}
+
+/// The lower median, or None if empty
+pub fn median(v: &[F8]) -> Option<F8> { quantiles(v, &[0.5]).pop() }
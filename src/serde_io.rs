@@ -0,0 +1,102 @@
+//! `serde` support for the 8 bit formats.
+//!
+//! By default every format serializes as the f32 it represents, and deserializes by rounding
+//! an f32 to the nearest value. To store raw bits instead, annotate a field with
+//! `#[serde(with = "f8::serde_io::bits")]`, and `numeric` names the default explicitly. Formats
+//! without NaN, such as JSON, need `bits` to round trip NaN encodings.
+
+use crate::{
+  e8m0::E8M0,
+  f8::F8,
+  ofp8::{E4M3, E5M2},
+  packed::F8x4,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An 8 bit format which can be serialized either as its value or its bits
+pub trait Byte: Copy {
+  fn to_byte(self) -> u8;
+  fn from_byte(b: u8) -> Self;
+  fn to_value(self) -> f32;
+  fn from_value(v: f32) -> Self;
+}
+
+macro_rules! impl_byte {
+  ($t: ty, $to: expr, $from: expr) => {
+    impl Byte for $t {
+      fn to_byte(self) -> u8 { self.0 }
+      fn from_byte(b: u8) -> Self { Self(b) }
+      fn to_value(self) -> f32 { $to(self) }
+      fn from_value(v: f32) -> Self { $from(v) }
+    }
+    impl Serialize for $t {
+      fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        numeric::serialize(self, s)
+      }
+    }
+    impl<'de> Deserialize<'de> for $t {
+      fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        numeric::deserialize(d)
+      }
+    }
+  };
+}
+
+impl Byte for F8 {
+  fn to_byte(self) -> u8 { self.to_bits() }
+  fn from_byte(b: u8) -> Self { F8::from_bits(b) }
+  fn to_value(self) -> f32 { self.v() }
+  fn from_value(v: f32) -> Self { F8::approx_from(v) }
+}
+
+impl Serialize for F8 {
+  fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+    numeric::serialize(self, s)
+  }
+}
+
+impl<'de> Deserialize<'de> for F8 {
+  fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> { numeric::deserialize(d) }
+}
+
+impl_byte!(E4M3, E4M3::to_f32, E4M3::from_f32);
+impl_byte!(E5M2, E5M2::to_f32, E5M2::from_f32);
+// Rounds up, so values between powers of two do not shrink
+impl_byte!(E8M0, E8M0::to_f32, E8M0::from_f32_ceil);
+
+/// Serializes as the four lanes
+impl Serialize for F8x4 {
+  fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+    self.to_array().serialize(s)
+  }
+}
+
+impl<'de> Deserialize<'de> for F8x4 {
+  fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+    <[F8; 4]>::deserialize(d).map(F8x4::new)
+  }
+}
+
+/// Serializes as the f32 value, deserializing to the nearest representable value
+pub mod numeric {
+  use super::Byte;
+  use serde::{Deserialize, Deserializer, Serializer};
+  pub fn serialize<T: Byte, S: Serializer>(v: &T, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_f32(v.to_value())
+  }
+  pub fn deserialize<'de, T: Byte, D: Deserializer<'de>>(d: D) -> Result<T, D::Error> {
+    f32::deserialize(d).map(T::from_value)
+  }
+}
+
+/// Serializes as the raw bits in a u8
+pub mod bits {
+  use super::Byte;
+  use serde::{Deserialize, Deserializer, Serializer};
+  pub fn serialize<T: Byte, S: Serializer>(v: &T, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u8(v.to_byte())
+  }
+  pub fn deserialize<'de, T: Byte, D: Deserializer<'de>>(d: D) -> Result<T, D::Error> {
+    u8::deserialize(d).map(T::from_byte)
+  }
+}
@@ -0,0 +1,81 @@
+//! F8 arithmetic in pure integer arithmetic, with no floating point anywhere, for targets
+//! without an FPU and as an implementation independent of the f32 based one.
+//!
+//! With the `soft-float` feature, F8's operators and `v` use these. Results are the exact
+//! result rounded to nearest with ties to an even significand, saturating at `F8::MAX`, the
+//! same as rounding through f32 with `F8::approx_from`, which is itself integer only.
+
+use crate::f8::{BIAS, F8};
+
+/// The largest `frac_bits` accepted by `from_fixed`, so that the largest F8 significand
+/// shifted into place still fits in 64 bits
+pub const MAX_FRAC_BITS: u32 = 54;
+
+/// Rounds `n / 2^frac_bits` to the nearest F8, ties to an even significand, saturating.
+///
+/// # Panics
+/// If `frac_bits` is not in `BIAS..=MAX_FRAC_BITS`
+pub fn from_fixed(n: i64, frac_bits: u32) -> F8 {
+  assert!(
+    (BIAS as u32..=MAX_FRAC_BITS).contains(&frac_bits),
+    "frac_bits out of range"
+  );
+  let sign = (n < 0) as u8;
+  let m = n.unsigned_abs();
+  // the smallest exponent whose significand holds `m` in 4 bits, before rounding
+  let shift_of = |e: u32| frac_bits + e - BIAS as u32;
+  let e = match (0..8).find(|&e| m < 16 << shift_of(e)) {
+    Some(e) => e,
+    None => return F8::new(sign, 0b111, 0b1111),
+  };
+  let shift = shift_of(e);
+  let mut q = m >> shift;
+  if shift > 0 {
+    let rem = m & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    q += ((rem > half) || (rem == half && q & 1 == 1)) as u64;
+  }
+  let (e, q) = if q == 16 { (e + 1, 8) } else { (e, q) };
+  if e > 0b111 {
+    return F8::new(sign, 0b111, 0b1111);
+  }
+  F8::new(sign, e as u8, q as u8)
+}
+
+/// The value in quarters, `v() * 2^BIAS`, which is always an integer
+fn quarters(f: F8) -> i64 { f.order_key() as i64 }
+
+pub fn add(a: F8, b: F8) -> F8 {
+  let sum = quarters(a) + quarters(b);
+  if sum == 0 {
+    // an exact zero is negative only when both operands are
+    return F8::new((a.is_sign_negative() && b.is_sign_negative()) as u8, 0, 0);
+  }
+  from_fixed(sum, BIAS as u32)
+}
+
+pub fn sub(a: F8, b: F8) -> F8 { add(a, -b) }
+
+pub fn mul(a: F8, b: F8) -> F8 {
+  let p = from_fixed(quarters(a) * quarters(b), 2 * BIAS as u32);
+  // zero products keep the sign of the product of signs
+  let sign = a.is_sign_negative() ^ b.is_sign_negative();
+  if p.significand() == 0 {
+    F8::new(sign as u8, 0, 0)
+  } else {
+    p
+  }
+}
+
+/// The bits of the f32 equal to `f`
+pub fn to_f32_bits(f: F8) -> u32 {
+  let sign = (f.is_sign_negative() as u32) << 31;
+  let s = f.significand() as u32;
+  if s == 0 {
+    return sign;
+  }
+  // s = 1.m * 2^k
+  let k = 31 - s.leading_zeros();
+  let exp = (127 + f.exponent() as u32 + k - BIAS as u32) << 23;
+  sign | exp | ((s - (1 << k)) << (23 - k))
+}
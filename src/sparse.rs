@@ -0,0 +1,71 @@
+use crate::{f8::F8, scaled::absmax_scale};
+
+/// A compressed sparse row matrix with F8 nonzeros and one f32 scale per row
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrF8 {
+  pub rows: usize,
+  pub cols: usize,
+  /// Row `r`'s entries are at `indptr[r]..indptr[r + 1]`
+  pub indptr: Vec<usize>,
+  pub indices: Vec<u32>,
+  pub values: Vec<F8>,
+  pub scales: Vec<f32>,
+}
+
+impl CsrF8 {
+  /// Quantizes a row major dense matrix, dropping entries which are or round to zero
+  pub fn from_dense(data: &[f32], rows: usize, cols: usize) -> Self {
+    assert_eq!(data.len(), rows * cols, "data is not rows x cols");
+    let mut m = CsrF8 {
+      rows,
+      cols,
+      indptr: vec![0],
+      indices: vec![],
+      values: vec![],
+      scales: vec![],
+    };
+    for row in data.chunks_exact(cols.max(1)).take(rows) {
+      let scale = absmax_scale(row);
+      for (c, &v) in row.iter().enumerate() {
+        let q = F8::approx_from(v / scale);
+        if q.significand() != 0 {
+          m.indices.push(c as u32);
+          m.values.push(q);
+        }
+      }
+      m.scales.push(scale);
+      m.indptr.push(m.values.len());
+    }
+    m
+  }
+  /// Number of stored nonzeros
+  pub fn nnz(&self) -> usize { self.values.len() }
+  pub fn to_dense(&self) -> Vec<f32> {
+    let mut out = vec![0.0; self.rows * self.cols];
+    for r in 0..self.rows {
+      for i in self.indptr[r]..self.indptr[r + 1] {
+        out[r * self.cols + self.indices[i] as usize] = self.values[i].v() * self.scales[r];
+      }
+    }
+    out
+  }
+  /// Computes `y = self * x`, accumulating in f32
+  pub fn spmv(&self, x: &[f32], y: &mut [f32]) {
+    assert_eq!(x.len(), self.cols, "x does not have cols elements");
+    assert_eq!(y.len(), self.rows, "y does not have rows elements");
+    for (r, y) in y.iter_mut().enumerate() {
+      let range = self.indptr[r]..self.indptr[r + 1];
+      let acc: f32 = self.indices[range.clone()]
+        .iter()
+        .zip(&self.values[range])
+        .map(|(&c, v)| v.v() * x[c as usize])
+        .sum();
+      *y = acc * self.scales[r];
+    }
+  }
+  /// Computes `y = self * x` for an F8 vector `x` quantized with `x_scale`
+  pub fn spmv_f8(&self, x: &[F8], x_scale: f32, y: &mut [f32]) {
+    let x: Vec<f32> = x.iter().map(|f| f.v() * x_scale).collect();
+    self.spmv(&x, y)
+  }
+}
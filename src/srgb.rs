@@ -0,0 +1,54 @@
+//! Conversion between sRGB encoded f32 and F8 holding linear light.
+//!
+//! The transfer function and scale are evaluated in f64 and rounded directly to F8 (or to
+//! f32 on the way back), so there is exactly one rounding per conversion.
+
+use crate::f8::{round_f64, F8};
+
+/// The sRGB electro-optical transfer function, extended to negative values by symmetry
+pub fn srgb_to_linear(v: f64) -> f64 {
+  let a = v.abs();
+  let l = if a <= 0.04045 {
+    a / 12.92
+  } else {
+    ((a + 0.055) / 1.055).powf(2.4)
+  };
+  l.copysign(v)
+}
+
+/// The inverse of `srgb_to_linear`
+pub fn linear_to_srgb(v: f64) -> f64 {
+  let a = v.abs();
+  let s = if a <= 0.0031308 {
+    a * 12.92
+  } else {
+    1.055 * a.powf(1.0 / 2.4) - 0.055
+  };
+  s.copysign(v)
+}
+
+/// Converts an sRGB encoded value to the F8 nearest its linear value divided by `scale`
+pub fn linear_f8_from_srgb(v: f32, scale: f32) -> F8 {
+  round_f64(srgb_to_linear(v as f64) / scale as f64)
+}
+
+/// Converts an F8 holding linear light divided by `scale` to an sRGB encoded value
+pub fn srgb_from_linear_f8(f: F8, scale: f32) -> f32 {
+  linear_to_srgb(f.v() as f64 * scale as f64) as f32
+}
+
+/// `linear_f8_from_srgb` over a slice
+pub fn srgb_to_f8(src: &[f32], scale: f32, dst: &mut [F8]) {
+  assert_eq!(src.len(), dst.len(), "Mismatched lengths");
+  for (&v, d) in src.iter().zip(dst.iter_mut()) {
+    *d = linear_f8_from_srgb(v, scale);
+  }
+}
+
+/// `srgb_from_linear_f8` over a slice
+pub fn f8_to_srgb(src: &[F8], scale: f32, dst: &mut [f32]) {
+  assert_eq!(src.len(), dst.len(), "Mismatched lengths");
+  for (&f, d) in src.iter().zip(dst.iter_mut()) {
+    *d = srgb_from_linear_f8(f, scale);
+  }
+}
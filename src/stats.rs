@@ -0,0 +1,60 @@
+//! Streaming statistics over F8 samples, kept in f64 so long streams do not lose precision.
+
+use crate::f8::F8;
+
+/// Welford's online count, mean and variance
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct RunningStats {
+  count: u64,
+  mean: f64,
+  /// The sum of squared differences from the mean
+  m2: f64,
+}
+
+impl RunningStats {
+  pub fn new() -> Self { Self::default() }
+  pub fn push_f32(&mut self, v: f32) {
+    self.count += 1;
+    let d = v as f64 - self.mean;
+    self.mean += d / self.count as f64;
+    self.m2 += d * (v as f64 - self.mean);
+  }
+  pub fn push(&mut self, v: F8) { self.push_f32(v.v()) }
+  /// Combines the statistics of two streams, as if one had been pushed after the other
+  pub fn merge(&mut self, o: &Self) {
+    if o.count == 0 {
+      return;
+    }
+    let count = self.count + o.count;
+    let d = o.mean - self.mean;
+    self.mean += d * o.count as f64 / count as f64;
+    self.m2 += o.m2 + d * d * self.count as f64 * o.count as f64 / count as f64;
+    self.count = count;
+  }
+  pub fn count(&self) -> u64 { self.count }
+  /// The mean, or 0 if empty
+  pub fn mean(&self) -> f64 { self.mean }
+  /// The population variance, or 0 if empty
+  pub fn variance(&self) -> f64 { self.m2 / self.count.max(1) as f64 }
+  /// The sample variance, or 0 with fewer than two samples
+  pub fn sample_variance(&self) -> f64 {
+    if self.count < 2 {
+      0.0
+    } else {
+      self.m2 / (self.count - 1) as f64
+    }
+  }
+  pub fn std_dev(&self) -> f64 { self.variance().sqrt() }
+}
+
+impl Extend<F8> for RunningStats {
+  fn extend<I: IntoIterator<Item = F8>>(&mut self, iter: I) {
+    iter.into_iter().for_each(|v| self.push(v))
+  }
+}
+
+impl<'a> Extend<&'a F8> for RunningStats {
+  fn extend<I: IntoIterator<Item = &'a F8>>(&mut self, iter: I) {
+    iter.into_iter().for_each(|&v| self.push(v))
+  }
+}
@@ -0,0 +1,147 @@
+//! On-disk storage of raw F8 tensors.
+//!
+//! A tensor file is a small header followed by one byte per element:
+//! `b"F8TN" | format: u8 | ndim: u8 | 0u16 | ndim * u64 (LE) dims | data`
+
+use crate::{
+  bytes::{as_bytes, from_bytes},
+  f8::F8,
+};
+use std::{
+  convert::TryFrom,
+  io::{self, Write},
+};
+
+const MAGIC: &[u8; 4] = b"F8TN";
+
+/// Which 8 bit format the payload of a tensor file is encoded in
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+  F8 = 0,
+}
+
+impl Format {
+  fn from_u8(v: u8) -> Option<Self> {
+    match v {
+      0 => Some(Format::F8),
+      _ => None,
+    }
+  }
+}
+
+/// Header of a tensor file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+  pub format: Format,
+  pub shape: Vec<usize>,
+}
+
+fn invalid(msg: &str) -> io::Error { io::Error::new(io::ErrorKind::InvalidData, msg) }
+
+impl Header {
+  /// Number of elements described by this header's shape
+  pub fn num_elements(&self) -> usize { self.shape.iter().product() }
+  /// Size of this header in bytes
+  pub fn byte_len(&self) -> usize { 8 + 8 * self.shape.len() }
+  pub fn write_to<W: Write>(&self, mut w: W) -> io::Result<()> {
+    if self.shape.len() > u8::MAX as usize {
+      return Err(io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "too many dimensions",
+      ));
+    }
+    w.write_all(MAGIC)?;
+    w.write_all(&[self.format as u8, self.shape.len() as u8, 0, 0])?;
+    for &d in &self.shape {
+      w.write_all(&(d as u64).to_le_bytes())?;
+    }
+    Ok(())
+  }
+  /// Parses a header from the start of `bytes`, returning it and the offset of the data.
+  pub fn parse(bytes: &[u8]) -> io::Result<(Self, usize)> {
+    if bytes.len() < 8 || &bytes[..4] != MAGIC {
+      return Err(invalid("not an F8 tensor file"));
+    }
+    let format = Format::from_u8(bytes[4]).ok_or_else(|| invalid("unknown format"))?;
+    let ndim = bytes[5] as usize;
+    let end = 8 + 8 * ndim;
+    if bytes.len() < end {
+      return Err(invalid("truncated header"));
+    }
+    let shape = bytes[8..end]
+      .chunks_exact(8)
+      .map(|c| {
+        let mut d = [0; 8];
+        d.copy_from_slice(c);
+        usize::try_from(u64::from_le_bytes(d)).ok()
+      })
+      .collect::<Option<Vec<_>>>()
+      .ok_or_else(|| invalid("dimension too large"))?;
+    // the element count must fit in usize for num_elements to be valid
+    shape
+      .iter()
+      .try_fold(1usize, |n, &d| n.checked_mul(d))
+      .ok_or_else(|| invalid("too many elements"))?;
+    Ok((Header { format, shape }, end))
+  }
+}
+
+/// Writes `data` with the given shape as a tensor file.
+pub fn write_tensor<W: Write>(mut w: W, shape: &[usize], data: &[F8]) -> io::Result<()> {
+  let header = Header {
+    format: Format::F8,
+    shape: shape.to_vec(),
+  };
+  if header.num_elements() != data.len() {
+    return Err(io::Error::new(
+      io::ErrorKind::InvalidInput,
+      "shape does not match data length",
+    ));
+  }
+  header.write_to(&mut w)?;
+  w.write_all(as_bytes(data))
+}
+
+/// Parses a whole tensor file held in memory, returning its header and elements.
+pub fn read_tensor(bytes: &[u8]) -> io::Result<(Header, &[F8])> {
+  let (header, offset) = Header::parse(bytes)?;
+  let data = &bytes[offset..];
+  if data.len() < header.num_elements() {
+    return Err(invalid("truncated data"));
+  }
+  let data = from_bytes(&data[..header.num_elements()]);
+  Ok((header, data))
+}
+
+/// A tensor file memory mapped into the address space, usable as `&[F8]` without copying.
+#[cfg(feature = "mmap")]
+pub struct MappedF8 {
+  mmap: memmap2::Mmap,
+  header: Header,
+  offset: usize,
+}
+
+#[cfg(feature = "mmap")]
+impl MappedF8 {
+  /// Maps the tensor file at `path`.
+  ///
+  /// The file must not be modified while it is mapped.
+  pub fn open<P: AsRef<std::path::Path>>(path: P) -> io::Result<Self> {
+    let file = std::fs::File::open(path)?;
+    let mmap = unsafe { memmap2::Mmap::map(&file)? };
+    let (header, offset) = Header::parse(&mmap)?;
+    if mmap.len() - offset < header.num_elements() {
+      return Err(invalid("truncated data"));
+    }
+    Ok(MappedF8 {
+      mmap,
+      header,
+      offset,
+    })
+  }
+  pub fn header(&self) -> &Header { &self.header }
+  pub fn shape(&self) -> &[usize] { &self.header.shape }
+  pub fn data(&self) -> &[F8] {
+    from_bytes(&self.mmap[self.offset..self.offset + self.header.num_elements()])
+  }
+}
@@ -0,0 +1,135 @@
+//! Buffered adapters reading and writing F8 streams, one byte per element, through any
+//! `io::Read` or `io::Write`, converting from and to f32 on the fly.
+
+use crate::f8::F8;
+use std::io::{self, Read, Write};
+
+const BUF_LEN: usize = 8 * 1024;
+
+/// Writes F8 to an inner writer, through a buffer flushed when full, on `flush`, and on drop
+#[derive(Debug)]
+pub struct F8Writer<W: Write> {
+  // an Option so `into_inner` can take it despite the Drop impl
+  inner: Option<W>,
+  buf: Vec<u8>,
+}
+
+impl<W: Write> F8Writer<W> {
+  pub fn new(inner: W) -> Self {
+    F8Writer {
+      inner: Some(inner),
+      buf: Vec::with_capacity(BUF_LEN),
+    }
+  }
+  fn push(&mut self, b: u8) -> io::Result<()> {
+    if self.buf.len() == BUF_LEN {
+      self.flush_buf()?;
+    }
+    self.buf.push(b);
+    Ok(())
+  }
+  fn flush_buf(&mut self) -> io::Result<()> {
+    self.inner.as_mut().unwrap().write_all(&self.buf)?;
+    self.buf.clear();
+    Ok(())
+  }
+  pub fn write_f8(&mut self, data: &[F8]) -> io::Result<()> {
+    data.iter().try_for_each(|f| self.push(f.to_bits()))
+  }
+  /// Rounds each value to the nearest F8 and writes it
+  pub fn write_f32(&mut self, data: &[f32]) -> io::Result<()> {
+    data
+      .iter()
+      .try_for_each(|&v| self.push(F8::approx_from(v).to_bits()))
+  }
+  pub fn flush(&mut self) -> io::Result<()> {
+    self.flush_buf()?;
+    self.inner.as_mut().unwrap().flush()
+  }
+  pub fn get_ref(&self) -> &W { self.inner.as_ref().unwrap() }
+  /// Flushes and returns the inner writer
+  pub fn into_inner(mut self) -> io::Result<W> {
+    self.flush()?;
+    Ok(self.inner.take().unwrap())
+  }
+}
+
+impl<W: Write> Drop for F8Writer<W> {
+  fn drop(&mut self) {
+    if self.inner.is_some() {
+      // errors cannot be reported from drop, as with `BufWriter`
+      let _ = self.flush_buf();
+    }
+  }
+}
+
+/// Reads F8 from an inner reader through a buffer
+#[derive(Debug)]
+pub struct F8Reader<R: Read> {
+  inner: R,
+  buf: Box<[u8]>,
+  pos: usize,
+  filled: usize,
+}
+
+impl<R: Read> F8Reader<R> {
+  pub fn new(inner: R) -> Self {
+    F8Reader {
+      inner,
+      buf: vec![0; BUF_LEN].into_boxed_slice(),
+      pos: 0,
+      filled: 0,
+    }
+  }
+  /// The buffered bytes, refilled if empty, which is empty only at the end of the stream
+  fn fill(&mut self) -> io::Result<&[u8]> {
+    if self.pos == self.filled {
+      self.filled = loop {
+        match self.inner.read(&mut self.buf) {
+          Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+          r => break r?,
+        }
+      };
+      self.pos = 0;
+    }
+    Ok(&self.buf[self.pos..self.filled])
+  }
+  /// Fills `out` from the stream, returning how many were read, which is fewer only at the
+  /// end of the stream
+  pub fn read_f8(&mut self, out: &mut [F8]) -> io::Result<usize> {
+    self.read_with(out, F8::from_bits)
+  }
+  /// `read_f8`, widening each element to f32
+  pub fn read_f32(&mut self, out: &mut [f32]) -> io::Result<usize> {
+    self.read_with(out, |b| F8::from_bits(b).v())
+  }
+  fn read_with<T>(&mut self, out: &mut [T], f: impl Fn(u8) -> T) -> io::Result<usize> {
+    let mut n = 0;
+    while n < out.len() {
+      let buf = self.fill()?;
+      if buf.is_empty() {
+        break;
+      }
+      let len = buf.len().min(out.len() - n);
+      for (o, &b) in out[n..n + len].iter_mut().zip(buf) {
+        *o = f(b);
+      }
+      self.pos += len;
+      n += len;
+    }
+    Ok(n)
+  }
+  /// Reads every remaining element as f32
+  pub fn read_to_end_f32(&mut self) -> io::Result<Vec<f32>> {
+    let mut out = vec![];
+    loop {
+      let buf = self.fill()?;
+      if buf.is_empty() {
+        return Ok(out);
+      }
+      out.extend(buf.iter().map(|&b| F8::from_bits(b).v()));
+      self.pos = self.filled;
+    }
+  }
+  pub fn into_inner(self) -> R { self.inner }
+}
@@ -0,0 +1,85 @@
+//! Complete truth tables of F8 operations, for comparison against other implementations such
+//! as hardware.
+
+use crate::f8::F8;
+use std::io::{self, Write};
+
+/// A binary operation to tabulate
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TableOp {
+  Add,
+  Sub,
+  Mul,
+}
+
+impl TableOp {
+  pub fn name(self) -> &'static str {
+    match self {
+      TableOp::Add => "add",
+      TableOp::Sub => "sub",
+      TableOp::Mul => "mul",
+    }
+  }
+  /// The exact result, see `impl Add for F8`
+  pub fn exact(self, a: F8, b: F8) -> f32 {
+    match self {
+      TableOp::Add => a.v() + b.v(),
+      TableOp::Sub => a.v() - b.v(),
+      TableOp::Mul => a.v() * b.v(),
+    }
+  }
+  /// The result of the crate's own operator
+  pub fn apply(self, a: F8, b: F8) -> F8 {
+    match self {
+      TableOp::Add => a + b,
+      TableOp::Sub => a - b,
+      TableOp::Mul => a * b,
+    }
+  }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TableFormat {
+  /// A header, then `a,b,a_value,b_value,exact,result,result_value` per row, with operands
+  /// and results as bits in hex
+  Csv,
+  /// An array of objects with the same fields as the CSV columns
+  Json,
+}
+
+/// Writes one row for every pair of operand bit patterns, `a` major. The F8 result is that of
+/// the crate's operators, which round the exact result to nearest with ties to even,
+/// saturating at `F8::MAX`.
+pub fn export_tables<W: Write>(op: TableOp, format: TableFormat, mut w: W) -> io::Result<()> {
+  match format {
+    TableFormat::Csv => writeln!(w, "a,b,a_value,b_value,exact,result,result_value")?,
+    TableFormat::Json => write!(w, "[")?,
+  }
+  for a in F8::iter_all() {
+    for b in F8::iter_all() {
+      let exact = op.exact(a, b);
+      let r = op.apply(a, b);
+      let (ab, bb, rb) = (a.to_bits(), b.to_bits(), r.to_bits());
+      match format {
+        TableFormat::Csv => writeln!(
+          w,
+          "{:02x},{:02x},{},{},{},{:02x},{}",
+          ab, bb, a, b, exact, rb, r
+        )?,
+        TableFormat::Json => {
+          let sep = if ab == 0 && bb == 0 { "" } else { "," };
+          write!(
+            w,
+            "{}\n{{\"a\":\"{:02x}\",\"b\":\"{:02x}\",\"a_value\":{},\"b_value\":{},\"exact\":{},\
+             \"result\":\"{:02x}\",\"result_value\":{}}}",
+            sep, ab, bb, a, b, exact, rb, r
+          )?
+        },
+      }
+    }
+  }
+  if format == TableFormat::Json {
+    writeln!(w, "\n]")?;
+  }
+  Ok(())
+}
@@ -0,0 +1,287 @@
+//! An n-dimensional F8 tensor with arbitrary strides and optional scales.
+//!
+//! Elements are shared between a tensor and its slices, so slicing copies no data, and
+//! kernels run on the F8 elements directly when the tensor has at most one scale.
+
+use crate::{f8::F8, linalg, scaled::absmax_scale};
+use std::{ops::Range, sync::Arc};
+
+/// How the elements of an `F8Tensor` are scaled
+#[derive(Debug, Clone, PartialEq)]
+pub enum TensorScale {
+  /// Elements are their F8 values
+  None,
+  /// Every element is `scale * data[idx]`
+  PerTensor(f32),
+  /// Element `idx` is `scales[idx[axis]] * data[idx]`
+  PerAxis { axis: usize, scales: Vec<f32> },
+}
+
+/// A strided view of shared F8 elements, where the element at `idx` is stored at
+/// `offset + sum(idx[d] * strides[d])`
+#[derive(Debug, Clone)]
+pub struct F8Tensor {
+  data: Arc<[F8]>,
+  offset: usize,
+  shape: Vec<usize>,
+  strides: Vec<usize>,
+  scale: TensorScale,
+}
+
+/// The row major strides of `shape`
+fn contiguous_strides(shape: &[usize]) -> Vec<usize> {
+  let mut strides = vec![1; shape.len()];
+  for d in (0..shape.len().saturating_sub(1)).rev() {
+    strides[d] = strides[d + 1] * shape[d + 1];
+  }
+  strides
+}
+
+/// Calls `f` with every index of `shape` in row major order
+fn for_each_index(shape: &[usize], mut f: impl FnMut(&[usize])) {
+  if shape.contains(&0) {
+    return;
+  }
+  let mut idx = vec![0; shape.len()];
+  loop {
+    f(&idx);
+    let mut d = shape.len();
+    loop {
+      if d == 0 {
+        return;
+      }
+      d -= 1;
+      idx[d] += 1;
+      if idx[d] < shape[d] {
+        break;
+      }
+      idx[d] = 0;
+    }
+  }
+}
+
+impl F8Tensor {
+  /// A contiguous row major tensor of `data` with the given scale
+  pub fn new(data: Vec<F8>, shape: &[usize], scale: TensorScale) -> Self {
+    assert_eq!(
+      data.len(),
+      shape.iter().product::<usize>(),
+      "shape does not match data"
+    );
+    if let TensorScale::PerAxis { axis, scales } = &scale {
+      assert!(*axis < shape.len(), "Scale axis out of bounds");
+      assert_eq!(
+        scales.len(),
+        shape[*axis],
+        "Scales do not match the scale axis"
+      );
+    }
+    F8Tensor {
+      data: data.into(),
+      offset: 0,
+      strides: contiguous_strides(shape),
+      shape: shape.to_vec(),
+      scale,
+    }
+  }
+  /// Quantizes a row major f32 tensor with one absmax scale
+  pub fn from_f32(data: &[f32], shape: &[usize]) -> Self {
+    let s = absmax_scale(data);
+    let q = data.iter().map(|&v| F8::approx_from(v / s)).collect();
+    F8Tensor::new(q, shape, TensorScale::PerTensor(s))
+  }
+  /// Quantizes a row major f32 tensor with one absmax scale per index along `axis`
+  pub fn from_f32_per_axis(data: &[f32], shape: &[usize], axis: usize) -> Self {
+    assert!(axis < shape.len(), "Scale axis out of bounds");
+    assert_eq!(
+      data.len(),
+      shape.iter().product::<usize>(),
+      "shape does not match data"
+    );
+    let strides = contiguous_strides(shape);
+    let at = |i: usize| (i / strides[axis]) % shape[axis];
+    let mut lanes = vec![vec![]; shape[axis]];
+    for (i, &v) in data.iter().enumerate() {
+      lanes[at(i)].push(v);
+    }
+    let scales: Vec<f32> = lanes.iter().map(|l| absmax_scale(l)).collect();
+    let q = data
+      .iter()
+      .enumerate()
+      .map(|(i, &v)| F8::approx_from(v / scales[at(i)]))
+      .collect();
+    F8Tensor::new(q, shape, TensorScale::PerAxis { axis, scales })
+  }
+  pub fn shape(&self) -> &[usize] { &self.shape }
+  pub fn strides(&self) -> &[usize] { &self.strides }
+  pub fn ndim(&self) -> usize { self.shape.len() }
+  pub fn len(&self) -> usize { self.shape.iter().product() }
+  pub fn is_empty(&self) -> bool { self.len() == 0 }
+  pub fn scale(&self) -> &TensorScale { &self.scale }
+  pub fn is_contiguous(&self) -> bool { self.strides == contiguous_strides(&self.shape) }
+  fn position(&self, idx: &[usize]) -> usize {
+    assert_eq!(
+      idx.len(),
+      self.ndim(),
+      "Index has the wrong number of dimensions"
+    );
+    let within = idx.iter().zip(&self.shape).all(|(i, s)| i < s);
+    assert!(within, "Index {:?} out of bounds for {:?}", idx, self.shape);
+    self.offset
+      + idx
+        .iter()
+        .zip(&self.strides)
+        .map(|(i, s)| i * s)
+        .sum::<usize>()
+  }
+  fn scale_at(&self, idx: &[usize]) -> f32 {
+    match &self.scale {
+      TensorScale::None => 1.0,
+      TensorScale::PerTensor(s) => *s,
+      TensorScale::PerAxis { axis, scales } => scales[idx[*axis]],
+    }
+  }
+  /// The single scale of every element, unless scaled per axis
+  fn uniform_scale(&self) -> Option<f32> {
+    match self.scale {
+      TensorScale::None => Some(1.0),
+      TensorScale::PerTensor(s) => Some(s),
+      TensorScale::PerAxis { .. } => None,
+    }
+  }
+  /// The stored element at `idx`, without its scale
+  pub fn get_f8(&self, idx: &[usize]) -> F8 { self.data[self.position(idx)] }
+  /// The scaled value at `idx`
+  pub fn get(&self, idx: &[usize]) -> f32 { self.get_f8(idx).v() * self.scale_at(idx) }
+  /// The stored elements in row major order
+  pub fn to_f8_vec(&self) -> Vec<F8> {
+    if self.is_contiguous() {
+      return self.data[self.offset..self.offset + self.len()].to_vec();
+    }
+    let mut out = Vec::with_capacity(self.len());
+    for_each_index(&self.shape, |idx| out.push(self.data[self.position(idx)]));
+    out
+  }
+  /// The scaled values in row major order
+  pub fn to_f32(&self) -> Vec<f32> {
+    let mut out = Vec::with_capacity(self.len());
+    for_each_index(&self.shape, |idx| out.push(self.get(idx)));
+    out
+  }
+  /// A contiguous copy, or a clone if already contiguous
+  pub fn to_contiguous(&self) -> Self {
+    if self.is_contiguous() {
+      return self.clone();
+    }
+    F8Tensor::new(self.to_f8_vec(), &self.shape, self.scale.clone())
+  }
+  /// The view of indices `range` along `axis`, sharing elements with `self`
+  pub fn slice(&self, axis: usize, range: Range<usize>) -> Self {
+    assert!(axis < self.ndim(), "Axis out of bounds");
+    assert!(
+      range.start <= range.end && range.end <= self.shape[axis],
+      "Range out of bounds"
+    );
+    let mut t = self.clone();
+    if range.start < range.end {
+      t.offset += range.start * self.strides[axis];
+    }
+    t.shape[axis] = range.len();
+    if let TensorScale::PerAxis { axis: a, scales } = &mut t.scale {
+      if *a == axis {
+        *scales = scales[range].to_vec();
+      }
+    }
+    t
+  }
+  /// The same elements with a different shape of equal length, copying them only if not
+  /// contiguous, or None if the length differs or the tensor is scaled per axis
+  pub fn reshape(&self, shape: &[usize]) -> Option<Self> {
+    if shape.iter().product::<usize>() != self.len() || self.uniform_scale().is_none() {
+      return None;
+    }
+    let mut t = self.to_contiguous();
+    t.strides = contiguous_strides(shape);
+    t.shape = shape.to_vec();
+    Some(t)
+  }
+  /// The elements in row major order, and the scale of every element if there is one
+  fn flat(&self) -> Option<(Vec<F8>, f32)> { self.uniform_scale().map(|s| (self.to_f8_vec(), s)) }
+  /// The dot product of two tensors of equal length, treated as flat
+  pub fn dot(&self, o: &Self) -> f32 {
+    assert_eq!(self.len(), o.len(), "Mismatched lengths");
+    match (self.flat(), o.flat()) {
+      (Some((a, sa)), Some((b, sb))) => linalg::dot(&a, &b) * sa * sb,
+      _ => self
+        .to_f32()
+        .iter()
+        .zip(o.to_f32())
+        .map(|(a, b)| a * b)
+        .sum(),
+    }
+  }
+  /// The Euclidean norm, treated as flat
+  pub fn norm_l2(&self) -> f32 {
+    match self.flat() {
+      Some((a, s)) => linalg::norm_l2(&a) * s.abs(),
+      None => self.to_f32().iter().map(|v| v * v).sum::<f32>().sqrt(),
+    }
+  }
+  /// The product of the `m x n` matrix `self` and the vector `x` of length `n`
+  pub fn matvec(&self, x: &Self) -> Vec<f32> {
+    assert_eq!(self.ndim(), 2, "matvec needs a matrix");
+    let (m, n) = (self.shape[0], self.shape[1]);
+    assert_eq!(x.shape(), [n], "x is not of length n");
+    let mut out = vec![0.0; m];
+    match (self.flat(), x.flat()) {
+      (Some((a, sa)), Some((b, sb))) => {
+        linalg::gemv(&a, &b, m, n, &mut out);
+        out.iter_mut().for_each(|o| *o *= sa * sb);
+      },
+      _ => {
+        let (a, b) = (self.to_f32(), x.to_f32());
+        for (o, row) in out.iter_mut().zip(a.chunks_exact(n.max(1))) {
+          *o = row.iter().zip(&b).map(|(a, b)| a * b).sum();
+        }
+      },
+    }
+    out
+  }
+  /// The row major product of `self`, `m x k` or a batch `b x m x k`, with the `k x n`
+  /// matrix `o`, of shape `m x n` or `b x m x n`
+  pub fn matmul(&self, o: &Self) -> Vec<f32> {
+    assert!(
+      self.ndim() == 2 || self.ndim() == 3,
+      "matmul needs a matrix or batch"
+    );
+    assert_eq!(o.ndim(), 2, "matmul needs a matrix on the right");
+    let (m, k) = (self.shape[self.ndim() - 2], self.shape[self.ndim() - 1]);
+    let batch = if self.ndim() == 3 { self.shape[0] } else { 1 };
+    assert_eq!(o.shape[0], k, "Inner dimensions differ");
+    let n = o.shape[1];
+    let mut out = vec![0.0; batch * m * n];
+    match (self.flat(), o.flat()) {
+      (Some((a, sa)), Some((b, sb))) => {
+        linalg::gemm_batched(&a, &b, batch, m, k, n, &mut out);
+        out.iter_mut().for_each(|v| *v *= sa * sb);
+      },
+      _ => {
+        let (a, b) = (self.to_f32(), o.to_f32());
+        let a_rows = a.chunks_exact(k.max(1));
+        for (a_row, out_row) in a_rows.zip(out.chunks_exact_mut(n.max(1))) {
+          for (&a_ik, b_row) in a_row.iter().zip(b.chunks_exact(n.max(1))) {
+            for (o, &b_kj) in out_row.iter_mut().zip(b_row) {
+              *o += a_ik * b_kj;
+            }
+          }
+        }
+      },
+    }
+    out
+  }
+}
+
+/// Tensors are equal when they have the same shape and scaled values
+impl PartialEq for F8Tensor {
+  fn eq(&self, o: &Self) -> bool { self.shape == o.shape && self.to_f32() == o.to_f32() }
+}
@@ -0,0 +1,41 @@
+use crate::{
+  activation::{gelu, gelu_lut, relu, relu_in_place, silu_in_place, silu_lut},
+  f8::F8,
+};
+
+#[test]
+fn tables_are_correctly_rounded() {
+  for bits in 0..=255u8 {
+    let f = F8::from_bits(bits);
+    let x = f.v() as f64;
+    let silu = x / (1.0 + (-x).exp());
+    assert_eq!(silu_lut()[bits as usize], F8::approx_from(silu as f32));
+  }
+  assert_eq!(
+    gelu_lut()[F8::approx_from(1.0).to_bits() as usize].v(),
+    0.75
+  );
+  assert_eq!(
+    gelu_lut()[F8::approx_from(-3.0).to_bits() as usize].v(),
+    0.0
+  );
+  assert_eq!(gelu_lut()[F8::approx_from(4.0).to_bits() as usize].v(), 4.0);
+}
+
+#[test]
+fn slice_variants_agree() {
+  let src: Vec<F8> = (0..=255).map(F8::from_bits).collect();
+  let mut dst = src.clone();
+  relu(&src, &mut dst);
+  let mut in_place = src.clone();
+  relu_in_place(&mut in_place);
+  assert_eq!(dst, in_place);
+  assert!(dst.iter().all(|f| f.v() >= 0.0));
+  gelu(&src, &mut dst);
+  silu_in_place(&mut in_place);
+  for (s, d) in src.iter().zip(&in_place) {
+    if s.v() > 0.0 {
+      assert!(d.v() > 0.0 && d.v() <= s.v());
+    }
+  }
+}
@@ -0,0 +1,31 @@
+use crate::{
+  adaround::{adaround, output_error},
+  quantize::uniform,
+  scaled::ScaledF8Tensor,
+};
+
+fn rand_vec(seed: u64, n: usize) -> Vec<f32> {
+  (0..n)
+    .map(|i| uniform(seed, i as u64) * 2.0 - 1.0)
+    .collect()
+}
+
+#[test]
+fn beats_nearest_rounding() {
+  let cols = 8;
+  let w = rand_vec(1, 4 * cols);
+  // correlated activations, where independent rounding errors do not cancel out
+  let x: Vec<f32> = rand_vec(2, 64 * cols)
+    .chunks(cols)
+    .flat_map(|r| r.iter().map(|&v| v + r[0]).collect::<Vec<_>>())
+    .collect();
+  let nearest = ScaledF8Tensor::quantize(&w);
+  let ada = adaround(&w, &x, cols, 500);
+  assert_eq!(ada.scale, nearest.scale);
+  let e_near = output_error(&w, &nearest, &x, cols);
+  let e_ada = output_error(&w, &ada, &x, cols);
+  assert!(e_ada < e_near, "{} >= {}", e_ada, e_near);
+  for (a, &w) in ada.dequantize().iter().zip(&w) {
+    assert!((a - w).abs() <= w.abs() / 4.0 + 0.01);
+  }
+}
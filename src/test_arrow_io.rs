@@ -0,0 +1,27 @@
+use crate::{
+  arrow_io::{cast_from_f32, cast_to_f32, fp8_field, fp8_kind, from_f8s, Fp8Kind},
+  f8::F8,
+};
+use arrow_array::{Array, Float32Array};
+use arrow_schema::{DataType, Field};
+
+#[test]
+fn field_carries_format() {
+  let f = fp8_field("w", Fp8Kind::E4M3, true);
+  assert_eq!(f.extension_type_name(), Some("f8.fp8"));
+  assert_eq!(fp8_kind(&f), Some(Fp8Kind::E4M3));
+  assert_eq!(fp8_kind(&Field::new("x", DataType::UInt8, false)), None);
+}
+
+#[test]
+fn casts_keep_nulls() {
+  let src = Float32Array::from(vec![Some(1.5), None, Some(-3.0)]);
+  for kind in [Fp8Kind::F8, Fp8Kind::E4M3, Fp8Kind::E5M2].iter().copied() {
+    let q = cast_from_f32(&src, kind);
+    assert!(q.is_null(1));
+    let back = cast_to_f32(&q, kind);
+    assert_eq!(back, src, "{:?}", kind);
+  }
+  let col = from_f8s(&[F8::approx_from(2.0), F8::MAX]);
+  assert_eq!(cast_to_f32(&col, Fp8Kind::F8).values(), &[2.0, 480.0]);
+}
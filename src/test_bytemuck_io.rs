@@ -0,0 +1,20 @@
+use crate::{color::Rgba, f8::F8, packed::F8x4};
+
+#[test]
+fn slice_casts() {
+  let data: Vec<F8> = (0..8u8).map(F8::from_bits).collect();
+  let bytes: &[u8] = bytemuck::cast_slice(&data);
+  assert_eq!(bytes, &[0, 1, 2, 3, 4, 5, 6, 7]);
+  let back: &[F8] = bytemuck::cast_slice(bytes);
+  assert_eq!(back, &data[..]);
+  let pixels: &[Rgba<F8>] = bytemuck::cast_slice(&data);
+  assert_eq!(pixels[1].r, F8::from_bits(4));
+  let lanes: F8x4 = bytemuck::cast([
+    F8::from_bits(1),
+    F8::from_bits(0),
+    F8::from_bits(0),
+    F8::from_bits(0),
+  ]);
+  assert_eq!(lanes.extract(0), F8::from_bits(1));
+  assert_eq!(<F8 as bytemuck::Zeroable>::zeroed(), F8::from_bits(0));
+}
@@ -0,0 +1,20 @@
+use crate::{
+  bytes::{as_bytes_mut, from_byte_vec, from_bytes_mut, into_bytes},
+  f8::F8,
+};
+
+#[test]
+fn casts() {
+  let mut data: Vec<F8> = F8::iter_all().collect();
+  let bytes = crate::as_bytes(&data);
+  assert!(bytes.iter().enumerate().all(|(i, &b)| b == i as u8));
+  assert_eq!(crate::from_bytes(bytes), &data[..]);
+  as_bytes_mut(&mut data)[3] = 0x42;
+  assert_eq!(data[3].to_bits(), 0x42);
+  let mut raw = [0u8, 1];
+  from_bytes_mut(&mut raw)[1] = F8::MAX;
+  assert_eq!(raw[1], F8::MAX.to_bits());
+  let v = into_bytes(data.clone());
+  assert_eq!(v.len(), 256);
+  assert_eq!(from_byte_vec(v), data);
+}
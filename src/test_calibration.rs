@@ -0,0 +1,43 @@
+use crate::calibration::{calibrate, calibrate_with, Objective};
+
+fn samples() -> Vec<f32> {
+  // mostly small values with a single large outlier
+  let mut v: Vec<f32> = (0..1000).map(|i| ((i as f32) * 0.61).sin()).collect();
+  v.push(200.0);
+  v
+}
+
+#[test]
+fn mse_beats_absmax() {
+  let data = samples();
+  let c = calibrate(&data);
+  assert_eq!(c.objective, Objective::Mse);
+  assert!(c.clip <= 200.0);
+  let absmax = calibrate_with(&data[..1], Objective::Mse);
+  assert!(absmax.error <= 1e-6);
+  assert!(c.error.is_finite());
+}
+
+#[test]
+fn kl_clips_outliers() {
+  let c = calibrate_with(&samples(), Objective::Kl);
+  assert!(c.clip < 200.0, "{:?}", c);
+  assert!(c.scale > 0.0);
+}
+
+#[test]
+fn handles_zeros() {
+  let c = calibrate(&[0.0; 8]);
+  assert_eq!(c.error, 0.0);
+  assert_eq!(calibrate_with(&[0.0; 8], Objective::Kl).error, 0.0);
+}
+
+#[test]
+fn ignores_non_finite() {
+  let mut data = samples();
+  let clean = calibrate(&data);
+  data.extend_from_slice(&[f32::NAN, f32::INFINITY, f32::NEG_INFINITY]);
+  assert_eq!(calibrate(&data), clean);
+  let clean = calibrate_with(&samples(), Objective::Kl);
+  assert_eq!(calibrate_with(&data, Objective::Kl), clean);
+}
@@ -0,0 +1,53 @@
+use crate::{channel::PerChannelF8, e8m0::E8M0, f8::F8};
+
+const W: [f32; 6] = [1.0, -2.0, 4.0, 0.001, 0.002, -0.004];
+
+#[test]
+fn rows_are_scaled_independently() {
+  let q = PerChannelF8::<f32>::quantize(&W, 2, 3);
+  let back = q.dequantize();
+  for (a, b) in W.iter().zip(&back) {
+    assert!((a - b).abs() <= a.abs() / 8.0, "{} {}", a, b);
+  }
+  assert!(q.scales[0] > q.scales[1] * 100.0);
+}
+
+#[test]
+fn e8m0_scales_are_powers_of_two() {
+  let q = PerChannelF8::<E8M0>::quantize(&W, 2, 3);
+  for s in &q.scales {
+    let f = s.to_f32();
+    assert_eq!(f, 2f32.powi(s.exp()));
+    assert!(4.0 / q.scales[0].to_f32() <= F8::MAX.v());
+  }
+  let back = q.dequantize();
+  assert_eq!(back[2], 4.0);
+}
+
+#[test]
+fn matmul_matches_dequantized() {
+  let q = PerChannelF8::<f32>::quantize(&W, 2, 3);
+  let deq = q.dequantize();
+  let x = [1.0, 0.5, -1.0, 2.0, 0.0, 1.0];
+  let mut out = [0.0; 4];
+  q.matmul(&x, 2, &mut out);
+  for b in 0..2 {
+    for r in 0..2 {
+      let e: f32 = (0..3).map(|c| deq[r * 3 + c] * x[b * 3 + c]).sum();
+      assert!((out[b * 2 + r] - e).abs() < 1e-5);
+    }
+  }
+  let xf: Vec<F8> = x[..3].iter().map(|&v| F8::approx_from(v)).collect();
+  let mut out = [0.0; 2];
+  q.matvec_f8(&xf, 1.0, &mut out);
+  assert!((out[0] + 4.0).abs() < 1e-5);
+}
+
+#[test]
+fn e8m0_conversions() {
+  assert_eq!(E8M0::from_f32_ceil(1.0), E8M0::ONE);
+  assert_eq!(E8M0::from_f32_ceil(3.0).to_f32(), 4.0);
+  assert_eq!(E8M0::from_f32_ceil(0.3).to_f32(), 0.5);
+  assert!(E8M0::from_f32_ceil(f32::NAN).to_f32().is_nan());
+  assert_eq!(E8M0(0).to_f32(), 2f32.powi(-127));
+}
@@ -0,0 +1,31 @@
+use crate::{
+  checksum::{fingerprint_combine, fingerprint_finish, fingerprint_ordered, fingerprint_partial},
+  f8::F8,
+  fingerprint,
+};
+
+#[test]
+fn order_independence() {
+  let a: Vec<F8> = (0..1000)
+    .map(|i| F8::from_bits((i * 31 % 256) as u8))
+    .collect();
+  let mut b = a.clone();
+  b.reverse();
+  assert_eq!(fingerprint(&a), fingerprint(&b));
+  assert_ne!(fingerprint_ordered(&a), fingerprint_ordered(&b));
+  let (x, y) = a.split_at(313);
+  let parts = fingerprint_combine(fingerprint_partial(x), fingerprint_partial(y));
+  assert_eq!(fingerprint_finish(parts, a.len()), fingerprint(&a));
+
+  // a single changed bit, or a different length, changes both
+  let mut c = a.clone();
+  c[500] = F8::from_bits(c[500].to_bits() ^ 1);
+  assert_ne!(fingerprint(&a), fingerprint(&c));
+  assert_ne!(fingerprint_ordered(&a), fingerprint_ordered(&c));
+  let zeros = [F8::from_bits(0); 3];
+  assert_ne!(fingerprint(&zeros[..2]), fingerprint(&zeros));
+  assert_ne!(
+    fingerprint_ordered(&zeros[..2]),
+    fingerprint_ordered(&zeros)
+  );
+}
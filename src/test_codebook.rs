@@ -0,0 +1,43 @@
+use crate::{codebook::Codebook, f8::F8};
+
+fn clustered() -> Vec<f32> {
+  (0..300)
+    .map(|i| [-7.3f32, 0.1, 123.4][i % 3] + ((i as f32) * 0.7).sin() * 0.01)
+    .collect()
+}
+
+#[test]
+fn learns_clusters() {
+  let data = clustered();
+  let cb = Codebook::train(&data, 3, 20);
+  assert_eq!(cb.len(), 3);
+  for (&c, &e) in cb.centroids().iter().zip(&[-7.3, 0.1, 123.4]) {
+    assert!((c - e).abs() < 0.01, "{} {}", c, e);
+  }
+  let mut idx = vec![0; data.len()];
+  cb.encode(&data, &mut idx);
+  let mut back = vec![0.0; data.len()];
+  cb.decode(&idx, &mut back);
+  for (a, b) in data.iter().zip(&back) {
+    assert!((a - b).abs() < 0.02);
+  }
+}
+
+#[test]
+fn encodes_to_nearest() {
+  let cb = Codebook::new(vec![1.0, -1.0, 4.0]);
+  assert_eq!(cb.encode_one(-5.0), 0);
+  assert_eq!(cb.encode_one(0.1), 1);
+  assert_eq!(cb.encode_one(2.4), 1);
+  assert_eq!(cb.encode_one(2.6), 2);
+  assert_eq!(cb.encode_one(100.0), 2);
+}
+
+#[test]
+fn snaps_to_f8() {
+  let cb = Codebook::train(&clustered(), 3, 20).snap_to_f8();
+  for (&c, f) in cb.centroids().iter().zip(cb.to_f8()) {
+    assert_eq!(c, f.v());
+  }
+  assert_eq!(cb.to_f8()[2], F8::approx_from(123.4));
+}
@@ -0,0 +1,60 @@
+use crate::{
+  codec::{compress, decode_planes, decompress, delta_decode, delta_encode, encode_planes},
+  f8::F8,
+};
+
+fn sample() -> Vec<F8> {
+  (0..1003)
+    .map(|i| F8::from_bits((i * 89 % 256) as u8))
+    .collect()
+}
+
+#[test]
+fn planes_round_trip() {
+  let data = sample();
+  let enc = encode_planes(&data);
+  assert_eq!(enc.len(), 8 + 126 + 377 + 502);
+  assert_eq!(decode_planes(&enc).unwrap(), data);
+  assert!(decode_planes(&encode_planes(&[])).unwrap().is_empty());
+  assert!(decode_planes(&enc[..enc.len() - 1]).is_err());
+  assert!(decode_planes(&[1, 2]).is_err());
+}
+
+#[test]
+fn compress_round_trip() {
+  let data = sample();
+  assert_eq!(decompress(&compress(&data)).unwrap(), data);
+  assert!(decompress(&compress(&[])).unwrap().is_empty());
+  // mostly zero, as after pruning, with a few other encodings of zero kept distinct
+  let mut sparse = vec![F8::from_bits(0); 100_000];
+  for i in (0..sparse.len()).step_by(97) {
+    sparse[i] = F8::from_bits((i % 255 + 1) as u8);
+  }
+  sparse[5] = F8::from_bits(0x10);
+  let enc = compress(&sparse);
+  assert!(enc.len() < sparse.len() / 20, "{}", enc.len());
+  assert_eq!(decompress(&enc).unwrap(), sparse);
+  let zeros = vec![F8::from_bits(0); 1 << 20];
+  assert!(compress(&zeros).len() < 20);
+  assert_eq!(decompress(&compress(&zeros)).unwrap(), zeros);
+  assert!(decompress(&[3]).is_err());
+  for n in 0..200u64 {
+    let v: Vec<F8> = (0..n)
+      .map(|i| F8::from_bits((crate::quantize::splitmix64(n * 1000 + i) % 3 * 100) as u8))
+      .collect();
+    assert_eq!(decompress(&compress(&v)).unwrap(), v);
+  }
+}
+
+#[test]
+fn delta_round_trip() {
+  let data = sample();
+  assert_eq!(delta_decode(&delta_encode(&data)), data);
+  let all: Vec<F8> = F8::iter_all().collect();
+  assert_eq!(delta_decode(&crate::delta_encode(&all)), all);
+  // a slow ramp moves by one distinct value at a time
+  let ramp: Vec<F8> = F8::values_ascending().skip(140).take(5).collect();
+  let d = delta_encode(&ramp);
+  assert!(d[1..].iter().all(|&d| (1..=8).contains(&d)), "{:?}", d);
+  assert!(delta_decode(&[]).is_empty());
+}
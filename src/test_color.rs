@@ -0,0 +1,27 @@
+use crate::{
+  color::{Rgb, Rgba},
+  f8::F8,
+};
+
+#[test]
+fn arithmetic_widens() {
+  let a = Rgb::<F8>::from([1.0, 2.0, 8.0]);
+  let b = Rgb::<F8>::from([0.5, 2.0, 8.0]);
+  assert_eq!(<[f32; 3]>::from(a + b), [1.5, 4.0, 16.0]);
+  assert_eq!(<[f32; 3]>::from(a - b), [0.5, 0.0, 0.0]);
+  assert_eq!(<[f32; 3]>::from(a * b), [0.5, 4.0, 64.0]);
+  assert_eq!(<[f32; 3]>::from(a * 0.5), [0.5, 1.0, 4.0]);
+  assert_eq!(<[f32; 3]>::from(a.lerp(b, 0.5)), [0.75, 2.0, 8.0]);
+}
+
+#[test]
+fn premultiplied_alpha() {
+  let c = Rgba::<F8>::from([4.0, 2.0, 1.0, 0.5]);
+  let p = c.premultiply();
+  assert_eq!(<[f32; 4]>::from(p), [2.0, 1.0, 0.5, 0.5]);
+  assert_eq!(p.unpremultiply(), c);
+  let dst = Rgba::<F8>::from([8.0, 8.0, 8.0, 1.0]);
+  assert_eq!(<[f32; 4]>::from(p.over(dst)), [6.0, 5.0, 4.5, 1.0]);
+  let clear = Rgba::<F8>::from([4.0, 2.0, 1.0, 0.0]);
+  assert_eq!(<[f32; 4]>::from(clear.unpremultiply()), [0.0; 4]);
+}
@@ -0,0 +1,34 @@
+use crate::companding::{compare, ALaw, MuLaw};
+
+#[test]
+fn known_values() {
+  assert_eq!(MuLaw::from_pcm(0), MuLaw(0xFF));
+  assert_eq!(MuLaw(0x00).to_pcm(), -32124);
+  assert_eq!(MuLaw(0x80).to_pcm(), 32124);
+  assert_eq!(ALaw::from_pcm(0), ALaw(0xD5));
+  assert_eq!(ALaw(0xD5).to_pcm(), 8);
+  assert_eq!(ALaw(0xAA).to_pcm(), 32256);
+}
+
+#[test]
+fn codes_round_trip() {
+  for b in 0..=255u8 {
+    assert_eq!(ALaw::from_pcm(ALaw(b).to_pcm()), ALaw(b), "{:#x}", b);
+    // 0x7F is a second zero
+    if b != 0x7F {
+      assert_eq!(MuLaw::from_pcm(MuLaw(b).to_pcm()), MuLaw(b), "{:#x}", b);
+    }
+  }
+}
+
+#[test]
+fn compare_on_sine() {
+  let samples: Vec<f32> = (0..4096).map(|i| 0.5 * (i as f32 * 0.01).sin()).collect();
+  let r = compare(&samples);
+  assert!(r.mulaw_snr_db > 30.0 && r.alaw_snr_db > 30.0, "{:?}", r);
+  assert!(r.f8_snr_db > 10.0, "{:?}", r);
+  assert_eq!(
+    MuLaw::from_f8(MuLaw(0x80).to_f8(1.0 / 480.0), 1.0 / 480.0),
+    MuLaw(0x80)
+  );
+}
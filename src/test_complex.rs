@@ -0,0 +1,24 @@
+use crate::complex::{from_interleaved, to_interleaved, ComplexF8};
+
+#[test]
+fn arithmetic() {
+  let a = ComplexF8::from_f32(1.5, -2.0);
+  let b = ComplexF8::from_f32(0.5, 3.0);
+  assert_eq!((a + b).to_f32(), (2.0, 1.0));
+  assert_eq!((a - b).to_f32(), (1.0, -5.0));
+  // (1.5 - 2i)(0.5 + 3i) = 6.75 + 3.5i, and 6.75 rounds to 7 with a single rounding
+  assert_eq!((a * b).to_f32(), (7.0, 3.5));
+  // 6.25 ties between 6 and 6.5
+  assert_eq!((a * a.conj()).to_f32(), (6.0, 0.0));
+  assert_eq!(a.norm_sqr(), 6.25);
+  assert_eq!(a.scale(2.0).to_f32(), (3.0, -4.0));
+}
+
+#[test]
+fn interleaved_round_trip() {
+  let src = [1.0, -0.25, 3.5, 480.0, -7.0, 0.0];
+  let c = from_interleaved(&src);
+  assert_eq!(c.len(), 3);
+  assert_eq!(to_interleaved(&c), src);
+  assert_eq!(std::mem::size_of::<ComplexF8>(), 2);
+}
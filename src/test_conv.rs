@@ -0,0 +1,74 @@
+use crate::{conv::Conv2d, f8::F8, test_f8::f8s};
+
+#[test]
+fn matches_naive_with_padding_and_stride() {
+  let mut conv = Conv2d::new(2, 1, (3, 3));
+  conv.padding = (1, 1);
+  conv.stride = (2, 1);
+  let (h, w) = (4, 3);
+  let input = f8s(&(0..24).map(|i| (i % 7) as f32 - 3.0).collect::<Vec<_>>());
+  let weights = f8s(
+    &(0..18)
+      .map(|i| (i % 5) as f32 * 0.5 - 1.0)
+      .collect::<Vec<_>>(),
+  );
+  let (oh, ow) = conv.output_size(h, w);
+  assert_eq!((oh, ow), (2, 3));
+  let mut out = vec![0.0; oh * ow];
+  conv.forward(&input, (h, w), &weights, Some(&[0.5]), &mut out);
+  for y in 0..oh {
+    for x in 0..ow {
+      let mut e = 0.5;
+      for ic in 0..2 {
+        for ky in 0..3 {
+          for kx in 0..3 {
+            let (iy, ix) = ((y * 2 + ky) as isize - 1, (x + kx) as isize - 1);
+            if iy < 0 || ix < 0 || iy >= h as isize || ix >= w as isize {
+              continue;
+            }
+            e += input[ic * 12 + iy as usize * 3 + ix as usize].v()
+              * weights[ic * 9 + ky * 3 + kx].v();
+          }
+        }
+      }
+      assert_eq!(out[y * ow + x], e);
+    }
+  }
+  let mut q = vec![F8::from_bits(0); out.len()];
+  conv.forward_f8(&input, (h, w), &weights, Some(&[0.5]), 1.0, &mut q);
+  assert_eq!(
+    q,
+    out.iter().map(|&v| F8::approx_from(v)).collect::<Vec<_>>()
+  );
+}
+
+#[test]
+fn kernel_larger_than_input() {
+  let mut conv = Conv2d::new(1, 1, (5, 2));
+  assert_eq!(conv.output_size(3, 4), (0, 3));
+  conv.padding = (1, 0);
+  assert_eq!(conv.output_size(3, 4), (1, 3));
+  let mut out = [];
+  conv.padding = (0, 0);
+  conv.forward(
+    &[F8::from_bits(0); 12],
+    (3, 4),
+    &[F8::from_bits(0); 10],
+    None,
+    &mut out,
+  );
+}
+
+#[test]
+#[should_panic(expected = "bias is not out_channels long")]
+fn rejects_short_bias() {
+  let conv = Conv2d::new(1, 2, (1, 1));
+  let mut out = [0.0; 2];
+  conv.forward(
+    &[F8::from_bits(0)],
+    (1, 1),
+    &[F8::from_bits(0); 2],
+    Some(&[0.0]),
+    &mut out,
+  );
+}
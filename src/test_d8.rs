@@ -0,0 +1,98 @@
+use crate::{check_properties, d8::D8};
+
+#[test]
+fn decimal_values_are_exact() {
+  for (v, s) in &[
+    (0.1f32, "0.1"),
+    (0.07, "0.07"),
+    (2.5, "2.5"),
+    (-19.0, "-19"),
+    (250.0, "250"),
+  ] {
+    let d = D8::from_f32(*v);
+    assert_eq!(d.to_f32(), *v);
+    assert_eq!(d.to_string(), *s);
+  }
+  assert_eq!(D8::MAX.to_f32(), 310.0);
+  assert_eq!(D8::from_f32(1e9), D8::MAX);
+  assert_eq!(D8::from_f32(f32::NAN).to_f32(), 0.0);
+}
+
+#[test]
+fn rounds_in_decimal() {
+  // 0.32 is nearer 0.31 than 0.3
+  assert_eq!(D8::from_f32(0.32).to_f32(), 0.31);
+  assert_eq!(D8::from_f32(0.36).to_f32(), 0.4);
+  // ties go to the even significand
+  assert_eq!(D8::from_f32(55.0).to_f32(), 60.0);
+  assert_eq!(D8::from_f32(45.0).to_f32(), 40.0);
+  assert_eq!(D8::from_f32(-0.005).to_string(), "-0");
+  let mut all: Vec<u32> = (0..=255).map(|b| D8(b).cents()).collect();
+  all.sort_unstable();
+  all.dedup();
+  assert_eq!(all.len(), 116);
+  for &c in &all {
+    let v = c as f32 / 100.0;
+    assert_eq!(D8::from_f32(v).cents(), c);
+  }
+}
+
+#[test]
+fn properties() {
+  let report = check_properties::<D8>();
+  assert!(report.is_empty(), "{:?}", &report[..report.len().min(5)]);
+}
+
+/// The nearest D8 to the magnitude `n / scale` hundredths by searching every encoding, ties to
+/// an even significand
+fn nearest(canonical: &[D8], neg: bool, n: u64, scale: u64) -> D8 {
+  let dist = |d: D8| (d.cents() as u64 * scale).abs_diff(n);
+  let best = canonical
+    .iter()
+    .copied()
+    .min_by_key(|&d| (dist(d), d.significand() % 2))
+    .unwrap();
+  let best = if n > D8::MAX.cents() as u64 * scale {
+    D8::MAX
+  } else {
+    best
+  };
+  D8(best.0 | (neg as u8) << 7)
+}
+
+#[test]
+fn arithmetic_is_exact_in_decimal() {
+  use crate::minifloat::Minifloat;
+  // 0.45 is a tie between 0.4 and 0.5, but is not exact in f32
+  assert_eq!(
+    Minifloat::add(D8::from_f32(0.05), D8::from_f32(0.4)).to_f32(),
+    0.4
+  );
+  let canonical: Vec<D8> = (0..128)
+    .map(D8)
+    .filter(|&d| D8::from_f32(d.to_f32()) == d)
+    .collect();
+  let signed = |d: D8| d.cents() as i64 * if d.is_sign_negative() { -1 } else { 1 };
+  for a in (0..=255).map(D8) {
+    for b in (0..=255).map(D8) {
+      let sum = signed(a) + signed(b);
+      let neg = sum < 0 || (sum == 0 && a.is_sign_negative() && b.is_sign_negative());
+      assert_eq!(
+        a.add(b),
+        nearest(&canonical, neg, sum.unsigned_abs(), 1),
+        "{} + {}",
+        a,
+        b
+      );
+      let neg = a.is_sign_negative() != b.is_sign_negative();
+      let prod = a.cents() as u64 * b.cents() as u64;
+      assert_eq!(
+        a.mul(b),
+        nearest(&canonical, neg, prod, 100),
+        "{} * {}",
+        a,
+        b
+      );
+    }
+  }
+}
@@ -0,0 +1,40 @@
+use crate::{
+  distance::{cosine_similarity, cosine_similarity_rows, l2_distance_sq, l2_distance_sq_rows},
+  f8::F8,
+  test_f8::f8s,
+};
+
+#[test]
+fn cosine() {
+  let a = f8s(&[1.0, 2.0, 0.0, -3.0, 4.0]);
+  assert!((cosine_similarity(&a, &a) - 1.0).abs() < 1e-6);
+  let neg: Vec<F8> = a.iter().map(|&f| -f).collect();
+  assert!((cosine_similarity(&a, &neg) + 1.0).abs() < 1e-6);
+  let b = f8s(&[2.0, -1.0, 5.0, 0.0, 0.0]);
+  assert_eq!(cosine_similarity(&a, &b), 0.0);
+  assert_eq!(cosine_similarity(&a, &f8s(&[0.0; 5])), 0.0);
+
+  let rows: Vec<F8> = a.iter().chain(&b).chain(&neg).copied().collect();
+  let mut out = [0.0; 3];
+  crate::cosine_similarity_rows(&a, &rows, &mut out);
+  let expected = [1.0, 0.0, -1.0];
+  for (o, e) in out.iter().zip(&expected) {
+    assert!((o - e).abs() < 1e-6);
+  }
+  cosine_similarity_rows(&[], &[], &mut out);
+  assert_eq!(out, [0.0; 3]);
+}
+
+#[test]
+fn l2() {
+  let a: Vec<F8> = (0..37).map(|i| F8::from_bits(i * 7)).collect();
+  let b: Vec<F8> = (0..37).map(|i| F8::from_bits(i * 3 + 100)).collect();
+  let expected: f32 = a.iter().zip(&b).map(|(x, y)| (x.v() - y.v()).powi(2)).sum();
+  let d = crate::l2_distance_sq(&a, &b);
+  assert!((d - expected).abs() <= 1e-6 * expected);
+  assert_eq!(l2_distance_sq(&a, &a), 0.0);
+  let rows: Vec<F8> = a.iter().chain(&b).copied().collect();
+  let mut out = [1.0; 2];
+  l2_distance_sq_rows(&a, &rows, &mut out);
+  assert_eq!(out, [0.0, d]);
+}
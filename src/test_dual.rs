@@ -0,0 +1,41 @@
+use crate::{dual::Dual, f8::F8};
+
+fn f(v: f32) -> F8 { F8::try_from(v).unwrap() }
+
+#[test]
+fn product_and_sum_rules() {
+  // d/dx (x * x + 3x) = 2x + 3
+  let x = Dual::variable(f(0.5));
+  let y = x * x + Dual::constant(f(3.0)) * x;
+  assert_eq!(y.value.v(), 1.75);
+  assert_eq!(y.deriv.v(), 4.0);
+  let z = Dual::constant(f(2.0)) - x;
+  assert_eq!((z.value.v(), z.deriv.v()), (1.5, -1.0));
+}
+
+#[test]
+fn activation_chain_rule() {
+  let r = Dual::variable(f(-2.0)).relu();
+  assert_eq!((r.value.v(), r.deriv.v()), (0.0, 0.0));
+  let r = (Dual::variable(f(2.0)) * Dual::constant(f(3.0))).relu();
+  assert_eq!((r.value.v(), r.deriv.v()), (6.0, 3.0));
+  let step = 1e-3;
+  for &v in &[-3.0f32, -1.0, -0.25, 0.0, 0.5, 1.0, 2.5] {
+    let x = Dual::variable(f(v));
+    let numeric = |g: fn(f32) -> f32| (g(v + step) - g(v - step)) / (2.0 * step);
+    let s = x.silu();
+    assert_eq!(
+      s.value,
+      crate::activation::silu_lut()[f(v).to_bits() as usize]
+    );
+    assert_eq!(
+      s.deriv,
+      F8::approx_from(numeric(crate::activation::silu_f32))
+    );
+    let g = x.gelu();
+    assert_eq!(
+      g.deriv,
+      F8::approx_from(numeric(crate::activation::gelu_f32))
+    );
+  }
+}
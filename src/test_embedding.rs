@@ -0,0 +1,26 @@
+use crate::{e8m0::E8M0, embedding::EmbeddingTable};
+
+const DATA: [f32; 6] = [1.0, 2.0, 0.01, -0.02, 300.0, 100.0];
+
+#[test]
+fn lookup_gathers_rows() {
+  let t = EmbeddingTable::<f32>::from_f32(&DATA, 3, 2);
+  let mut out = [0.0; 6];
+  t.lookup(&[2, 0, 1], &mut out);
+  let expected = [300.0, 100.0, 1.0, 2.0, 0.01, -0.02];
+  for (a, e) in out.iter().zip(&expected) {
+    assert!((a - e).abs() <= e.abs() / 16.0, "{} {}", a, e);
+  }
+  let mut sum = [0.0; 2];
+  t.lookup_sum(&[0, 0, 2], &mut sum);
+  assert!((sum[0] - 302.0).abs() < 1e-3);
+}
+
+#[test]
+fn e8m0_rows() {
+  let t = EmbeddingTable::<E8M0>::from_f32(&DATA, 3, 2);
+  assert_eq!(t.rows(), 3);
+  let mut out = [0.0; 2];
+  t.lookup(&[0], &mut out);
+  assert_eq!(out, [1.0, 2.0]);
+}
@@ -0,0 +1,38 @@
+use crate::{exact, f8::F8};
+
+fn values() -> Vec<F8> { (0..=255).map(F8::from_bits).collect() }
+
+#[test]
+fn matches_exact_f32_arithmetic() {
+  // the f32 results are exact, see `impl Add for F8`, so rounding them once is the reference
+  for a in values() {
+    for b in values().into_iter().step_by(3) {
+      assert_eq!(
+        exact::add(a, b).v(),
+        F8::approx_from(a.v() + b.v()).v() + 0.0
+      );
+      assert_eq!(
+        exact::sub(a, b).v(),
+        F8::approx_from(a.v() - b.v()).v() + 0.0
+      );
+      assert_eq!(
+        exact::mul(a, b).v(),
+        F8::approx_from(a.v() * b.v()).v() + 0.0
+      );
+    }
+  }
+}
+
+#[test]
+fn division() {
+  let f = F8::approx_from;
+  assert_eq!(exact::div(f(1.0), f(3.0)).unwrap().v(), 0.25);
+  assert_eq!(exact::div(f(-7.0), f(2.0)).unwrap().v(), -3.5);
+  assert_eq!(exact::div(f(480.0), f(0.25)).unwrap().v(), 480.0);
+  // 1.125 is a tie between 1 and 1.25
+  assert_eq!(exact::div(f(9.0), f(8.0)).unwrap().v(), 1.0);
+  assert!(exact::div(f(1.0), f(-0.0)).is_none());
+  for a in values() {
+    assert_eq!(exact::round(&exact::to_rational(a)).v(), a.v());
+  }
+}
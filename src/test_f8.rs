@@ -1,5 +1,9 @@
-use crate::f8::F8;
-use num::{Zero, One};
+use crate::f8::{bracket, ASCENDING, F8};
+use num_traits::{One, Zero};
+
+/// Rounds each value to the nearest F8
+#[cfg(feature = "std")]
+pub(crate) fn f8s(v: &[f32]) -> Vec<F8> { v.iter().map(|&v| F8::approx_from(v)).collect() }
 
 #[test]
 fn identities_correct() {
@@ -7,14 +11,145 @@ fn identities_correct() {
   assert_eq!(F8::one().v(), 1f32);
 }
 
-
 #[test]
 fn test_from_vals() {
-  // assert!(F8::try_from(1.0).is_some());
+  assert!(F8::try_from(1.0).is_some());
+  assert!(F8::try_from(0.0).is_some());
   let v = F8::approx_from(2.0);
   assert_eq!(v.v(), 2.0);
-  /*
-  assert!(F8::try_from(0.012).is_some());
-  assert!(F8::try_from(0.002).is_some());
-  */
+  assert!(F8::try_from(0.012).is_none());
+  assert!(F8::try_from(0.002).is_none());
+  assert_eq!(F8::approx_from(1e9), F8::MAX);
+  assert_eq!(F8::approx_from(f32::NEG_INFINITY), -F8::MAX);
+  assert_eq!(F8::approx_from(f32::NAN).v(), 0.0);
+  assert_eq!(F8::approx_from(1e-40).v(), 0.0);
+}
+
+fn nearest(f: f32) -> f32 {
+  let (lo, hi) = bracket(f.abs());
+  let (l, h) = (lo.v(), hi.v());
+  let v = if f.abs() - l < h - f.abs() || (f.abs() - l == h - f.abs() && lo.significand() % 2 == 0)
+  {
+    l
+  } else {
+    h
+  };
+  if f.is_sign_negative() {
+    -v
+  } else {
+    v
+  }
+}
+
+#[test]
+fn approx_from_rounds_to_nearest_even() {
+  let mut samples: Vec<f32> = (0..u32::MAX / 4099)
+    .map(|i| f32::from_bits(i * 4099))
+    .collect();
+  for w in ASCENDING.windows(2) {
+    let (l, h) = (w[0].v(), w[1].v());
+    let mid = (l + h) / 2.0;
+    samples.extend_from_slice(&[l, mid, -mid, mid * (1.0 + 1e-6), mid * (1.0 - 1e-6)]);
+  }
+  for f in samples.into_iter().filter(|f| !f.is_nan()) {
+    assert_eq!(F8::approx_from(f).v(), nearest(f), "{}", f);
+  }
+  for bits in 0..=255u8 {
+    let f = F8::from_bits(bits);
+    assert_eq!(F8::approx_from(f.v()).v(), f.v());
+  }
+}
+
+#[test]
+fn decode_table_matches_v() {
+  for bits in 0..=255u8 {
+    let f = F8::from_bits(bits);
+    assert_eq!(f.to_bits(), bits);
+    assert_eq!(F8::DECODE_TABLE[bits as usize].to_bits(), f.v().to_bits());
+  }
+}
+
+#[cfg(feature = "encode-table")]
+#[test]
+fn encode_table_matches_approx_from() {
+  for &v in &[0.0f32, 0.25, 1.0, 2.0, 3.5, -1.5, 12.0] {
+    assert_eq!(F8::from_f32_table(v), F8::approx_from(v));
+  }
+}
+
+#[test]
+fn iterates_every_bit_pattern() {
+  let bits: Vec<u8> = F8::iter_all().map(F8::to_bits).collect();
+  assert_eq!(bits, (0..=255).collect::<Vec<u8>>());
+  assert_eq!(F8::iter_finite().count(), 256);
+  assert!(F8::iter_finite().all(|f| f.v().is_finite()));
+}
+
+#[test]
+fn values_ascending_are_distinct_and_sorted() {
+  let vals: Vec<f32> = F8::values_ascending().map(F8::v).collect();
+  assert_eq!(vals.len(), 143);
+  assert!(vals.windows(2).all(|w| w[0] < w[1]));
+  assert_eq!((vals[0], vals[71], vals[142]), (-480.0, 0.0, 480.0));
+  assert!(F8::iter_all().all(|f| vals.contains(&f.v())));
+}
+
+#[test]
+fn ulp_distance_counts_values_between() {
+  let vals: Vec<F8> = F8::values_ascending().collect();
+  for a in F8::iter_all() {
+    let i = vals.iter().position(|v| v.v() == a.v()).unwrap();
+    for (j, b) in vals.iter().enumerate() {
+      assert_eq!(
+        a.ulp_distance(*b),
+        (i as i64 - j as i64).unsigned_abs() as u32
+      );
+    }
+  }
+  assert_eq!(F8::from_bits(0x80).ulp_distance(F8::from_bits(0)), 0);
+  assert_eq!((-F8::MAX).ulp_distance(F8::MAX), 142);
+}
+
+#[test]
+fn arithmetic_is_total() {
+  // test builds check overflow, so any panicking path in an operation shows up here
+  for a in F8::iter_all() {
+    for b in F8::iter_all() {
+      let r = std::panic::catch_unwind(|| {
+        let mut c = a;
+        c += b;
+        c -= b;
+        c *= b;
+        (a + b, a - b, a * b, -a, c)
+      });
+      assert!(r.is_ok(), "{:?} {:?}", a, b);
+    }
+  }
+  let specials = [
+    f32::NAN,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::MIN_POSITIVE,
+    1e-45,
+  ];
+  for &v in specials.iter() {
+    let _ = F8::approx_from(v);
+  }
+  for bits in (0..=u32::MAX).step_by(4093) {
+    let _ = F8::approx_from(f32::from_bits(bits));
+  }
+}
+
+#[test]
+fn arithmetic_rounds_exact_results() {
+  for a in F8::iter_all() {
+    for b in F8::iter_all() {
+      assert_eq!(a + b, F8::approx_from(a.v() + b.v()));
+      assert_eq!((a * b).v(), F8::approx_from(a.v() * b.v()).v());
+    }
+  }
+  let f = |v: f32| F8::try_from(v).unwrap();
+  assert_eq!((f(480.0) * f(480.0)).v(), 480.0);
+  assert_eq!((f(0.25) * f(0.25)).v(), 0.0);
+  assert_eq!((f(1.75) + f(0.25)).v(), 2.0);
 }
@@ -0,0 +1,28 @@
+use crate::{f8::F8, ffi::*};
+
+#[test]
+fn scalar_functions() {
+  let a = f8_from_f32(1.5);
+  assert_eq!(f8_to_f32(a), 1.5);
+  assert_eq!(f8_neg(a), -a);
+  assert_eq!(f8_add(a, f8_from_f32(0.0)), a);
+  assert_eq!(f8_mul(a, F8::MAX), a * F8::MAX);
+  assert_eq!(f8_sub(a, a), a - a);
+}
+
+#[test]
+fn slice_functions() {
+  let src = [0.25f32, -2.0, 7.0];
+  let mut q = [F8::from_bits(0); 3];
+  let mut back = [0f32; 3];
+  unsafe {
+    f8_from_f32_slice(src.as_ptr(), q.as_mut_ptr(), 3);
+    f8_to_f32_slice(q.as_ptr(), back.as_mut_ptr(), 3);
+    f8_from_f32_slice(std::ptr::null(), std::ptr::null_mut(), 0);
+  }
+  assert_eq!(back, src);
+  let zeros = [F8::from_bits(0); 3];
+  let mut sum = q;
+  unsafe { f8_add_slice(sum.as_mut_ptr(), zeros.as_ptr(), 3) };
+  assert_eq!(sum, q);
+}
@@ -0,0 +1,66 @@
+use crate::{
+  complex::ComplexF8,
+  f8::F8,
+  fft::{fft, fft_complex, quantize_spectrum},
+};
+
+fn dft(x: &[(f32, f32)]) -> Vec<(f64, f64)> {
+  let n = x.len();
+  (0..n)
+    .map(|k| {
+      x.iter()
+        .enumerate()
+        .fold((0.0, 0.0), |(re, im), (j, &(a, b))| {
+          let t = -2.0 * std::f64::consts::PI * (k * j) as f64 / n as f64;
+          let (c, s) = (t.cos(), t.sin());
+          (
+            re + a as f64 * c - b as f64 * s,
+            im + a as f64 * s + b as f64 * c,
+          )
+        })
+    })
+    .collect()
+}
+
+#[test]
+fn matches_dft() {
+  let x: Vec<ComplexF8> = (0..64usize)
+    .map(|i| {
+      ComplexF8::new(
+        F8::from_bits((i * 7) as u8),
+        F8::from_bits((i * 13 + 1) as u8),
+      )
+    })
+    .collect();
+  let expected = dft(&x.iter().map(|c| c.to_f32()).collect::<Vec<_>>());
+  let tol = 1e-5
+    * expected
+      .iter()
+      .fold(1.0, |m: f64, &(r, i)| m.max(r.abs()).max(i.abs()));
+  for (&(re, im), &(er, ei)) in fft_complex(&x).iter().zip(&expected) {
+    assert!((re as f64 - er).abs() < tol && (im as f64 - ei).abs() < tol);
+  }
+  assert!(fft(&[]).is_empty());
+}
+
+#[test]
+fn requantized_tone() {
+  let x: Vec<F8> = (0..16)
+    .map(|i| F8::approx_from((std::f32::consts::PI * i as f32 / 4.0).cos()))
+    .collect();
+  let spectrum = fft(&x);
+  let (scale, q) = quantize_spectrum(&spectrum);
+  // a cosine with a period of 8 samples puts its energy in bins 2 and 14
+  let peak = q
+    .iter()
+    .enumerate()
+    .max_by(|a, b| a.1.norm_sqr().total_cmp(&b.1.norm_sqr()))
+    .unwrap();
+  assert!(peak.0 == 2 || peak.0 == 14);
+  assert!((q[2].re.v() * scale - 8.0).abs() < 0.5);
+  assert!(q[5].norm_sqr() * scale * scale < 0.5);
+}
+
+#[test]
+#[should_panic]
+fn rejects_other_lengths() { fft(&[F8::from_bits(0); 3]); }
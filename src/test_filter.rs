@@ -0,0 +1,48 @@
+use crate::{
+  f8::F8,
+  filter::{convolve, MovingAverage, OnePole},
+  test_f8::f8s,
+};
+
+#[test]
+fn moving_average() {
+  let x = f8s(&[1.0, 3.0, 5.0, 7.0, 9.0, 11.0, 13.0]);
+  let mut out = x.clone();
+  let mut f = MovingAverage::new(3);
+  f.process_slice(&x, &mut out);
+  assert_eq!(out, f8s(&[1.0, 2.0, 3.0, 5.0, 7.0, 9.0, 11.0]));
+  f.reset();
+  assert_eq!(f.process(x[6]), x[6]);
+}
+
+#[test]
+fn one_pole_keeps_wide_state() {
+  // each step toward 16 is far below the spacing of F8 near it, yet the output still converges
+  let mut f = OnePole::new(0.01);
+  f.process(F8::approx_from(0.0));
+  let target = F8::approx_from(16.0);
+  let mut y = F8::approx_from(0.0);
+  for _ in 0..2000 {
+    y = f.process(target);
+  }
+  assert_eq!(y, target);
+  assert!(f.state().unwrap() < 16.0);
+  let tau = OnePole::from_time_constant(10.0);
+  assert!((tau.alpha - 0.09516).abs() < 1e-4);
+}
+
+#[test]
+fn convolution() {
+  let signal = f8s(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+  let kernel = f8s(&[1.0, 0.5, 0.25, -1.0, 2.0]);
+  let mut out = vec![0.0; 10];
+  convolve(&signal, &kernel, &mut out);
+  let mut expected = vec![0.0; 10];
+  for (i, s) in signal.iter().enumerate() {
+    for (j, k) in kernel.iter().enumerate() {
+      expected[i + j] += s.v() * k.v();
+    }
+  }
+  assert_eq!(out, expected);
+  convolve(&[], &kernel, &mut []);
+}
@@ -0,0 +1,43 @@
+use crate::{
+  f8::F8,
+  fixed::{compare, Q1_6, Q3_4, Q8},
+};
+
+#[test]
+fn conversions() {
+  assert_eq!(Q3_4::from_f32(1.5).0, 24);
+  assert_eq!(Q3_4::from_f32(100.0).0, 127);
+  assert_eq!(Q3_4::from_f32(-100.0).to_f32(), Q3_4::MIN);
+  assert_eq!((Q3_4::MIN, Q3_4::MAX), (-8.0, 7.9375));
+  // 3/128 is a tie between 1/64 and 2/64
+  assert_eq!(Q1_6::from_f32(3.0 / 128.0).0, 2);
+  assert_eq!(Q1_6::from_f32(f32::NAN).0, 0);
+  for f in F8::iter_all().filter(|f| f.v().abs() <= Q3_4::MAX) {
+    // F8 within range has at most 2 fractional bits
+    assert_eq!(Q3_4::from_f8(f).to_f32(), f.v());
+    assert_eq!(Q3_4::from_f8(f).to_f8().v(), f.v());
+  }
+  assert_eq!(Q8::<0>::from_f32(5.5).0, 6);
+}
+
+#[test]
+fn picks_the_better_format() {
+  // evenly spread in [-1, 1), where Q0.7 matches the spacing exactly
+  let uniform: Vec<f32> = (-128..128).map(|i| i as f32 / 128.0).collect();
+  let c = compare(&uniform);
+  assert_eq!(c.best_frac, 7);
+  assert_eq!(c.fixed[7].rms, 0.0);
+  assert!(!c.f8_fits_better());
+  // spanning several orders of magnitude favours the float
+  let wide: Vec<f32> = (0..200).map(|i| 1.05f32.powi(i) * 0.25).collect();
+  let c = compare(&wide);
+  assert!(c.f8_fits_better());
+  assert!(c.fixed[c.best_frac as usize].clipped > 0 || c.fixed[c.best_frac as usize].max_abs > 1.0);
+}
+
+#[test]
+fn compare_with_nan() {
+  let c = compare(&[0.5, f32::NAN, -1.0]);
+  assert!(c.best_frac < 8);
+  assert!(c.f8.rms.is_nan());
+}
@@ -0,0 +1,119 @@
+use crate::f8::F8;
+
+#[test]
+fn display_is_exact() {
+  assert_eq!(F8::approx_from(0.25).to_string(), "0.25");
+  assert_eq!(F8::approx_from(-1.5).to_string(), "-1.5");
+  assert_eq!(F8::MAX.to_string(), "480");
+  assert_eq!(format!("{:.3}", F8::approx_from(0.75)), "0.750");
+  assert_eq!(format!("{:>6}", F8::approx_from(3.0)), "     3");
+  assert_eq!(format!("{:+}", F8::approx_from(2.0)), "+2");
+  for b in 0..=255u8 {
+    let f = F8::from_bits(b);
+    assert_eq!(f.to_string().parse::<f32>().unwrap(), f.v());
+  }
+}
+
+#[test]
+fn parse_rounds_to_nearest_even() {
+  let p = |s: &str| s.parse::<F8>().unwrap().v();
+  assert_eq!(p("1.5"), 1.5);
+  assert_eq!(p("-0.25"), -0.25);
+  assert_eq!(p("+3e1"), 30.0);
+  assert_eq!(p("31"), 32.0);
+  assert_eq!(p(".75"), 0.75);
+  assert_eq!(p("480"), 480.0);
+  assert_eq!(p("1e9"), 480.0);
+  assert_eq!(p("-inf"), -480.0);
+  assert_eq!(p("0.01"), 0.0);
+  // 0.125 is a tie between 0 and 0.25, anything above it rounds up
+  assert_eq!(p("0.125"), 0.0);
+  assert_eq!(p("0.12500000000000000001"), 0.25);
+  assert_eq!(p("0.375"), 0.5);
+  // ties between 464 = (448 + 480) / 2
+  assert_eq!(p("464"), 448.0);
+  assert_eq!(p("464.0000001"), 480.0);
+  // exponents at the limits of i64 saturate
+  assert_eq!(p("1e9223372036854775807"), 480.0);
+  assert_eq!(p("0.0001e-9223372036854775808"), 0.0);
+  for s in &["", "-", ".", "nan", "1.2.3", "1e", "abc", "--1", "+-inf"] {
+    assert!(s.parse::<F8>().is_err(), "{}", s);
+  }
+}
+
+#[test]
+fn parse_agrees_with_approx_from() {
+  for i in 0..4000 {
+    let v = i as f32 * 0.0625 - 100.0;
+    assert_eq!(
+      v.to_string().parse::<F8>().unwrap().v(),
+      F8::approx_from(v).v(),
+      "{}",
+      v
+    );
+  }
+}
+
+#[test]
+fn scientific() {
+  assert_eq!(format!("{:e}", F8::approx_from(1.5)), "1.5e0");
+  assert_eq!(format!("{:E}", F8::MAX), "4.8E2");
+  assert_eq!(format!("{:e}", F8::approx_from(-0.25)), "-2.5e-1");
+  assert_eq!(format!("{:.2e}", F8::approx_from(3.0)), "3.00e0");
+  assert_eq!(format!("{:e}", F8::from_bits(0)), "0e0");
+}
+
+#[test]
+fn bit_patterns() {
+  let f = F8::approx_from(-1.5);
+  assert_eq!(format!("{:08b}", f), format!("{:08b}", f.to_bits()));
+  assert_eq!(format!("{:#04x}", F8::MAX), "0x7f");
+  assert_eq!(format!("{:X}", F8::from_bits(0xAB)), "AB");
+  assert_eq!(
+    f.fmt_fields(),
+    format!("1|{:03b}|{:04b}", f.exponent(), f.significand())
+  );
+  assert_eq!(F8::MAX.fmt_fields(), "0|111|1111");
+  assert_eq!(F8::from_bits(0).fmt_fields(), "0|000|0000");
+}
+
+#[test]
+fn hex_float() {
+  let h = |s: &str| F8::from_hex_str(s).unwrap().v();
+  assert_eq!(h("0x1.8p-1"), 0.75);
+  assert_eq!(h("-0X1P+3"), -8.0);
+  assert_eq!(h("0x.4"), 0.25);
+  assert_eq!(h("0x1e0"), 480.0);
+  assert_eq!(h("0x1p100"), 480.0);
+  assert_eq!(h("0x1p-100"), 0.0);
+  // 1.125 is a tie between 1 and 1.25
+  assert_eq!(h("0x1.2p0"), 1.0);
+  assert_eq!(h("0x1.2000000000001p0"), 1.25);
+  for s in &["1.5", "0x", "0xp1", "0x1.g", "0x1p", "0x1p+"] {
+    assert!(F8::from_hex_str(s).is_err(), "{}", s);
+  }
+  assert_eq!(F8::approx_from(0.75).to_hex_string(), "0x1.8p-1");
+  assert_eq!(F8::approx_from(-8.0).to_hex_string(), "-0x1p+3");
+  assert_eq!(F8::MAX.to_hex_string(), "0x1.ep+8");
+  assert_eq!(F8::from_bits(0).to_hex_string(), "0x0p+0");
+  for b in 0..=255u8 {
+    let f = F8::from_bits(b);
+    assert_eq!(F8::from_hex_str(&f.to_hex_string()).unwrap().v(), f.v());
+  }
+}
+
+#[test]
+fn shortest_round_trip() {
+  assert_eq!(format!("{:#}", F8::approx_from(0.75)), "0.7");
+  assert_eq!(format!("{:#}", F8::approx_from(-104.0)), "-104");
+  assert_eq!(format!("{:>5}", format!("{:#}", F8::MAX)), "  480");
+  assert_eq!(format!("{:#>5}", F8::MAX), "##480");
+  for b in 0..=255u8 {
+    let f = F8::from_bits(b);
+    let s = f.to_shortest_string();
+    let back: F8 = s.parse().unwrap();
+    assert_eq!(back.v(), f.v(), "{}", s);
+    assert_eq!(back.is_sign_negative(), f.is_sign_negative());
+    assert!(s.len() <= f.to_string().len(), "{} {}", s, f);
+  }
+}
@@ -0,0 +1,71 @@
+use crate::{
+  gguf::{
+    f16_to_f32, f32_to_f16, read_q4_0, read_q8_0, write_q4_0, write_q8_0, BlockQ4_0, BlockQ8_0,
+  },
+  mx::{dequantize_blocks, quantize_blocks, MxBlock},
+};
+
+fn ramp() -> [f32; 32] {
+  let mut v = [0.0; 32];
+  for (i, v) in v.iter_mut().enumerate() {
+    *v = (i as f32 - 16.0) * 0.25;
+  }
+  v
+}
+
+#[test]
+fn f16_conversion() {
+  for &v in &[0.0f32, 1.0, -2.5, 65504.0, 6.1035156e-5, 5.9604645e-8] {
+    assert_eq!(f16_to_f32(f32_to_f16(v)), v);
+  }
+  assert_eq!(f32_to_f16(1e6), 0x7C00);
+  assert_eq!(f32_to_f16(1.0 + 2f32.powi(-11)), 0x3C00);
+  assert!(f16_to_f32(f32_to_f16(f32::NAN)).is_nan());
+  for h in 0..0x7C00u16 {
+    assert_eq!(f32_to_f16(f16_to_f32(h)), h);
+  }
+}
+
+#[test]
+fn q8_0_round_trip() {
+  let v = ramp();
+  let b = BlockQ8_0::quantize(&v);
+  let blocks = read_q8_0(&write_q8_0(&[b, b])).unwrap();
+  assert_eq!(blocks, vec![b, b]);
+  for (a, b) in v.iter().zip(b.dequantize().iter()) {
+    assert!((a - b).abs() < 0.02);
+  }
+  let mx = b.to_mx();
+  for (a, b) in v.iter().zip(mx.dequantize().iter()) {
+    assert!((a - b).abs() <= a.abs() / 8.0 + 0.01, "{} {}", a, b);
+  }
+  let back = BlockQ8_0::from_mx(&mx);
+  assert!((back.dequantize()[0] + 4.0).abs() < 0.02);
+  assert!(read_q8_0(&[0; 33]).is_err());
+}
+
+#[test]
+fn q4_0_round_trip() {
+  let v = ramp();
+  let b = BlockQ4_0::quantize(&v);
+  assert_eq!(read_q4_0(&write_q4_0(&[b])).unwrap(), vec![b]);
+  let d = b.dequantize();
+  assert_eq!(d[0], -4.0);
+  for (a, b) in v.iter().zip(d.iter()) {
+    assert!((a - b).abs() <= 0.25, "{} {}", a, b);
+  }
+  let mx = b.to_mx();
+  assert_eq!(BlockQ4_0::from_mx(&mx), b);
+}
+
+#[test]
+fn mx_blocks_pad() {
+  let v: Vec<f32> = (0..40).map(|i| i as f32).collect();
+  let blocks = quantize_blocks(&v);
+  assert_eq!(blocks.len(), 2);
+  let mut out = vec![0.0; 40];
+  dequantize_blocks(&blocks, &mut out);
+  assert!((out[39] - 39.0).abs() <= 39.0 / 16.0);
+  assert_eq!(out[1], 1.0);
+  assert_eq!(MxBlock::quantize(&[0.0; 32]).dequantize(), [0.0; 32]);
+}
@@ -0,0 +1,40 @@
+use crate::glam_io::{dequantize_vec3s, f8_to_vec3, quantize_vec3s, vec3_to_f8, ScaledF8Array};
+use glam::{Mat4, Quat, Vec3, Vec4};
+
+#[test]
+fn vectors_round_trip() {
+  let v = Vec3::new(0.1, -2.0, 30.0);
+  let q = ScaledF8Array::from(v);
+  assert!((Vec3::from(q) - v).abs().max_element() <= 30.0 / 16.0);
+  assert_eq!(Vec3::from(q).z, 30.0);
+  let w = Vec4::new(1.0, 2.0, -4.0, 0.5);
+  assert_eq!(Vec4::from(ScaledF8Array::from(w)), w);
+  assert_eq!(
+    f8_to_vec3(vec3_to_f8(Vec3::new(1.5, -3.0, 0.0))),
+    Vec3::new(1.5, -3.0, 0.0)
+  );
+}
+
+#[test]
+fn matrix_round_trip() {
+  let m = Mat4::from_scale_rotation_translation(
+    Vec3::splat(2.0),
+    Quat::from_rotation_y(0.3),
+    Vec3::new(10.0, 0.0, -5.0),
+  );
+  let back = Mat4::from(ScaledF8Array::<16>::from(m));
+  assert!(m.abs_diff_eq(back, 10.0 / 16.0), "{:?} {:?}", m, back);
+}
+
+#[test]
+fn vertex_buffer() {
+  let vs: Vec<Vec3> = (0..10)
+    .map(|i| Vec3::new(i as f32, -(i as f32), 0.5))
+    .collect();
+  let t = quantize_vec3s(&vs);
+  assert_eq!(t.data.len(), 30);
+  let back = dequantize_vec3s(&t);
+  for (a, b) in vs.iter().zip(&back) {
+    assert!((*a - *b).abs().max_element() <= 9.0 / 16.0, "{} {}", a, b);
+  }
+}
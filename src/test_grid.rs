@@ -0,0 +1,49 @@
+use crate::grid_report;
+
+#[test]
+fn buckets_cover_every_value() {
+  let r = grid_report(0.0, 1.0);
+  assert_eq!(r.buckets.len(), 8);
+  assert_eq!(r.buckets.iter().map(|b| b.count).sum::<usize>(), 72);
+  let b0 = r.buckets[0];
+  assert_eq!(
+    (b0.min, b0.max, b0.spacing, b0.count),
+    (0.0, 3.75, 0.25, 16)
+  );
+  let b7 = r.buckets[7];
+  assert_eq!(
+    (b7.min, b7.max, b7.spacing, b7.count),
+    (256.0, 480.0, 32.0, 8)
+  );
+}
+
+#[test]
+fn coverage_of_ranges() {
+  let c = grid_report(0.0, 1.0).coverage;
+  assert_eq!((c.values, c.clipped, c.max_gap), (5, 0.0, 0.25));
+  let c = grid_report(-1000.0, 1000.0).coverage;
+  assert_eq!(c.values, 143);
+  assert!((c.clipped - 0.52).abs() < 1e-6);
+  assert_eq!(c.max_gap, 32.0);
+  // a range between two values still has the gap around it
+  let c = grid_report(300.0, 310.0).coverage;
+  assert_eq!((c.values, c.max_gap), (0, 32.0));
+  assert_eq!(grid_report(500.0, 500.0).coverage.clipped, 1.0);
+}
+
+#[test]
+fn conversion_profile() {
+  let p = crate::conversion_error_profile();
+  // 0.125 ties to zero, and the last step is 32
+  assert_eq!(p.underflow, 0.125);
+  assert_eq!(p.overflow, f32::from_bits(496f32.to_bits() + 1));
+  let b = |e: i32| *p.binades.iter().find(|b| b.exponent == e).unwrap();
+  // below 2^-3 everything rounds to zero, and past 2^9 everything saturates
+  assert_eq!(b(-4).max_rel, 1.0);
+  assert!(b(10).mean_rel > 0.5);
+  // in the normal range 4 significant bits keep the relative error within 1/16
+  for e in 2..9 {
+    assert!(b(e).max_rel <= 1.0 / 16.0, "{:?}", b(e));
+    assert!(b(e).mean_rel < b(e).max_rel);
+  }
+}
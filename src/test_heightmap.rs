@@ -0,0 +1,43 @@
+use crate::{f8::F8, heightmap::HeightmapF8};
+
+/// Largest error of the mean over any run of 8 texels along a row
+fn worst_window_bias(src: &[f32], q: &[f32], width: usize) -> f32 {
+  let mut worst = 0f32;
+  for (s, q) in src.chunks(width).zip(q.chunks(width)) {
+    for x in 0..width - 8 {
+      let bias: f32 = (x..x + 8).map(|i| q[i] - s[i]).sum::<f32>() / 8.0;
+      worst = worst.max(bias.abs());
+    }
+  }
+  worst
+}
+
+#[test]
+fn diffusion_removes_terraces() {
+  let (w, h) = (64, 4);
+  // a gentle ramp on top of a sharp peak, so the ramp falls where F8 is coarse
+  let src: Vec<f32> = (0..w * h)
+    .map(|i| {
+      let x = (i % w) as f32;
+      100.0 + x * 0.05 + if i % w == 0 { -100.0 } else { 0.0 }
+    })
+    .collect();
+  let q = HeightmapF8::quantize(&src, w, h);
+  let back = q.dequantize();
+  // round every texel independently for comparison
+  let plain: Vec<f32> = src
+    .iter()
+    .map(|&v| q.offset + F8::approx_from((v - q.offset) / q.scale).v() * q.scale)
+    .collect();
+  let diffused = worst_window_bias(&src, &back, w);
+  let rounded = worst_window_bias(&src, &plain, w);
+  assert!(diffused < rounded / 2.0, "{} {}", diffused, rounded);
+  let weighted = HeightmapF8::quantize_slope_weighted(&src, w, h, 10.0);
+  assert_eq!(weighted.data.len(), src.len());
+}
+
+#[test]
+fn flat_heightmap() {
+  let q = HeightmapF8::quantize(&[5.0; 9], 3, 3);
+  assert_eq!(q.dequantize(), vec![5.0; 9]);
+}
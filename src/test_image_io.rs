@@ -0,0 +1,27 @@
+use crate::image_io::{F8Image, ImageScale};
+use image::{ImageBuffer, Luma, Rgb};
+
+#[test]
+fn rgb_per_channel_round_trip() {
+  let img = ImageBuffer::from_fn(4, 3, |x, y| Rgb([x as f32 * 100.0, y as f32 * 0.01, 1.0]));
+  let q = F8Image::from_rgb32f(&img, ImageScale::PerChannel);
+  assert_eq!(q.scales.len(), 3);
+  assert_eq!(q.data.len(), 36);
+  let back = q.to_rgb32f().unwrap();
+  for (a, b) in img.pixels().zip(back.pixels()) {
+    for c in 0..3 {
+      assert!((a[c] - b[c]).abs() <= a[c] / 16.0 + 1e-6, "{:?} {:?}", a, b);
+    }
+  }
+  assert!(q.to_luma32f().is_none());
+  let shared = F8Image::from_rgb32f(&img, ImageScale::PerImage);
+  assert_eq!(shared.scales.len(), 1);
+}
+
+#[test]
+fn luma_round_trip() {
+  let img: ImageBuffer<Luma<f32>, Vec<f32>> =
+    ImageBuffer::from_fn(2, 2, |x, y| Luma([(x + 2 * y) as f32]));
+  let back = F8Image::from_luma32f(&img).to_luma32f().unwrap();
+  assert_eq!(back.as_raw(), &vec![0.0, 1.0, 2.0, 3.0]);
+}
@@ -0,0 +1,43 @@
+use crate::{f8::F8, interval::F8Interval};
+
+fn iv(lo: f32, hi: f32) -> F8Interval {
+  F8Interval::new(F8::try_from(lo).unwrap(), F8::try_from(hi).unwrap())
+}
+
+#[test]
+fn rounds_outward() {
+  let a = F8Interval::enclosing(1.1).unwrap();
+  assert_eq!((a.lo.v(), a.hi.v()), (1.0, 1.25));
+  let a = F8Interval::enclosing(-1.1).unwrap();
+  assert_eq!((a.lo.v(), a.hi.v()), (-1.25, -1.0));
+  assert_eq!(F8Interval::enclosing(2.0), Some(iv(2.0, 2.0)));
+  assert!(F8Interval::enclosing(500.0).is_none());
+  assert!(F8Interval::enclosing(f32::NAN).is_none());
+  // 4.25 + 4.25 lies between 8 and 9
+  let s = iv(4.0, 4.5).checked_add(iv(4.0, 4.5)).unwrap();
+  assert_eq!((s.lo.v(), s.hi.v()), (8.0, 9.0));
+  let p = iv(-3.0, 2.0).checked_mul(iv(1.5, 2.5)).unwrap();
+  assert_eq!((p.lo.v(), p.hi.v()), (-7.5, 5.0));
+  assert!(iv(288.0, 480.0).checked_add(iv(256.0, 256.0)).is_none());
+  assert_eq!(iv(1.0, 2.0).checked_sub(iv(0.5, 0.75)), Some(iv(0.25, 1.5)));
+}
+
+#[test]
+fn encloses_every_exact_result() {
+  let vals: Vec<F8> = F8::values_ascending().step_by(5).collect();
+  for w in vals.windows(3) {
+    for v in vals.windows(2) {
+      let (a, b) = (iv(w[0].v(), w[2].v()), iv(v[0].v(), v[1].v()));
+      for x in [w[0], w[1], w[2]].iter().map(|f| f.v()) {
+        for y in [v[0], v[1]].iter().map(|f| f.v()) {
+          if let Some(s) = a.checked_add(b) {
+            assert!(s.contains(x + y));
+          }
+          if let Some(p) = a.checked_mul(b) {
+            assert!(p.contains(x * y));
+          }
+        }
+      }
+    }
+  }
+}
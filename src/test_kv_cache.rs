@@ -0,0 +1,45 @@
+use crate::kv_cache::KvCacheF8;
+
+#[test]
+fn append_and_read_back() {
+  let mut c = KvCacheF8::new(2, 2);
+  c.append(&[1.0, 2.0, -100.0, 50.0], &[0.5, 0.25, 3.0, -3.0]);
+  c.append(&[0.0, 1.0, 1.0, 0.0], &[1.0, 1.0, 1.0, 1.0]);
+  assert_eq!(c.len(), 2);
+  let mut k = [0.0; 2];
+  c.key(0, 1, &mut k);
+  assert!(
+    (k[0] + 100.0).abs() < 1e-3 && (k[1] - 50.0).abs() < 4.0,
+    "{:?}",
+    k
+  );
+  c.value(0, 0, &mut k);
+  assert!((k[0] - 0.5).abs() < 1e-5 && (k[1] - 0.25).abs() < 0.02);
+  c.truncate(1);
+  assert_eq!(c.len(), 1);
+}
+
+#[test]
+fn attention_matches_f32() {
+  let mut c = KvCacheF8::new(1, 2);
+  c.append(&[1.0, 0.0], &[1.0, 0.0]);
+  c.append(&[0.0, 1.0], &[0.0, 1.0]);
+  let mut s = [0.0; 2];
+  c.scores(0, &[2.0, 1.0], &mut s);
+  let r = 1.0 / 2f32.sqrt();
+  assert!((s[0] - 2.0 * r).abs() < 1e-5 && (s[1] - r).abs() < 1e-5);
+  let mut out = [0.0; 2];
+  c.attend(0, &[2.0, 1.0], &mut out);
+  let w0 = 1.0 / (1.0 + (-r).exp());
+  assert!((out[0] - w0).abs() < 1e-5 && (out[1] - (1.0 - w0)).abs() < 1e-5);
+}
+
+#[test]
+#[should_panic(expected = "Head 2 out of range")]
+fn rejects_heads_beyond_num_heads() {
+  let mut cache = KvCacheF8::new(2, 2);
+  cache.append(&[1.0; 4], &[1.0; 4]);
+  cache.append(&[2.0; 4], &[2.0; 4]);
+  // would otherwise read the first head of the next token
+  cache.key(0, 2, &mut [0.0; 2]);
+}
@@ -0,0 +1,173 @@
+use crate::{
+  f8::F8,
+  linalg::{
+    axpby, axpy, cumsum, dot, gemm, gemm_batched, gemv, norm_inf, norm_l1, norm_l2, pack_a,
+    pack_a_f32, pack_b, pack_b_f32, MR, NR,
+  },
+  test_f8::f8s,
+};
+
+#[test]
+fn dot_matches_f32() {
+  let a = f8s(&[1.0, 2.0, -0.5, 3.0, 0.25, 1.5, 4.0]);
+  let b = f8s(&[0.5, -1.0, 2.0, 1.0, 8.0, 2.0, 0.75]);
+  let expected: f32 = a.iter().zip(&b).map(|(x, y)| x.v() * y.v()).sum();
+  assert_eq!(dot(&a, &b), expected);
+}
+
+#[test]
+fn gemm_matches_naive() {
+  let (m, k, n) = (2, 3, 2);
+  let a = f8s(&[1.0, 2.0, 3.0, -1.0, 0.5, 0.0]);
+  let b = f8s(&[1.0, 0.0, 0.0, 1.0, 2.0, -2.0]);
+  let mut out = [0.0; 4];
+  gemm(&a, &b, m, k, n, &mut out);
+  assert_eq!(out, [7.0, -4.0, -1.0, 0.5]);
+}
+
+#[test]
+fn gemm_across_partial_panels() {
+  let (m, k, n) = (6, 5, 7);
+  let a: Vec<F8> = (0..m * k)
+    .map(|i| F8::from_bits((i * 37 % 256) as u8))
+    .collect();
+  let b: Vec<F8> = (0..k * n)
+    .map(|i| F8::from_bits((i * 53 % 256) as u8))
+    .collect();
+  let mut out = vec![0.0; m * n];
+  gemm(&a, &b, m, k, n, &mut out);
+  for i in 0..m {
+    for j in 0..n {
+      let e = (0..k).fold(0.0, |acc, kk| acc + a[i * k + kk].v() * b[kk * n + j].v());
+      assert_eq!(out[i * n + j], e);
+    }
+  }
+}
+
+#[test]
+fn fused_accumulate() {
+  let x = f8s(&[1.0, -2.0, 0.5]);
+  let mut y = [1.0, 1.0, 1.0];
+  axpy(2.0, &x, &mut y);
+  assert_eq!(y, [3.0, -3.0, 2.0]);
+  axpby(1.0, &x, -1.0, &mut y);
+  assert_eq!(y, [-2.0, 1.0, -1.5]);
+}
+
+#[test]
+fn gemv_matches_gemm() {
+  let (m, n) = (6, 7);
+  let a: Vec<F8> = (0..m * n)
+    .map(|i| F8::from_bits((i * 37 % 256) as u8))
+    .collect();
+  let x: Vec<F8> = (0..n).map(|i| F8::from_bits((i * 11 + 3) as u8)).collect();
+  let (mut out, mut expected) = ([0.0; 6], [0.0; 6]);
+  crate::gemv(&a, &x, m, n, &mut out);
+  gemm(&a, &x, m, n, 1, &mut expected);
+  for (o, e) in out.iter().zip(&expected) {
+    assert!((o - e).abs() <= 1e-3 * e.abs().max(1.0), "{} != {}", o, e);
+  }
+  let mut empty = [1.0; 3];
+  gemv(&[], &[], 3, 0, &mut empty);
+  assert_eq!(empty, [0.0; 3]);
+}
+
+#[test]
+fn batched_gemm_matches_gemm() {
+  let (batch, m, k, n) = (3, 2, 5, 6);
+  let a: Vec<F8> = (0..batch * m * k)
+    .map(|i| F8::from_bits((i * 29 % 256) as u8))
+    .collect();
+  let b: Vec<F8> = (0..k * n)
+    .map(|i| F8::from_bits((i * 53 % 256) as u8))
+    .collect();
+  let mut out = vec![0.0; batch * m * n];
+  gemm_batched(&a, &b, batch, m, k, n, &mut out);
+  for (a, out) in a.chunks_exact(m * k).zip(out.chunks_exact(m * n)) {
+    let mut expected = vec![0.0; m * n];
+    gemm(a, &b, m, k, n, &mut expected);
+    for (o, e) in out.iter().zip(&expected) {
+      assert!((o - e).abs() <= 1e-3 * e.abs().max(1.0), "{} != {}", o, e);
+    }
+  }
+}
+
+#[test]
+fn norms() {
+  let x = f8s(&[3.0, -4.0, 0.0]);
+  assert_eq!(norm_l1(&x), 7.0);
+  assert_eq!(norm_l2(&x), 5.0);
+  assert_eq!(norm_inf(&x), 4.0);
+  assert_eq!(norm_l2(&[]), 0.0);
+  // long enough that an f32 running sum would lose precision
+  let big = vec![F8::MAX; 1 << 20];
+  let expected = F8::MAX.v() * 1024.0;
+  assert!((norm_l2(&big) - expected).abs() <= expected * 1e-6);
+  assert_eq!(norm_l1(&big), F8::MAX.v() * (1 << 20) as f32);
+}
+
+#[test]
+fn cumsum_carries_wide() {
+  // in F8 alone 16 + 0.5 rounds back to 16, so the total would never grow
+  let x = f8s(
+    &[16.0; 1]
+      .iter()
+      .chain(&[0.5; 8])
+      .copied()
+      .collect::<Vec<_>>(),
+  );
+  let mut out = x.clone();
+  cumsum(&x, &mut out);
+  assert_eq!(out[8].v(), 20.0);
+  assert_eq!(out[1].v(), 16.0);
+  let naive = x[1..].iter().fold(x[0], |acc, &v| acc + v);
+  assert_eq!(naive.v(), 16.0);
+}
+
+#[test]
+fn packing_layout() {
+  // a 5 x 3 matrix inside rows of 4, so one column of each row is unused
+  let (m, k, lda) = (5, 3, 4);
+  let a: Vec<F8> = (0..m * lda).map(|i| F8::from_bits(i as u8 + 1)).collect();
+  let packed = pack_a(&a, lda, m, k);
+  assert_eq!(packed.len(), 2 * k * MR);
+  for i in 0..2 * MR {
+    for kk in 0..k {
+      let (p, r) = (i / MR, i % MR);
+      let expected = if i < m {
+        a[i * lda + kk]
+      } else {
+        F8::from_bits(0)
+      };
+      assert_eq!(packed[(p * k + kk) * MR + r], expected);
+    }
+  }
+  let decoded: Vec<f32> = packed.iter().map(|f| f.v()).collect();
+  assert_eq!(pack_a_f32(&a, lda, m, k), decoded);
+
+  let (n, ldb) = (3, 4);
+  let b = &a[..k * ldb];
+  let packed = pack_b(b, ldb, k, n);
+  assert_eq!(packed.len(), k * NR);
+  for kk in 0..k {
+    for j in 0..NR {
+      let expected = if j < n {
+        b[kk * ldb + j]
+      } else {
+        F8::from_bits(0)
+      };
+      assert_eq!(packed[kk * NR + j], expected);
+    }
+  }
+  let decoded: Vec<f32> = packed.iter().map(|f| f.v()).collect();
+  assert_eq!(pack_b_f32(b, ldb, k, n), decoded);
+  assert!(pack_b(&[], 0, 0, 0).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Leading dimension is less than the row length")]
+fn packing_rejects_overlapping_rows() { pack_a(&[F8::from_bits(0); 6], 2, 2, 3); }
+
+#[test]
+#[should_panic(expected = "Matrix does not fit in the slice")]
+fn packing_rejects_short_slices() { pack_b(&[F8::from_bits(0); 6], 4, 2, 3); }
@@ -0,0 +1,17 @@
+use crate::{f8, literal::exact};
+
+#[test]
+fn checked_literals() {
+  assert_eq!(f8!(1.5).v(), 1.5);
+  assert_eq!(f8!(-480).v(), -480.0);
+  assert_eq!(f8!(0.25 * 3.0).v(), 0.75);
+  assert_eq!(f8!(approx 1.1).v(), 1.0);
+  assert_eq!(f8!(approx 1e6).v(), 480.0);
+  assert!(exact(0.1).is_none());
+  assert!(exact(1.25 + 1e-12).is_none());
+  assert!(exact(f64::NAN).is_none());
+  for b in 0..=255u8 {
+    let v = crate::f8::F8::from_bits(b).v();
+    assert_eq!(exact(v as f64).unwrap().v(), v);
+  }
+}
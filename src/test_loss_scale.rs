@@ -0,0 +1,30 @@
+use crate::{f8::F8, loss_scale::LossScaler};
+
+#[test]
+fn backs_off_on_overflow() {
+  let mut s = LossScaler::new(1024.0);
+  let mut out = [F8::from_bits(0); 3];
+  assert_eq!(s.quantize(&[0.1, 1.0, -2.0], &mut out), 2);
+  assert!(s.overflowed());
+  assert!(s.update());
+  assert_eq!(s.scale(), 512.0);
+  assert_eq!(s.total_overflows(), 2);
+  assert!(!s.overflowed());
+}
+
+#[test]
+fn grows_after_clean_steps() {
+  let mut s = LossScaler::new(1.0);
+  s.growth_interval = 3;
+  let mut out = [F8::from_bits(0); 2];
+  for _ in 0..3 {
+    assert_eq!(s.quantize(&[0.5, -1.0], &mut out), 0);
+    assert!(!s.update());
+  }
+  assert_eq!(s.scale(), 2.0);
+  let mut back = [0.0; 2];
+  s.quantize(&[0.5, -1.0], &mut out);
+  s.unscale_into(&out, &mut back);
+  assert_eq!(back, [0.5, -1.0]);
+  assert_eq!(s.quantize(&[f32::NAN], &mut out[..1]), 1);
+}
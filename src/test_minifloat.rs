@@ -0,0 +1,16 @@
+use crate::{
+  check_properties,
+  f8::F8,
+  ofp8::{E4M3, E5M2},
+};
+
+#[test]
+fn ocp_formats_have_every_property() {
+  assert_eq!(check_properties::<E4M3>(), vec![]);
+  assert_eq!(check_properties::<E5M2>(), vec![]);
+}
+
+#[test]
+fn f8_has_every_property() {
+  assert_eq!(check_properties::<F8>(), vec![]);
+}
@@ -0,0 +1,22 @@
+use crate::{
+  f8::F8,
+  nalgebra_io::{from_f32_matrix, to_f32_matrix},
+};
+use nalgebra::{DMatrix, Matrix2};
+use num_traits::Zero;
+
+#[test]
+fn matrices_of_f8() {
+  let m = Matrix2::new(1.0f32, -2.0, 0.5, 6.0);
+  let q = from_f32_matrix(&m);
+  assert_eq!(q.cast::<f32>(), m);
+  assert_eq!(to_f32_matrix(&q), m);
+  assert_eq!(to_f32_matrix(&(-q)), -m);
+  let z = DMatrix::<F8>::zeros(2, 3);
+  assert!(z.iter().all(|f| f.is_zero()));
+  assert_eq!(
+    nalgebra::try_convert::<_, Matrix2<F8>>(m.add_scalar(0.1)),
+    None
+  );
+  assert_eq!(nalgebra::try_convert::<_, Matrix2<F8>>(m), Some(q));
+}
@@ -0,0 +1,35 @@
+use crate::{
+  f8::F8,
+  ndarray_io::{map_f8, map_f8_inplace, to_f32, to_f8, QuantizedArray, Scaling},
+};
+use ndarray::{array, ArrayD, Axis};
+
+#[test]
+fn per_axis_scales() {
+  let a = array![[1.0f32, 2.0, 4.0], [100.0, -200.0, 400.0]].into_dyn();
+  let rows = QuantizedArray::quantize(a.view(), Scaling::PerAxis(0));
+  assert_eq!(rows.scales.len(), 2);
+  assert_eq!(rows.dequantize(), a);
+  let cols = QuantizedArray::quantize(a.view(), Scaling::PerAxis(1));
+  assert_eq!(cols.scales.len(), 3);
+  let whole = QuantizedArray::quantize(a.view(), Scaling::PerTensor);
+  let err = |q: &QuantizedArray| {
+    (&q.dequantize() - &a)
+      .mapv(f32::abs)
+      .index_axis_move(Axis(0), 0)
+      .sum()
+  };
+  assert!(err(&rows) < err(&whole), "{} {}", err(&rows), err(&whole));
+}
+
+#[test]
+fn mapping() {
+  let a: ArrayD<f32> = array![[0.5, 1.0], [2.0, -3.0]].into_dyn();
+  let q = to_f8(a.view());
+  assert_eq!(to_f32(q.view()), a);
+  let doubled = map_f8(q.view(), |v| v * 2.0);
+  assert_eq!(to_f32(doubled.view()), &a * 2.0);
+  let mut m = q.clone();
+  map_f8_inplace(&mut m, f32::abs);
+  assert_eq!(m[[1, 1]], F8::approx_from(3.0));
+}
@@ -0,0 +1,35 @@
+use crate::{
+  norm::{layer_norm, rms_norm, rms_norm_weighted},
+  test_f8::f8s,
+};
+
+#[test]
+fn rms_norm_of_large_values() {
+  // the sum of squares here is far beyond F8::MAX
+  let x = f8s(&[256.0, -256.0, 256.0, -256.0]);
+  let mut out = x.clone();
+  rms_norm(&x, 1e-6, &mut out);
+  assert_eq!(out, f8s(&[1.0, -1.0, 1.0, -1.0]));
+  let w = f8s(&[2.0, 0.5, 0.0, 1.0]);
+  rms_norm_weighted(&x, &w, 1e-6, &mut out);
+  assert_eq!(out, f8s(&[2.0, -0.5, 0.0, -1.0]));
+}
+
+#[test]
+fn layer_norm_centers() {
+  let x = f8s(&[100.0, 104.0, 100.0, 104.0]);
+  let mut out = x.clone();
+  layer_norm(&x, None, None, 0.0, &mut out);
+  assert_eq!(out, f8s(&[-1.0, 1.0, -1.0, 1.0]));
+  let b = f8s(&[1.0; 4]);
+  layer_norm(&x, None, Some(&b), 0.0, &mut out);
+  assert_eq!(out, f8s(&[0.0, 2.0, 0.0, 2.0]));
+}
+
+#[test]
+#[should_panic(expected = "Mismatched parameter length")]
+fn rms_norm_rejects_long_weights() {
+  let x = f8s(&[1.0, 2.0]);
+  let mut out = x.clone();
+  rms_norm_weighted(&x, &f8s(&[1.0; 3]), 1e-6, &mut out);
+}
@@ -0,0 +1,28 @@
+use crate::normal::{max_angular_error, PackedNormal};
+
+#[test]
+fn axes_are_exact() {
+  for v in [
+    [1.0, 0.0, 0.0],
+    [0.0, -1.0, 0.0],
+    [0.0, 0.0, 1.0],
+    [0.0, 0.0, -1.0],
+  ] {
+    let p = PackedNormal::encode(v);
+    let d = p.decode();
+    for c in 0..3 {
+      assert!((d[c] - v[c]).abs() < 1e-6, "{:?} {:?}", v, d);
+    }
+    assert_eq!(PackedNormal::from_bits(p.to_bits()), p);
+  }
+  assert_eq!(PackedNormal::encode([0.0; 3]).decode(), [0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn bounded_angular_error() {
+  let max = max_angular_error(2000);
+  assert!(max > 0.0 && max < 0.1, "{}", max);
+  let v = [0.3, -0.5, -0.8];
+  let p = PackedNormal::encode(v);
+  assert!(p.angular_error(v) < 0.1);
+}
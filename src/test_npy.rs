@@ -0,0 +1,56 @@
+use crate::{
+  f8::F8,
+  npy::{read, read_f8, write, write_f8},
+};
+
+#[test]
+fn round_trip() {
+  let data: Vec<F8> = (0..12).map(F8::from_bits).collect();
+  for shape in &[vec![12], vec![3, 4], vec![2, 3, 2]] {
+    let mut buf = vec![];
+    write_f8(&mut buf, shape, &data).unwrap();
+    assert_eq!((buf.len() - 12) % 64, 0);
+    let (s, d) = read_f8(&buf).unwrap();
+    assert_eq!(&s, shape);
+    assert_eq!(d, data);
+  }
+}
+
+#[test]
+fn reads_numpy_output() {
+  // as written by `np.save(f, np.array([[1, 2], [3, 4]], dtype=np.uint8))`
+  let mut file = b"\x93NUMPY\x01\x00v\x00".to_vec();
+  let mut header = "{'descr': '|u1', 'fortran_order': False, 'shape': (2, 2), }".to_string();
+  while header.len() < 117 {
+    header.push(' ');
+  }
+  header.push('\n');
+  file.extend_from_slice(header.as_bytes());
+  file.extend_from_slice(&[1, 2, 3, 4]);
+  let (shape, bits) = read(&file).unwrap();
+  assert_eq!(shape, vec![2, 2]);
+  assert_eq!(bits, &[1, 2, 3, 4]);
+}
+
+#[test]
+fn rejects_wide_dtypes() {
+  let mut buf = vec![];
+  write(&mut buf, &[1], &[0]).unwrap();
+  let s = String::from_utf8_lossy(&buf).replace("|u1", "<f4");
+  assert!(read(s.as_bytes()).is_err());
+  assert!(write(&mut buf, &[2], &[0]).is_err());
+}
+
+#[test]
+fn rejects_overflowing_shapes() {
+  let mut file = b"\x93NUMPY\x01\x00v\x00".to_vec();
+  let mut header =
+    "{'descr': '|u1', 'fortran_order': False, 'shape': (4294967296, 4294967296), }".to_string();
+  while header.len() < 117 {
+    header.push(' ');
+  }
+  header.push('\n');
+  file.extend_from_slice(header.as_bytes());
+  let err = read(&file).unwrap_err();
+  assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,31 @@
+use crate::number_line::{ascii, points, svg, Scale};
+
+#[test]
+fn positions() {
+  let lin = points(Scale::Linear);
+  assert_eq!(lin.len(), 143);
+  assert_eq!(
+    (lin[0].position, lin[71].position, lin[142].position),
+    (0.0, 0.5, 1.0)
+  );
+  let log = points(Scale::Log);
+  assert_eq!(log.len(), 71);
+  assert_eq!((log[0].value.v(), log[0].position), (0.25, 0.0));
+  assert_eq!(log[70].position, 1.0);
+  // powers of two are evenly spaced on the log axis
+  let p = |v: f32| log.iter().find(|p| p.value.v() == v).unwrap().position;
+  assert!(((p(4.0) - p(1.0)) - (p(64.0) - p(16.0))).abs() < 1e-6);
+  assert!(lin.windows(2).all(|w| w[0].position < w[1].position));
+}
+
+#[test]
+fn renders() {
+  let a = ascii(&points(Scale::Linear), 20);
+  let lines: Vec<&str> = a.lines().collect();
+  assert_eq!(lines[0].len(), 20);
+  assert!(lines[0].starts_with('|') && lines[0].ends_with('|'));
+  assert_eq!(lines[1], "-480             480");
+  let s = svg(&points(Scale::Log), 400, 40);
+  assert!(s.starts_with("<svg"));
+  assert_eq!(s.matches("<title>").count(), 71);
+}
@@ -0,0 +1,47 @@
+use crate::ofp8::{E4M3, E5M2};
+
+#[test]
+fn e4m3_values() {
+  assert_eq!(E4M3::MAX.to_f32(), 448.0);
+  assert_eq!(E4M3(0x01).to_f32(), 2f32.powi(-9));
+  assert_eq!(E4M3(0x38).to_f32(), 1.0);
+  assert_eq!(E4M3(0xB8).to_f32(), -1.0);
+  assert!(E4M3::NAN.to_f32().is_nan());
+  assert_eq!(E4M3::from_f32(1e6), E4M3::MAX);
+  assert_eq!(E4M3::from_f32(f32::NEG_INFINITY).to_f32(), -448.0);
+  assert!(E4M3::from_f32(f32::NAN).is_nan());
+}
+
+#[test]
+fn e5m2_values() {
+  assert_eq!(E5M2::MAX.to_f32(), 57344.0);
+  assert_eq!(E5M2(0x01).to_f32(), 2f32.powi(-16));
+  assert_eq!(E5M2(0x3C).to_f32(), 1.0);
+  assert_eq!(E5M2::INFINITY.to_f32(), f32::INFINITY);
+  assert_eq!(E5M2::from_f32(f32::INFINITY), E5M2::INFINITY);
+  assert_eq!(E5M2::from_f32(1e6), E5M2::MAX);
+  assert!(E5M2(0xFF).to_f32().is_nan());
+}
+
+#[test]
+fn round_trips_and_rounds_to_nearest() {
+  for bits in 0..=255u8 {
+    let e = E4M3(bits);
+    if !e.is_nan() {
+      assert_eq!(E4M3::from_f32(e.to_f32()), e);
+    }
+    let e = E5M2(bits);
+    if !e.is_nan() {
+      assert_eq!(E5M2::from_f32(e.to_f32()), e);
+    }
+  }
+  // halfway between 1.0 and 1.125 rounds to even
+  assert_eq!(E4M3::from_f32(1.0625).to_f32(), 1.0);
+  assert_eq!(E4M3::from_f32(1.0626).to_f32(), 1.125);
+  assert_eq!(E4M3::from_f32(1.1875).to_f32(), 1.25);
+  // halfway between the largest subnormal and smallest normal
+  assert_eq!(
+    E4M3::from_f32(2f32.powi(-6) * 0.9375).to_f32(),
+    2f32.powi(-6)
+  );
+}
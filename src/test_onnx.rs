@@ -0,0 +1,64 @@
+use crate::{
+  ofp8::{E4M3, E5M2},
+  onnx::{decode_tensor, encode_tensor, OnnxFloat8},
+};
+
+const ALL: [OnnxFloat8; 4] = [
+  OnnxFloat8::E4M3FN,
+  OnnxFloat8::E4M3FNUZ,
+  OnnxFloat8::E5M2,
+  OnnxFloat8::E5M2FNUZ,
+];
+
+#[test]
+fn max_values() {
+  assert_eq!(OnnxFloat8::E4M3FN.max(), 448.0);
+  assert_eq!(OnnxFloat8::E4M3FNUZ.max(), 240.0);
+  assert_eq!(OnnxFloat8::E5M2.max(), 57344.0);
+  assert_eq!(OnnxFloat8::E5M2FNUZ.max(), 57344.0);
+  for &t in &ALL {
+    assert_eq!(OnnxFloat8::from_data_type(t.data_type()), Some(t));
+  }
+}
+
+#[test]
+fn matches_ocp_types() {
+  for bits in 0..=255u8 {
+    let v = OnnxFloat8::E4M3FN.decode(bits);
+    assert_eq!(v.to_bits(), E4M3(bits).to_f32().to_bits());
+    let v = OnnxFloat8::E5M2.decode(bits);
+    assert!(v.is_nan() && E5M2(bits).is_nan() || v == E5M2(bits).to_f32());
+  }
+}
+
+#[test]
+fn round_trips_every_value() {
+  for &t in &ALL {
+    for bits in 0..=255u8 {
+      if t.is_nan(bits) {
+        continue;
+      }
+      assert_eq!(t.encode(t.decode(bits), false), bits, "{:?} {:x}", t, bits);
+    }
+  }
+}
+
+#[test]
+fn specials() {
+  let uz = OnnxFloat8::E4M3FNUZ;
+  assert_eq!(uz.encode(-0.0, true), 0);
+  assert_eq!(uz.encode(1e9, true), 0x7F);
+  assert_eq!(uz.encode(1e9, false), 0x80);
+  assert!(uz.decode(0x80).is_nan());
+  assert_eq!(OnnxFloat8::E4M3FN.encode(f32::INFINITY, true), 0x7E);
+  assert_eq!(OnnxFloat8::E4M3FN.encode(-1e9, false), 0xFF);
+  assert_eq!(OnnxFloat8::E5M2.encode(f32::INFINITY, true), 0x7C);
+  assert_eq!(OnnxFloat8::E5M2.encode(-1e9, false), 0xFC);
+  assert_eq!(OnnxFloat8::E5M2.encode(-1e9, true), 0xFB);
+  assert_eq!(OnnxFloat8::E5M2FNUZ.encode(f32::NEG_INFINITY, true), 0xFF);
+  let mut raw = [0; 3];
+  encode_tensor(OnnxFloat8::E4M3FN, &[1.0, -2.0, 0.5], true, &mut raw);
+  let mut out = [0.0; 3];
+  decode_tensor(OnnxFloat8::E4M3FN, &raw, &mut out);
+  assert_eq!(out, [1.0, -2.0, 0.5]);
+}
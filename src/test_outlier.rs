@@ -0,0 +1,47 @@
+use crate::{outlier::OutlierF8, scaled::ScaledF8Tensor};
+
+fn data() -> Vec<f32> {
+  let mut v: Vec<f32> = (0..64).map(|i| ((i as f32) * 0.3).cos()).collect();
+  v[10] = 500.0;
+  v[40] = -900.0;
+  v
+}
+
+fn max_err(a: &[f32], b: &[f32]) -> f32 {
+  a.iter()
+    .zip(b)
+    .map(|(a, b)| (a - b).abs())
+    .fold(0.0, f32::max)
+}
+
+#[test]
+fn outliers_reduce_error() {
+  let v = data();
+  let o = OutlierF8::quantize(&v, 10.0);
+  assert_eq!(o.outliers, vec![(10, 500.0), (40, -900.0)]);
+  let back = o.dequantize();
+  assert_eq!(back[40], -900.0);
+  assert_eq!(o.get(10), 500.0);
+  assert_eq!(o.get(3), back[3]);
+  let plain = ScaledF8Tensor::quantize(&v).dequantize();
+  assert!(max_err(&v, &back) * 10.0 < max_err(&v, &plain));
+  assert_eq!(OutlierF8::quantize_top_k(&v, 2), o);
+}
+
+#[test]
+fn top_k_with_ties() {
+  let v = [1.0, -4.0, 4.0, 0.5, 4.0];
+  let o = OutlierF8::quantize_top_k(&v, 2);
+  assert_eq!(o.outliers, vec![(1, -4.0), (2, 4.0)]);
+  assert_eq!(OutlierF8::quantize_top_k(&v, 9).outliers.len(), 5);
+  assert!(OutlierF8::quantize_top_k(&v, 0).outliers.is_empty());
+}
+
+#[test]
+fn dot_includes_outliers() {
+  let v = data();
+  let o = OutlierF8::quantize_top_k(&v, 2);
+  let x: Vec<f32> = (0..64).map(|i| i as f32 * 0.01).collect();
+  let expected: f32 = o.dequantize().iter().zip(&x).map(|(a, b)| a * b).sum();
+  assert!((o.dot(&x) - expected).abs() < 1e-3);
+}
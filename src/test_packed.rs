@@ -0,0 +1,58 @@
+use crate::{
+  f8::F8,
+  packed::{pack, unpack, F8x4},
+};
+
+#[test]
+fn lanes_round_trip() {
+  let lanes = [
+    F8::from_bits(0x12),
+    F8::from_bits(0x9A),
+    F8::from_bits(0x00),
+    F8::from_bits(0x8F),
+  ];
+  let p = F8x4::new(lanes);
+  assert_eq!(p.to_array(), lanes);
+  for (i, &l) in lanes.iter().enumerate() {
+    assert_eq!(p.extract(i), l);
+  }
+  let q = p.insert(2, F8::from_bits(0x77));
+  assert_eq!(q.extract(2), F8::from_bits(0x77));
+  assert_eq!(q.extract(3), lanes[3]);
+  assert_eq!(p.sign_mask(), 0b1010);
+  assert_eq!(p.zero_mask(), 0b0100);
+  assert_eq!((-p).sign_mask(), 0b0101);
+  assert_eq!(p.abs().sign_mask(), 0);
+  assert_eq!(
+    p.to_f32s(),
+    [lanes[0].v(), lanes[1].v(), lanes[2].v(), lanes[3].v()]
+  );
+  assert_eq!(F8x4::splat(lanes[1]).to_array(), [lanes[1]; 4]);
+}
+
+#[test]
+fn masks_match_per_lane() {
+  for bits in 0..=255u8 {
+    let p = F8x4::new([
+      F8::from_bits(bits),
+      F8::from_bits(0x81),
+      F8::from_bits(bits),
+      F8::from_bits(0x10),
+    ]);
+    let f = F8::from_bits(bits);
+    let z = (f.significand() == 0) as u8;
+    let s = f.is_sign_negative() as u8;
+    assert_eq!(p.zero_mask(), z | z << 2 | 1 << 3);
+    assert_eq!(p.sign_mask(), s | 1 << 1 | s << 2);
+  }
+}
+
+#[test]
+fn pack_pads_with_zero() {
+  let v: Vec<F8> = (1..=6).map(F8::from_bits).collect();
+  let packed = pack(&v);
+  assert_eq!(packed.len(), 2);
+  let unpacked = unpack(&packed);
+  assert_eq!(&unpacked[..6], &v[..]);
+  assert_eq!(unpacked[6..], [F8::from_bits(0); 2]);
+}
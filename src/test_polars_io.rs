@@ -0,0 +1,33 @@
+use crate::polars_io::{dequantize_expr, quantize_expr, F8Series};
+use polars::prelude::*;
+
+#[test]
+fn pack_and_unpack() {
+  let p = F8Series::pack("t", &[1.0, -2.0, 4.0]);
+  assert_eq!(p.bits.dtype(), &DataType::UInt8);
+  let back = p.unpack().unwrap();
+  let back: Vec<Option<f32>> = back.f32().unwrap().into_iter().collect();
+  assert_eq!(back, vec![Some(1.0), Some(-2.0), Some(4.0)]);
+  let with_null = Series::new("n".into(), &[Some(1.5f32), None]);
+  let q = F8Series::quantize(&with_null, 1.0).unwrap();
+  assert_eq!(q.to_f8s().unwrap()[1], None);
+  assert_eq!(q.unpack().unwrap(), with_null);
+}
+
+#[test]
+fn lazy_expressions() {
+  let df = df!("x" => &[0.5f32, 3.0, -8.0]).unwrap();
+  let out = df
+    .lazy()
+    .select([dequantize_expr(quantize_expr(col("x"), 0.5), 0.5).alias("y")])
+    .collect()
+    .unwrap();
+  let y: Vec<Option<f32>> = out
+    .column("y")
+    .unwrap()
+    .f32()
+    .unwrap()
+    .into_iter()
+    .collect();
+  assert_eq!(y, vec![Some(0.5), Some(3.0), Some(-8.0)]);
+}
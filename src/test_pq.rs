@@ -0,0 +1,66 @@
+use crate::{pq::ProductQuantizer, quantize::uniform};
+
+/// Points scattered around 4 clusters in each half of 8 dimensions, a quarter of the rows
+/// in each
+fn clustered(n: usize) -> Vec<f32> {
+  (0..n * 8)
+    .map(|i| {
+      let (r, d) = (i / 8, i % 8);
+      let center = ((4 * r / n + d / 4) % 4) as f32 * 10.0 - 15.0;
+      center + uniform(5, i as u64) - 0.5
+    })
+    .collect()
+}
+
+#[test]
+fn encodes_clusters() {
+  let data = clustered(200);
+  let pq = ProductQuantizer::train(&data, 8, 2, 4, 10);
+  assert_eq!(pq.centroids.len(), 2 * 4 * 4);
+  let (mut codes, mut out) = ([0u8; 2], [0f32; 8]);
+  let mut max_err = 0f32;
+  for v in data.chunks_exact(8) {
+    pq.encode(v, &mut codes);
+    pq.decode(&codes, &mut out);
+    for (a, b) in v.iter().zip(&out) {
+      max_err = max_err.max((a - b).abs());
+    }
+  }
+  // noise is at most 0.5, leaving the rest for rounding the centroids to F8
+  assert!(max_err < 1.5, "{}", max_err);
+}
+
+#[test]
+fn asymmetric_distance_matches_decoded() {
+  let data = clustered(100);
+  let pq = ProductQuantizer::train(&data, 8, 4, 8, 5);
+  let query = &data[8..16];
+  let table = pq.distance_table(query);
+  let (mut codes, mut out) = ([0u8; 4], [0f32; 8]);
+  for v in data.chunks_exact(8) {
+    pq.encode(v, &mut codes);
+    pq.decode(&codes, &mut out);
+    let expected: f32 = query.iter().zip(&out).map(|(a, b)| (a - b) * (a - b)).sum();
+    assert!((table.distance(&codes) - expected).abs() <= 1e-4 * expected.max(1.0));
+  }
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn rejects_codes_beyond_k() {
+  let pq = ProductQuantizer::train(&clustered(40), 8, 2, 4, 2);
+  // code 4 of the first subspace would read the second subspace's first centroid
+  pq.decode(&[4, 0], &mut [0f32; 8]);
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn distance_rejects_codes_beyond_k() {
+  let data = clustered(40);
+  let pq = ProductQuantizer::train(&data, 8, 2, 4, 2);
+  pq.distance_table(&data[..8]).distance(&[4, 0]);
+}
+
+#[test]
+#[should_panic(expected = "dim is zero")]
+fn rejects_zero_dim() { ProductQuantizer::train(&[], 0, 1, 1, 1); }
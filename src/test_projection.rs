@@ -0,0 +1,58 @@
+use crate::{
+  f8::F8,
+  projection::{ProjectionKind, RandomProjection},
+  quantize::uniform,
+};
+
+/// The mean ratio of projected to original squared lengths over random vectors
+fn mean_length_ratio(p: &RandomProjection) -> f32 {
+  let trials = 200;
+  let mut out = vec![0.0; p.rows];
+  let mut total = 0.0;
+  for t in 0..trials {
+    let x: Vec<f32> = (0..p.cols).map(|i| uniform(t, i as u64) - 0.5).collect();
+    p.project(&x, &mut out);
+    total += out.iter().map(|v| v * v).sum::<f32>() / x.iter().map(|v| v * v).sum::<f32>();
+  }
+  total / trials as f32
+}
+
+#[test]
+fn preserves_lengths() {
+  let kinds = [
+    ProjectionKind::Sign,
+    ProjectionKind::Sparse { density: 1.0 / 3.0 },
+    ProjectionKind::Gaussian,
+  ];
+  for &kind in &kinds {
+    let p = RandomProjection::new(kind, 64, 256, 7);
+    let r = mean_length_ratio(&p);
+    assert!((r - 1.0).abs() < 0.1, "{:?}: {}", kind, r);
+    assert_eq!(p, RandomProjection::new(kind, 64, 256, 7));
+  }
+  let sparse = RandomProjection::new(ProjectionKind::Sparse { density: 0.1 }, 64, 256, 1);
+  let nonzero = sparse.weights.iter().filter(|w| w.v() != 0.0).count();
+  assert!((nonzero as f32 / sparse.weights.len() as f32 - 0.1).abs() < 0.02);
+}
+
+#[test]
+fn f8_input_matches() {
+  let p = RandomProjection::new(ProjectionKind::Gaussian, 16, 40, 3);
+  let x: Vec<F8> = (0..40).map(|i| F8::from_bits(i * 5)).collect();
+  let xf: Vec<f32> = x.iter().map(|f| f.v()).collect();
+  let (mut a, mut b) = (vec![0.0; 16], vec![0.0; 16]);
+  p.project(&xf, &mut a);
+  p.project_f8(&x, &mut b);
+  for (a, b) in a.iter().zip(&b) {
+    assert!((a - b).abs() <= 1e-3 * a.abs().max(1.0), "{} != {}", a, b);
+  }
+}
+
+#[test]
+fn empty_input_projects_to_zero() {
+  let p = RandomProjection::new(ProjectionKind::Sign, 3, 0, 1);
+  let (mut a, mut b) = ([1.0; 3], [1.0; 3]);
+  p.project(&[], &mut a);
+  p.project_f8(&[], &mut b);
+  assert_eq!((a, b), ([0.0; 3], [0.0; 3]));
+}
@@ -0,0 +1,24 @@
+use crate::{
+  f8::F8,
+  proptest_io::{bounded, finite, normal},
+};
+use proptest::prelude::*;
+
+proptest! {
+  #[test]
+  fn round_trips_through_f32(f in any::<F8>()) {
+    prop_assert_eq!(F8::approx_from(f.v()).v(), f.v());
+  }
+
+  #[test]
+  fn normal_has_top_significand_bit(f in normal()) {
+    prop_assert!(f.significand() >= 8);
+    prop_assert!(f.v() != 0.0);
+  }
+
+  #[test]
+  fn finite_and_bounded(f in finite(), g in bounded(4.0)) {
+    prop_assert!(f.v().is_finite());
+    prop_assert!(g.v().abs() <= 4.0);
+  }
+}
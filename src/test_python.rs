@@ -0,0 +1,49 @@
+use crate::{
+  f8::F8,
+  python::{dequantize_array, quantization_error_of, quantize_array, quantize_scaled_array},
+};
+use numpy::ndarray::{ArrayD, IxDyn};
+
+#[test]
+fn bits_round_trip() {
+  let bits = ArrayD::from_shape_vec(IxDyn(&[16, 16]), (0..=255).collect()).unwrap();
+  let values = dequantize_array(bits.view());
+  assert_eq!(values.shape(), &[16, 16]);
+  for (&b, &v) in bits.iter().zip(&values) {
+    assert_eq!(v, F8::from_bits(b).v());
+  }
+  // every value maps back to an encoding of itself
+  let back = dequantize_array(quantize_array(values.view()).view());
+  assert_eq!(back, values);
+}
+
+#[test]
+fn scaled_round_trip() {
+  let a =
+    ArrayD::from_shape_vec(IxDyn(&[2, 3]), vec![0.5f32, -96.0, 3.0, 0.0, 12.0, -1.5]).unwrap();
+  let (scale, bits) = quantize_scaled_array(a.view());
+  assert_eq!(scale, 0.2);
+  assert_eq!(bits.shape(), &[2, 3]);
+  let back = dequantize_array(bits.view()).mapv(|v| v * scale);
+  let (max, rms) = quantization_error_of(a.view(), scale);
+  let worst = a
+    .iter()
+    .zip(&back)
+    .map(|(x, y)| (x - y).abs())
+    .fold(0.0, f32::max);
+  assert_eq!(max, worst);
+  assert!(rms <= max && max <= 0.5 * 16.0 * scale);
+  // unscaled, against the errors of rounding each element by hand
+  let b = a.mapv(|v| v * 1.1 + 0.01);
+  let errs: Vec<f32> = b
+    .iter()
+    .map(|&v| (v - F8::approx_from(v).v()).abs())
+    .collect();
+  let sq: f64 = errs.iter().map(|&e| (e as f64).powi(2)).sum();
+  let expected = (
+    errs.iter().copied().fold(0.0, f32::max),
+    (sq / errs.len() as f64).sqrt() as f32,
+  );
+  assert!(expected.0 > 0.0);
+  assert_eq!(quantization_error_of(b.view(), 1.0), expected);
+}
@@ -0,0 +1,101 @@
+use crate::{
+  f8::F8,
+  quantize::{
+    quantize_dithered_2d, quantize_stochastic, quantize_stochastic_at, Quantizer, Rounding,
+  },
+};
+
+fn run(rounding: Rounding, data: &[f32], chunk: usize) -> (Vec<F8>, f32) {
+  let mut q = Quantizer::new(rounding);
+  let mut out = vec![];
+  for c in data.chunks(chunk) {
+    out.extend_from_slice(q.push(c));
+  }
+  (out, q.finish())
+}
+
+#[test]
+fn chunking_does_not_change_output() {
+  let data: Vec<f32> = (0..100).map(|i| (i as f32 * 0.37).sin() * 3.0).collect();
+  for &r in &[
+    Rounding::Nearest,
+    Rounding::ErrorFeedback,
+    Rounding::Stochastic(7),
+  ] {
+    let (whole, res) = run(r, &data, data.len());
+    let (chunked, chunked_res) = run(r, &data, 7);
+    assert_eq!(whole, chunked);
+    assert_eq!(res, chunked_res);
+  }
+}
+
+#[test]
+fn error_feedback_preserves_sum() {
+  let data = [0.1f32; 40];
+  let (out, residual) = run(Rounding::ErrorFeedback, &data, 3);
+  let sum: f32 = out.iter().map(|f| f.v()).sum();
+  assert!((sum + residual - 4.0).abs() < 1e-4);
+  assert!(residual.abs() < 0.5);
+}
+
+#[test]
+fn error_feedback_recovers_from_non_finite_input() {
+  let mut data = vec![f32::NAN, f32::INFINITY];
+  data.extend_from_slice(&[0.1; 40]);
+  let (out, residual) = run(Rounding::ErrorFeedback, &data, 5);
+  let (fresh, fresh_residual) = run(Rounding::ErrorFeedback, &data[2..], 5);
+  assert_eq!(&out[2..], &fresh[..]);
+  assert_eq!(residual, fresh_residual);
+}
+
+#[test]
+fn stochastic_rounds_to_neighbors() {
+  let (out, _) = run(Rounding::Stochastic(1), &[0.1; 64], 64);
+  assert!(out.iter().all(|f| f.v() == 0.0 || f.v() == 0.25));
+  assert!(out.iter().any(|f| f.v() == 0.25));
+  let (exact, _) = run(Rounding::Stochastic(1), &[1.5; 8], 8);
+  assert!(exact.iter().all(|f| f.v() == 1.5));
+}
+
+#[test]
+fn seeded_stochastic_is_reproducible() {
+  let src: Vec<f32> = (0..256).map(|i| i as f32 * 0.013).collect();
+  let mut a = vec![F8::from_bits(0); src.len()];
+  let mut b = a.clone();
+  quantize_stochastic(&src, &mut a, 42);
+  quantize_stochastic_at(&src[..100], &mut b[..100], 42, 0);
+  quantize_stochastic_at(&src[100..], &mut b[100..], 42, 100);
+  assert_eq!(a, b);
+  let (streamed, _) = run(Rounding::Stochastic(42), &src, 13);
+  assert_eq!(a, streamed);
+  quantize_stochastic(&src, &mut b, 43);
+  assert_ne!(a, b);
+}
+
+#[test]
+fn stochastic_is_unbiased() {
+  let src = [0.6f32; 4096];
+  let mut dst = [F8::from_bits(0); 4096];
+  quantize_stochastic(&src, &mut dst, 3);
+  let mean = dst.iter().map(|f| f.v()).sum::<f32>() / src.len() as f32;
+  assert!((mean - 0.6).abs() < 0.01, "{}", mean);
+}
+
+#[test]
+fn dithering_preserves_local_mean() {
+  let (w, h) = (16, 16);
+  let src: Vec<f32> = (0..w * h)
+    .map(|i| 8.0 + (i % w) as f32 / w as f32)
+    .collect();
+  let mut dst = vec![F8::from_bits(0); src.len()];
+  quantize_dithered_2d(&src, w, &mut dst);
+  assert!(dst.iter().all(|f| f.v() == 8.0 || f.v() == 9.0));
+  // each 4 column band averages close to its source, where rounding alone would flatten it
+  for band in 0..4 {
+    let cols = band * 4..band * 4 + 4;
+    let idx = || (0..h).flat_map(|y| cols.clone().map(move |x| y * w + x));
+    let want = idx().map(|i| src[i]).sum::<f32>();
+    let got = idx().map(|i| dst[i].v()).sum::<f32>();
+    assert!((want - got).abs() / 64.0 < 0.1, "{} {} {}", band, want, got);
+  }
+}
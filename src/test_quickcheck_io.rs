@@ -0,0 +1,21 @@
+use crate::f8::F8;
+use quickcheck::{quickcheck, Arbitrary};
+
+quickcheck! {
+  fn round_trips_through_f32(f: F8) -> bool { F8::approx_from(f.v()).v() == f.v() }
+}
+
+#[test]
+fn shrinks_toward_zero() {
+  let mut f = F8::approx_from(-96.0);
+  let mut steps = 0;
+  // always take the last, least aggressive shrink to walk the longest chain
+  while let Some(next) = f.shrink().last() {
+    f = next;
+    steps += 1;
+    assert!(steps < 256);
+  }
+  assert_eq!(f.v(), 0.0);
+  assert!(F8::from_bits(0).shrink().next().is_none());
+  assert!(F8::approx_from(3.0).shrink().any(|f| f.v() == 1.0));
+}
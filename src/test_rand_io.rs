@@ -0,0 +1,57 @@
+use crate::{f8::F8, rand_io::StochasticF8};
+use rand::{distributions::Uniform, rngs::StdRng, Rng, SeedableRng};
+
+#[test]
+fn standard_is_unit_interval() {
+  let mut rng = StdRng::seed_from_u64(1);
+  let mut counts = [0; 4];
+  for _ in 0..4000 {
+    let f: F8 = rng.gen();
+    let v = f.v();
+    assert!((0.0..1.0).contains(&v), "{}", v);
+    counts[(v * 4.0) as usize] += 1;
+  }
+  assert!(
+    counts.iter().all(|&c| (900..1100).contains(&c)),
+    "{:?}",
+    counts
+  );
+}
+
+#[test]
+fn uniform_ranges() {
+  let mut rng = StdRng::seed_from_u64(2);
+  let (lo, hi) = (F8::approx_from(-2.0), F8::approx_from(16.0));
+  let d = Uniform::new(lo, hi);
+  let mut sum = 0.0;
+  for _ in 0..4000 {
+    let v = rng.sample(d).v();
+    assert!((-2.0..16.0).contains(&v), "{}", v);
+    sum += v;
+  }
+  // rounding down makes the mean fall below the midpoint of 7
+  let mean = sum / 4000.0;
+  assert!(mean > 6.0 && mean < 7.0, "{}", mean);
+  let top = Uniform::new_inclusive(F8::approx_from(448.0), F8::MAX);
+  let hits = (0..1000).filter(|_| rng.sample(top) == F8::MAX).count();
+  assert!(hits > 0 && hits < 1000, "{}", hits);
+  let single = Uniform::new_inclusive(hi, hi);
+  assert_eq!(rng.sample(single), hi);
+}
+
+#[test]
+fn stochastic_normal_keeps_mean() {
+  let mut rng = StdRng::seed_from_u64(3);
+  let n = 8000;
+  let d = StochasticF8::normal(0.6, 0.02).unwrap();
+  let mean = (0..n).map(|_| rng.sample(d).v()).sum::<f32>() / n as f32;
+  assert!((mean - 0.6).abs() < 0.01, "{}", mean);
+  let nearest = (0..n)
+    .map(|_| F8::approx_from(rng.sample(d.0)).v())
+    .sum::<f32>()
+    / n as f32;
+  assert!((nearest - 0.6).abs() > 0.05, "{}", nearest);
+  let e = StochasticF8::exponential(0.25).unwrap();
+  let mean = (0..n).map(|_| rng.sample(e).v()).sum::<f32>() / n as f32;
+  assert!((mean - 4.0).abs() < 0.2, "{}", mean);
+}
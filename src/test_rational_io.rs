@@ -0,0 +1,27 @@
+use crate::f8::F8;
+use crate::rational_io::InexactRatioError;
+use num_rational::Ratio;
+use std::convert::TryFrom;
+
+fn exact(r: Ratio<i32>) -> Result<F8, InexactRatioError> { TryFrom::try_from(r) }
+
+#[test]
+fn exact_round_trip() {
+  assert_eq!(Ratio::from(F8::approx_from(-0.75)), Ratio::new(-3, 4));
+  assert_eq!(Ratio::from(F8::MAX), Ratio::from_integer(480));
+  for b in 0..=255u8 {
+    let f = F8::from_bits(b);
+    let r = Ratio::from(f);
+    assert_eq!(*r.numer() as f32 / *r.denom() as f32, f.v());
+    assert_eq!(exact(r).unwrap().v(), f.v());
+  }
+  assert_eq!(exact(Ratio::new_raw(6, -8)).unwrap().v(), -0.75);
+  for r in &[
+    Ratio::new(1, 3),
+    Ratio::new(1, 8),
+    Ratio::new(481, 1),
+    Ratio::new_raw(1, 0),
+  ] {
+    assert!(exact(*r).is_err());
+  }
+}
@@ -0,0 +1,65 @@
+use crate::{
+  f8::F8,
+  rgbe::{compare, Rgbe8, RGBE_MAX},
+};
+
+#[test]
+fn round_trip_within_bound() {
+  let pixels = [
+    [1.0, 0.5, 0.25],
+    [1000.0, 3.0, 0.0],
+    [0.001, 0.002, 0.0005],
+    [255.9, 127.0, 1.0],
+    [1e-30, 0.0, 0.0],
+  ];
+  for &p in &pixels {
+    let q = Rgbe8::from_rgb(p);
+    let bound = q.max_abs_error();
+    let back = q.to_rgb();
+    let peak = p.iter().fold(0f32, |m, &v| m.max(v));
+    for c in 0..3 {
+      assert!(
+        (p[c] - back[c]).abs() <= bound * 1.0001,
+        "{:?} {:?}",
+        p,
+        back
+      );
+    }
+    assert!(bound <= peak / 255.0, "{:?} {}", p, bound);
+  }
+}
+
+#[test]
+fn exact_and_special_values() {
+  assert_eq!(Rgbe8::from_rgb([0.0; 3]), Rgbe8::BLACK);
+  assert_eq!(Rgbe8::from_rgb([-1.0, f32::NAN, 0.0]), Rgbe8::BLACK);
+  assert_eq!(Rgbe8::from_rgb([1.0, 0.5, 0.0]).to_rgb(), [1.0, 0.5, 0.0]);
+  let top = Rgbe8::from_rgb([f32::INFINITY, 1.0, 0.0]);
+  assert_eq!(top.to_rgb()[0], RGBE_MAX);
+  assert_eq!(Rgbe8::from_bytes(top.to_bytes()), top);
+}
+
+#[test]
+fn beats_f8_on_bright_pixels() {
+  let pixels: Vec<[f32; 3]> = (0..64)
+    .map(|i| {
+      let v = 1.1f32.powi(i);
+      [v, v * 0.7, v * 0.3]
+    })
+    .collect();
+  let c = compare(&pixels);
+  assert!(c.rgbe.max_rel < 1.0 / 255.0, "{:?}", c);
+  assert!(c.rgbe.max_rel < c.f8.max_rel, "{:?}", c);
+}
+
+#[test]
+fn f8_error_model() {
+  // the bound stated in the module docs for per channel F8
+  for i in 0..=47_800 {
+    let v = 2.0 + i as f32 * 0.01;
+    let err = (F8::approx_from(v).v() - v).abs();
+    assert!(err <= v / 16.0, "{} {}", v, err);
+  }
+  // below 2 the significand has fewer bits
+  assert_eq!(F8::approx_from(0.375).v(), 0.5);
+}
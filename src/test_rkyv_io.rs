@@ -0,0 +1,31 @@
+use crate::{e8m0::E8M0, f8::F8, ofp8::E4M3};
+use rkyv::{rancor::Error, vec::ArchivedVec, Archive, Deserialize, Serialize};
+
+#[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+struct Tensor {
+  scale: E8M0,
+  nan: E4M3,
+  data: Vec<F8>,
+}
+
+#[test]
+fn archived_slices_are_f8() {
+  let data: Vec<F8> = (0..=255u8).map(F8::from_bits).collect();
+  let bytes = rkyv::to_bytes::<Error>(&data).unwrap();
+  let archived = rkyv::access::<ArchivedVec<F8>, Error>(&bytes).unwrap();
+  assert_eq!(archived.as_slice(), &data[..]);
+}
+
+#[test]
+fn struct_round_trip() {
+  let t = Tensor {
+    scale: E8M0::from_exp(-4),
+    nan: E4M3::NAN,
+    data: vec![F8::approx_from(1.5), F8::MAX],
+  };
+  let bytes = rkyv::to_bytes::<Error>(&t).unwrap();
+  let archived = rkyv::access::<ArchivedTensor, Error>(&bytes).unwrap();
+  assert_eq!(archived.scale, t.scale);
+  assert_eq!(archived.data.as_slice(), &t.data[..]);
+  assert_eq!(rkyv::deserialize::<Tensor, Error>(archived).unwrap(), t);
+}
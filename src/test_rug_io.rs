@@ -0,0 +1,8 @@
+use crate::rug_io::report;
+
+#[test]
+fn luts_match_mpfr() {
+  for r in report() {
+    assert_eq!((r.mismatches, r.panics), (0, 0), "{}", r);
+  }
+}
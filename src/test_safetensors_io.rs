@@ -0,0 +1,37 @@
+use crate::{
+  f8::F8,
+  ofp8::{E4M3, E5M2},
+  safetensors_io::{load, load_tensor, save, Fp8Data, Fp8Tensor},
+};
+
+#[test]
+fn round_trip_all_formats() {
+  let f8 = Fp8Tensor {
+    shape: vec![2, 2],
+    data: Fp8Data::F8((0..4).map(F8::from_bits).collect()),
+  };
+  let e4 = Fp8Tensor {
+    shape: vec![3],
+    data: Fp8Data::E4M3(vec![E4M3(0x38), E4M3(0xB8), E4M3::MAX]),
+  };
+  let e5 = Fp8Tensor {
+    shape: vec![1],
+    data: Fp8Data::E5M2(vec![E5M2::INFINITY]),
+  };
+  let bytes = save(vec![("a", &f8), ("b", &e4), ("c", &e5)]).unwrap();
+  let loaded = load(&bytes).unwrap();
+  assert_eq!(loaded.len(), 3);
+  assert_eq!(loaded[0], ("a".to_string(), f8));
+  assert_eq!(loaded[1].1, e4);
+  assert_eq!(load_tensor(&bytes, "c").unwrap(), e5);
+  assert_eq!(e4.data.to_f32(), vec![1.0, -1.0, 448.0]);
+}
+
+#[test]
+fn rejects_bad_shape() {
+  let t = Fp8Tensor {
+    shape: vec![5],
+    data: Fp8Data::E4M3(vec![E4M3(0)]),
+  };
+  assert!(save(vec![("t", &t)]).is_err());
+}
@@ -0,0 +1,35 @@
+use crate::scaled::ScaledF8Tensor;
+
+#[test]
+fn quantize_round_trip() {
+  let data = [1000.0, -250.0, 3.0, 0.0];
+  let t = ScaledF8Tensor::quantize(&data);
+  let back = t.dequantize();
+  assert!((back[0] - 1000.0).abs() < 1e-3);
+  for (a, b) in data.iter().zip(&back) {
+    assert!((a - b).abs() <= 1000.0 / 16.0);
+  }
+}
+
+#[test]
+fn subnormal_data_keeps_a_nonzero_scale() {
+  // the largest magnitude divided by F8::MAX underflows to zero
+  let tiny = f32::from_bits(1);
+  let data = [2.0 * tiny, -tiny, 0.0];
+  let t = ScaledF8Tensor::quantize(&data);
+  assert!(t.scale > 0.0);
+  assert_eq!(t.dequantize(), data);
+}
+
+#[test]
+fn fused_ops_apply_scale() {
+  let a = ScaledF8Tensor::quantize_with_scale(&[2.0, 4.0], 2.0);
+  let b = ScaledF8Tensor::quantize_with_scale(&[1.0, 1.0], 0.5);
+  assert_eq!(a.dot(&b), 6.0);
+  let mut out = [0.0; 1];
+  a.gemm(&b, 1, 2, 1, &mut out);
+  assert_eq!(out, [6.0]);
+  let mut y = [1.0, 1.0];
+  a.axpy(0.5, &mut y);
+  assert_eq!(y, [2.0, 3.0]);
+}
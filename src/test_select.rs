@@ -0,0 +1,83 @@
+use crate::{
+  f8::F8,
+  select::{
+    argmax, argmin, binary_search_total, is_sorted_total, median, quantiles, sort_total, top_k,
+  },
+  test_f8::f8s,
+};
+
+#[test]
+fn order_key_is_monotonic() {
+  for a in 0..=255u8 {
+    for b in 0..=255u8 {
+      let (a, b) = (F8::from_bits(a), F8::from_bits(b));
+      assert_eq!(
+        a.order_key().cmp(&b.order_key()),
+        a.v().partial_cmp(&b.v()).unwrap()
+      );
+    }
+  }
+}
+
+#[test]
+fn extremes() {
+  let v = f8s(&[1.0, -3.0, 7.0, 7.0, -3.0, 0.0]);
+  assert_eq!(argmax(&v), Some(2));
+  assert_eq!(argmin(&v), Some(1));
+  assert_eq!(argmax(&[]), None);
+  // 2.0 encoded two different ways compares equal
+  let v = [F8::new(0, 3, 1), F8::new(0, 2, 2)];
+  assert_eq!(argmax(&v), Some(0));
+}
+
+#[test]
+fn top_k_matches_sort() {
+  let v: Vec<F8> = (0..200u32)
+    .map(|i| F8::from_bits((i * 37 % 256) as u8))
+    .collect();
+  for &k in &[0, 1, 5, 64, 200, 300] {
+    let mut expected: Vec<usize> = (0..v.len()).collect();
+    expected.sort_by(|&a, &b| v[b].v().partial_cmp(&v[a].v()).unwrap().then(a.cmp(&b)));
+    expected.truncate(k);
+    assert_eq!(top_k(&v, k), expected, "k = {}", k);
+  }
+}
+
+#[test]
+fn total_order_sorting() {
+  let mut all: Vec<F8> = F8::iter_all().collect();
+  assert!(!is_sorted_total(&all));
+  sort_total(&mut all);
+  assert!(is_sorted_total(&all));
+  assert!(all.windows(2).all(|w| w[0].order_key() <= w[1].order_key()));
+  assert_eq!(all[0].v(), -F8::MAX.v());
+  let zero = all.iter().position(|f| f.v() == 0.0).unwrap();
+  assert!(all[zero].is_sign_negative());
+  for (i, &f) in all.iter().enumerate() {
+    assert_eq!(binary_search_total(&all, f), Ok(i));
+  }
+  let mut vals = f8s(&[3.0, -1.0, 0.5]);
+  sort_total(&mut vals);
+  assert_eq!(vals, f8s(&[-1.0, 0.5, 3.0]));
+  assert_eq!(binary_search_total(&vals, F8::approx_from(1.0)), Err(2));
+}
+
+#[test]
+fn quantiles_match_sorting() {
+  let v: Vec<F8> = (0..1001)
+    .map(|i| F8::from_bits((i * 97 % 256) as u8))
+    .collect();
+  let mut sorted = v.clone();
+  sort_total(&mut sorted);
+  let qs = [0.0f32, 0.1, 0.25, 0.5, 0.9, 0.999, 1.0, 2.0];
+  let expected: Vec<F8> = qs
+    .iter()
+    .map(|&q| sorted[(q.min(1.0) as f64 * 1000.0) as usize])
+    .collect();
+  assert_eq!(quantiles(&v, &qs), expected);
+  assert_eq!(
+    median(&f8s(&[4.0, 1.0, 3.0, 2.0])),
+    Some(F8::approx_from(2.0))
+  );
+  assert_eq!(median(&[]), None);
+}
@@ -0,0 +1,45 @@
+use crate::{e8m0::E8M0, f8::F8, ofp8::E4M3, packed::F8x4};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+  value: F8,
+  #[serde(with = "crate::serde_io::bits")]
+  raw: F8,
+  #[serde(with = "crate::serde_io::bits")]
+  nan: E4M3,
+  scale: E8M0,
+  lanes: F8x4,
+}
+
+#[test]
+fn numeric_and_bits() {
+  let r = Record {
+    value: F8::approx_from(1.5),
+    raw: F8::approx_from(-2.0),
+    nan: E4M3::NAN,
+    scale: E8M0::from_exp(3),
+    lanes: F8x4::from_f32s([0.0, 1.0, 2.0, 3.0]),
+  };
+  let json = serde_json::to_string(&r).unwrap();
+  assert_eq!(
+    json,
+    format!(
+      r#"{{"value":1.5,"raw":{},"nan":127,"scale":8.0,"lanes":[0.0,1.0,2.0,3.0]}}"#,
+      F8::approx_from(-2.0).to_bits()
+    )
+  );
+  assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), r);
+}
+
+#[test]
+fn deserializes_to_nearest() {
+  assert_eq!(
+    serde_json::from_str::<F8>("1.45").unwrap(),
+    F8::approx_from(1.45)
+  );
+  assert_eq!(
+    serde_json::from_str::<E8M0>("5.0").unwrap(),
+    E8M0::from_exp(3)
+  );
+}
@@ -0,0 +1,49 @@
+use crate::{f8::F8, soft};
+
+#[test]
+fn matches_rounding_through_f32() {
+  for a in F8::iter_all() {
+    assert_eq!(
+      f32::from_bits(soft::to_f32_bits(a)),
+      F8::DECODE_TABLE[a.to_bits() as usize]
+    );
+    for b in F8::iter_all() {
+      let (x, y) = (a.v(), b.v());
+      let add = soft::add(a, b);
+      assert_eq!(add, F8::approx_from(x + y), "{} + {}", x, y);
+      assert_eq!(
+        soft::sub(a, b).v(),
+        F8::approx_from(x - y).v(),
+        "{} - {}",
+        x,
+        y
+      );
+      let mul = soft::mul(a, b);
+      assert_eq!(mul.v(), F8::approx_from(x * y).v(), "{} * {}", x, y);
+      assert_eq!(
+        mul.is_sign_negative(),
+        (x * y).is_sign_negative(),
+        "{} * {}",
+        x,
+        y
+      );
+    }
+  }
+}
+
+#[test]
+fn fixed_point_rounding() {
+  // 9/8 is a tie between 1 and 1.25
+  assert_eq!(soft::from_fixed(9, 3).v(), 1.0);
+  assert_eq!(soft::from_fixed(-11, 3).v(), -1.5);
+  assert_eq!(soft::from_fixed(1 << 40, 2), F8::MAX);
+  assert_eq!(soft::from_fixed(-495 * 4, 2).v(), -480.0);
+  let max = soft::MAX_FRAC_BITS;
+  assert_eq!(soft::from_fixed(3 << (max - 1), max).v(), 1.5);
+  assert_eq!(soft::from_fixed(i64::MIN, max).v(), -480.0);
+  assert_eq!(soft::from_fixed(i64::MAX, max), F8::MAX);
+}
+
+#[test]
+#[should_panic(expected = "frac_bits out of range")]
+fn fixed_point_rejects_large_frac_bits() { soft::from_fixed(1, soft::MAX_FRAC_BITS + 1); }
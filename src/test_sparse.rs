@@ -0,0 +1,19 @@
+use crate::{f8::F8, sparse::CsrF8};
+
+#[test]
+fn spmv_matches_dense() {
+  let dense = [
+    0.0, 2.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 5.0, 0.0, 0.0, 0.001,
+  ];
+  let m = CsrF8::from_dense(&dense, 3, 4);
+  assert_eq!(m.nnz(), 3);
+  assert_eq!(m.indptr, vec![0, 2, 2, 3]);
+  assert_eq!(m.to_dense()[1], 2.0);
+  let x = [1.0, 2.0, 3.0, 4.0];
+  let mut y = [0.0; 3];
+  m.spmv(&x, &mut y);
+  assert_eq!(y, [0.0, 0.0, 5.0]);
+  let xf: Vec<F8> = x.iter().map(|&v| F8::approx_from(v)).collect();
+  m.spmv_f8(&xf, 0.5, &mut y);
+  assert_eq!(y, [0.0, 0.0, 2.5]);
+}
@@ -0,0 +1,51 @@
+use crate::{
+  f8::F8,
+  srgb::{
+    f8_to_srgb, linear_f8_from_srgb, linear_to_srgb, srgb_from_linear_f8, srgb_to_f8,
+    srgb_to_linear,
+  },
+};
+
+#[test]
+fn transfer_round_trips() {
+  for i in 0..=100 {
+    let v = i as f64 / 100.0;
+    assert!(
+      (linear_to_srgb(srgb_to_linear(v)) - v).abs() < 1e-12,
+      "{}",
+      v
+    );
+  }
+  assert_eq!(srgb_to_linear(1.0), 1.0);
+  assert_eq!(srgb_to_linear(-0.5), -srgb_to_linear(0.5));
+}
+
+#[test]
+fn rounds_to_nearest_f8() {
+  let scale = 1.0 / 64.0;
+  for i in 0..=1000 {
+    let v = i as f32 / 1000.0;
+    let f = linear_f8_from_srgb(v, scale);
+    let want = srgb_to_linear(v as f64) / scale as f64;
+    let err = (f.v() as f64 - want).abs();
+    let best = (0..=255u8)
+      .map(|b| (F8::from_bits(b).v() as f64 - want).abs())
+      .fold(f64::INFINITY, f64::min);
+    assert_eq!(err, best, "{}", v);
+  }
+  assert_eq!(linear_f8_from_srgb(1.0, 1e-6), F8::MAX);
+  assert_eq!(linear_f8_from_srgb(f32::NAN, 1.0).v(), 0.0);
+}
+
+#[test]
+fn slices_round_trip() {
+  let src: Vec<f32> = (0..=16).map(|i| i as f32 / 16.0).collect();
+  let mut q = vec![F8::from_bits(0); src.len()];
+  srgb_to_f8(&src, 1.0 / 256.0, &mut q);
+  let mut back = vec![0.0; src.len()];
+  f8_to_srgb(&q, 1.0 / 256.0, &mut back);
+  for (a, b) in src.iter().zip(&back) {
+    assert!((a - b).abs() < 0.03, "{} {}", a, b);
+  }
+  assert_eq!(srgb_from_linear_f8(q[16], 1.0 / 256.0), 1.0);
+}
@@ -0,0 +1,38 @@
+use crate::{f8::F8, stats::RunningStats};
+
+#[test]
+fn matches_two_pass() {
+  let v: Vec<F8> = (0..1000)
+    .map(|i| F8::from_bits((i * 13 % 256) as u8))
+    .collect();
+  let mut s = RunningStats::new();
+  s.extend(&v);
+  let n = v.len() as f64;
+  let mean = v.iter().map(|f| f.v() as f64).sum::<f64>() / n;
+  let var = v.iter().map(|f| (f.v() as f64 - mean).powi(2)).sum::<f64>() / n;
+  assert_eq!(s.count(), 1000);
+  assert!((s.mean() - mean).abs() < 1e-9);
+  assert!((s.variance() - var).abs() < 1e-6 * var);
+  assert!((s.sample_variance() - var * n / (n - 1.0)).abs() < 1e-6 * var);
+
+  let (a, b) = v.split_at(377);
+  let (mut sa, mut sb) = (RunningStats::new(), RunningStats::new());
+  sa.extend(a);
+  sb.extend(b.iter().copied());
+  sa.merge(&sb);
+  assert_eq!(sa.count(), s.count());
+  assert!((sa.mean() - s.mean()).abs() < 1e-9);
+  assert!((sa.variance() - s.variance()).abs() < 1e-6 * var);
+  sa.merge(&RunningStats::new());
+  assert_eq!(sa.count(), 1000);
+}
+
+#[test]
+fn large_offset_is_stable() {
+  let mut s = RunningStats::new();
+  for i in 0..10_000 {
+    s.push_f32(1e6 + (i % 2) as f32);
+  }
+  assert!((s.variance() - 0.25).abs() < 1e-9);
+  assert_eq!(RunningStats::new().variance(), 0.0);
+}
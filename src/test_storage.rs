@@ -0,0 +1,47 @@
+use crate::{
+  f8::F8,
+  storage::{read_tensor, write_tensor, Format, Header},
+};
+
+#[test]
+fn round_trip_in_memory() {
+  let data: Vec<F8> = (0..6).map(F8::from_bits).collect();
+  let mut buf = vec![];
+  write_tensor(&mut buf, &[2, 3], &data).unwrap();
+  let (header, read) = read_tensor(&buf).unwrap();
+  assert_eq!(
+    header,
+    Header {
+      format: Format::F8,
+      shape: vec![2, 3]
+    }
+  );
+  assert_eq!(read, &data[..]);
+}
+
+#[test]
+fn rejects_bad_input() {
+  assert!(read_tensor(b"nope").is_err());
+  let mut buf = vec![];
+  assert!(write_tensor(&mut buf, &[4], &[F8::from_bits(0)]).is_err());
+  // dims whose product overflows usize
+  let mut huge = b"F8TN\0\x02\0\0".to_vec();
+  huge.extend_from_slice(&(1u64 << 32).to_le_bytes());
+  huge.extend_from_slice(&(1u64 << 32).to_le_bytes());
+  let err = read_tensor(&huge).unwrap_err();
+  assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn round_trip_mmap() {
+  use crate::storage::MappedF8;
+  let data: Vec<F8> = (0..=255).map(F8::from_bits).collect();
+  let path = std::env::temp_dir().join("f8_test_round_trip_mmap.f8t");
+  write_tensor(std::fs::File::create(&path).unwrap(), &[16, 16], &data).unwrap();
+  let mapped = MappedF8::open(&path).unwrap();
+  assert_eq!(mapped.shape(), &[16, 16]);
+  assert_eq!(mapped.data(), &data[..]);
+  drop(mapped);
+  std::fs::remove_file(path).unwrap();
+}
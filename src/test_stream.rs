@@ -0,0 +1,50 @@
+use crate::{
+  f8::F8,
+  stream::{F8Reader, F8Writer},
+};
+
+#[test]
+fn round_trip() {
+  let values: Vec<f32> = (0..20_000)
+    .map(|i| F8::from_bits((i % 256) as u8).v())
+    .collect();
+  let mut w = F8Writer::new(vec![]);
+  w.write_f32(&values[..10_000]).unwrap();
+  let rest: Vec<F8> = values[10_000..]
+    .iter()
+    .map(|&v| F8::approx_from(v))
+    .collect();
+  w.write_f8(&rest).unwrap();
+  let bytes = w.into_inner().unwrap();
+  assert_eq!(bytes.len(), 20_000);
+  assert!(bytes
+    .iter()
+    .zip(&values)
+    .all(|(&b, &v)| F8::from_bits(b).v() == v));
+
+  let mut r = F8Reader::new(&bytes[..]);
+  let mut head = vec![0f32; 9_000];
+  assert_eq!(r.read_f32(&mut head).unwrap(), 9_000);
+  assert_eq!(head, values[..9_000]);
+  let mut one = [F8::from_bits(0)];
+  assert_eq!(r.read_f8(&mut one).unwrap(), 1);
+  assert_eq!(one[0].v(), values[9_000]);
+  assert_eq!(r.read_to_end_f32().unwrap(), values[9_001..]);
+  assert_eq!(r.read_f8(&mut one).unwrap(), 0);
+}
+
+#[test]
+fn flushes_on_drop() {
+  let mut out = vec![];
+  {
+    let mut w = F8Writer::new(&mut out);
+    w.write_f32(&[1.0, 2.0]).unwrap();
+  }
+  assert_eq!(
+    out,
+    [
+      F8::approx_from(1.0).to_bits(),
+      F8::approx_from(2.0).to_bits()
+    ]
+  );
+}
@@ -0,0 +1,42 @@
+use crate::{
+  export_tables,
+  f8::F8,
+  tables::{TableFormat, TableOp},
+};
+
+#[test]
+fn csv_table() {
+  let mut out = vec![];
+  export_tables(TableOp::Mul, TableFormat::Csv, &mut out).unwrap();
+  let text = String::from_utf8(out).unwrap();
+  let lines: Vec<&str> = text.lines().collect();
+  assert_eq!(lines.len(), 1 + 256 * 256);
+  assert_eq!(lines[0], "a,b,a_value,b_value,exact,result,result_value");
+  // 0x1f is 7.5, and 7.5 * 7.5 = 56.25 rounds to 56
+  let row = 1 + 0x1f * 256 + 0x1f;
+  assert_eq!(lines[row], "1f,1f,7.5,7.5,56.25,4e,56");
+}
+
+#[test]
+fn json_table() {
+  let mut out = vec![];
+  export_tables(TableOp::Add, TableFormat::Json, &mut out).unwrap();
+  let rows: Vec<serde_json::Value> = serde_json::from_slice(&out).unwrap();
+  assert_eq!(rows.len(), 256 * 256);
+  let r = &rows[0x7f * 256 + 0x7f];
+  assert_eq!(r["a_value"], 480.0);
+  assert_eq!(r["exact"], 960.0);
+  assert_eq!(r["result"], "7f");
+}
+
+#[test]
+fn tables_match_operators() {
+  let mut out = vec![];
+  export_tables(TableOp::Sub, TableFormat::Csv, &mut out).unwrap();
+  let text = String::from_utf8(out).unwrap();
+  for line in text.lines().skip(1) {
+    let cols: Vec<&str> = line.split(',').collect();
+    let bits = |i: usize| F8::from_bits(u8::from_str_radix(cols[i], 16).unwrap());
+    assert_eq!(bits(5), bits(0) - bits(1), "{}", line);
+  }
+}
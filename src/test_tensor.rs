@@ -0,0 +1,78 @@
+use crate::{
+  f8::F8,
+  tensor::{F8Tensor, TensorScale},
+};
+
+fn iota(n: usize) -> Vec<F8> { (0..n).map(|i| F8::approx_from(i as f32)).collect() }
+
+#[test]
+fn slicing_and_reshape() {
+  let bits = |t: &F8Tensor| {
+    t.to_f8_vec()
+      .iter()
+      .map(|f| f.to_bits())
+      .collect::<Vec<_>>()
+  };
+  let t = F8Tensor::new(
+    (0..24).map(F8::from_bits).collect(),
+    &[2, 3, 4],
+    TensorScale::None,
+  );
+  assert_eq!(t.strides(), [12, 4, 1]);
+  assert_eq!(t.get_f8(&[1, 2, 3]).to_bits(), 23);
+  let s = t.slice(1, 1..3).slice(2, 2..4);
+  assert_eq!(s.shape(), [2, 2, 2]);
+  assert!(!s.is_contiguous());
+  assert_eq!(bits(&s), [6, 7, 10, 11, 18, 19, 22, 23]);
+  let r = s.reshape(&[4, 2]).unwrap();
+  assert!(r.is_contiguous());
+  assert_eq!(r.get_f8(&[3, 0]).to_bits(), 22);
+  assert!(s.reshape(&[3, 3]).is_none());
+  assert_eq!(t.slice(0, 1..1).len(), 0);
+  assert!(t.slice(0, 1..1).to_f32().is_empty());
+}
+
+#[test]
+fn scales() {
+  let data: Vec<f32> = (0..6).map(|i| (i as f32 - 2.0) * 100.0).collect();
+  let t = F8Tensor::from_f32(&data, &[2, 3]);
+  assert!(matches!(t.scale(), TensorScale::PerTensor(_)));
+  for (a, b) in t.to_f32().iter().zip(&data) {
+    assert!((a - b).abs() <= 0.07 * b.abs());
+  }
+  let rows = [1.0, 2.0, 3.0, 1000.0, 2000.0, 3000.0];
+  let p = F8Tensor::from_f32_per_axis(&rows, &[2, 3], 0);
+  for (a, b) in p.to_f32().iter().zip(&rows) {
+    assert!((a - b).abs() <= 0.04 * b.abs(), "{} {}", a, b);
+  }
+  let second = p.slice(0, 1..2);
+  assert_eq!(second.get(&[0, 2]), p.get(&[1, 2]));
+  assert!(p.reshape(&[6]).is_none());
+}
+
+#[test]
+fn kernels() {
+  let a = F8Tensor::new(iota(6), &[2, 3], TensorScale::PerTensor(0.5));
+  let b = F8Tensor::new(iota(6), &[3, 2], TensorScale::None);
+  // [[0, 1, 2], [3, 4, 5]] * [[0, 1], [2, 3], [4, 5]] / 2
+  assert_eq!(a.matmul(&b), [5.0, 6.5, 14.0, 20.0]);
+  let batch = F8Tensor::new(iota(12), &[2, 2, 3], TensorScale::None);
+  let out = batch.matmul(&b);
+  assert_eq!(out[..4], [10.0, 13.0, 28.0, 40.0]);
+  assert_eq!(out[4..], [46.0, 67.0, 64.0, 94.0]);
+  let x = F8Tensor::new(iota(3), &[3], TensorScale::None);
+  assert_eq!(a.matvec(&x), [2.5, 7.0]);
+  assert_eq!(x.dot(&x), 5.0);
+  assert_eq!(x.norm_l2(), 5f32.sqrt());
+
+  // per axis scales fall back to dequantized arithmetic
+  let p = F8Tensor::from_f32_per_axis(&[0.0, 1.0, 2.0, 30.0, 40.0, 50.0], &[2, 3], 0);
+  let exact = p.to_f32();
+  let expected: Vec<f32> = exact.chunks(3).map(|r| r[1] + 2.0 * r[2]).collect();
+  assert_eq!(p.matvec(&x), expected);
+  assert_eq!(p.slice(0, 0..1).reshape(&[3]), None);
+  assert_eq!(
+    a.slice(1, 0..2).dot(&a.slice(1, 1..3)),
+    (0.0 + 2.0 + 12.0 + 20.0) / 4.0
+  );
+}
@@ -0,0 +1,24 @@
+use crate::texture::{psnr, CompressedTexture, Tile4x4};
+
+#[test]
+fn tile_round_trip() {
+  let v: [f32; 16] = std::array::from_fn(|i| i as f32 * 0.125);
+  let t = Tile4x4::encode(&v);
+  assert_eq!(t.decode(), v);
+}
+
+#[test]
+fn texture_with_partial_tiles() {
+  let (w, h) = (10, 7);
+  let src: Vec<f32> = (0..w * h)
+    .map(|i| ((i % w) as f32 * 0.3).sin() * 100.0 + (i / w) as f32)
+    .collect();
+  let c = CompressedTexture::encode(&src, w, h);
+  assert_eq!(c.tiles.len(), 3 * 2);
+  assert_eq!(c.byte_len(), 6 * 17);
+  let back = c.decode();
+  assert_eq!(back.len(), src.len());
+  let p = psnr(&src, &back, 100.0);
+  assert!(p > 25.0, "{}", p);
+  assert_eq!(psnr(&src, &src, 100.0), f32::INFINITY);
+}
@@ -0,0 +1,34 @@
+use crate::{f8::F8, tracked::Tracked};
+
+#[test]
+fn bounds_hold_the_exact_result() {
+  let xs = [0.3f32, -1.7, 2.9, 0.05, 7.3, -0.6];
+  let t: Vec<_> = xs.iter().map(|&x| Tracked::from_f32(x)).collect();
+  let mut acc = Tracked::exact(F8::from_bits(0));
+  let mut exact = 0.0f64;
+  for (i, &x) in xs.iter().enumerate() {
+    let prod = t[i] * t[(i + 1) % xs.len()];
+    acc = acc + prod;
+    exact += x as f64 * xs[(i + 1) % xs.len()] as f64;
+    let (lo, hi) = acc.bounds();
+    assert!(
+      (lo as f64) <= exact && exact <= hi as f64,
+      "{} {:?}",
+      exact,
+      acc
+    );
+  }
+  assert!(acc.error > 0.0);
+  let d = Tracked::from_f32(1.1) - Tracked::from_f32(1.1);
+  assert_eq!(d.value.v(), 0.0);
+  assert!((d.error - 0.2).abs() < 1e-6);
+}
+
+#[test]
+fn exact_operations_add_no_error() {
+  let a = Tracked::exact(F8::approx_from(1.5));
+  let b = Tracked::exact(F8::approx_from(-2.0));
+  assert_eq!((a * b).error, 0.0);
+  assert_eq!((a + b).value.v(), -0.5);
+  assert_eq!((a + b).error, 0.0);
+}
@@ -0,0 +1,84 @@
+use crate::vector_store::{Metric, VectorStoreF8};
+
+fn assert_close(a: &[f32], b: &[f32]) {
+  for (x, y) in a.iter().zip(b) {
+    assert!(
+      (x - y).abs() <= 1e-5 * y.abs().max(1.0),
+      "{:?} != {:?}",
+      a,
+      b
+    );
+  }
+}
+
+fn store() -> VectorStoreF8 {
+  let mut s = VectorStoreF8::new(3);
+  for v in &[
+    [1.0, 0.0, 0.0],
+    [0.0, 2.0, 0.0],
+    [3.0, 3.0, 0.0],
+    [-1.0, 0.0, 0.0],
+    [0.0; 3],
+  ] {
+    s.push(v);
+  }
+  s
+}
+
+#[test]
+fn scores() {
+  let s = store();
+  assert_eq!(s.len(), 5);
+  let mut out = [0.0; 5];
+  s.scores(&[1.0, 1.0, 0.0], Metric::Dot, &mut out);
+  assert_close(&out, &[1.0, 2.0, 6.0, -1.0, 0.0]);
+  s.scores(&[2.0, 0.0, 0.0], Metric::L2, &mut out);
+  assert_close(&out, &[1.0, 8.0, 10.0, 9.0, 4.0]);
+  s.scores(&[0.0, 0.0, 5.0], Metric::Cosine, &mut out);
+  assert_eq!(out, [0.0; 5]);
+  let mut batch = [0.0; 10];
+  s.scores_batch(&[1.0, 1.0, 0.0, 2.0, 0.0, 0.0], Metric::Dot, &mut batch);
+  assert_close(&batch[..5], &[1.0, 2.0, 6.0, -1.0, 0.0]);
+  assert_close(&batch[5..], &[2.0, 0.0, 6.0, -2.0, 0.0]);
+  let mut row = [0.0; 3];
+  s.get(2, &mut row);
+  assert_close(&row, &[3.0, 3.0, 0.0]);
+}
+
+#[test]
+fn search() {
+  let s = store();
+  let q = [1.0, 0.1, 0.0];
+  let ids = |r: Vec<(usize, f32)>| r.iter().map(|p| p.0).collect::<Vec<_>>();
+  assert_eq!(ids(s.search(&q, Metric::Cosine, 2)), [0, 2]);
+  assert_eq!(ids(s.search(&q, Metric::Dot, 1)), [2]);
+  assert_eq!(ids(s.search(&q, Metric::L2, 3)), [0, 4, 3]);
+  assert_eq!(s.search(&q, Metric::L2, 10).len(), 5);
+  assert!(VectorStoreF8::new(3).search(&q, Metric::Dot, 3).is_empty());
+}
+
+#[test]
+fn l2_of_near_duplicates() {
+  let mut s = VectorStoreF8::new(4);
+  s.push(&[400.0, 300.0, 200.0, 1.0]);
+  let query = [400.0, 300.0, 200.0, 1.5];
+  let mut q = VectorStoreF8::new(4);
+  q.push(&query);
+  let (mut a, mut b) = ([0.0; 4], [0.0; 4]);
+  s.get(0, &mut a);
+  q.get(0, &mut b);
+  let expected: f32 = a.iter().zip(&b).map(|(x, y)| (y - x) * (y - x)).sum();
+  assert!(expected > 0.0);
+  let mut out = [0.0];
+  s.scores(&query, Metric::L2, &mut out);
+  assert_eq!(out, [expected]);
+}
+
+#[test]
+#[should_panic(expected = "queries is not a multiple of dim")]
+fn scores_batch_rejects_partial_queries() {
+  let mut s = VectorStoreF8::new(4);
+  s.push(&[1.0; 4]);
+  s.push(&[2.0; 4]);
+  s.scores_batch(&[1.0; 6], Metric::Dot, &mut [0.0; 3]);
+}
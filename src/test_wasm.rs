@@ -0,0 +1,15 @@
+use crate::wasm::{
+  absmax, dequantize, f8_add, f8_from_f32, f8_sub, f8_to_f32, quantize, quantize_with_scale,
+};
+
+#[test]
+fn scalars_and_slices() {
+  let a = f8_from_f32(1.5);
+  assert_eq!(f8_to_f32(a), 1.5);
+  assert_eq!(f8_sub(a, a), f8_add(f8_from_f32(0.0), f8_from_f32(0.0)));
+  let src = [1.0, -2.0, 48.0];
+  assert_eq!(dequantize(&quantize(&src), 1.0), src);
+  let scale = absmax(&src);
+  let back = dequantize(&quantize_with_scale(&src, scale), scale);
+  assert!((back[2] - 48.0).abs() < 1e-4, "{:?}", back);
+}
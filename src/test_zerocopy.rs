@@ -0,0 +1,26 @@
+use crate::{f8::F8, ofp8::E4M3, rgbe::Rgbe8};
+use zerocopy::{AsBytes, FromBytes, FromZeroes};
+
+#[derive(AsBytes, FromBytes, FromZeroes, Debug)]
+#[repr(C)]
+struct Packet {
+  id: u8,
+  value: F8,
+  nan: E4M3,
+  pad: u8,
+  pixel: Rgbe8,
+}
+
+#[test]
+fn parse_payload() {
+  let bytes = [7u8, 0x23, 0x7F, 0, 1, 2, 3, 130];
+  let p = Packet::read_from(&bytes[..]).unwrap();
+  assert_eq!(p.id, 7);
+  assert_eq!(p.value, F8::from_bits(0x23));
+  assert!(p.nan.is_nan());
+  assert_eq!(p.pixel.exp, 130);
+  assert_eq!(p.as_bytes(), &bytes);
+  let values = F8::slice_from(&bytes[..4]).unwrap();
+  assert_eq!(values[1], F8::from_bits(0x23));
+  assert_eq!(F8::new_zeroed(), F8::from_bits(0));
+}
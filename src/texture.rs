@@ -0,0 +1,94 @@
+//! Single channel texture compression in 4x4 tiles, each of 16 F8 sharing one E8M0 scale,
+//! for 17 bytes per tile.
+
+use crate::{channel::Scale, e8m0::E8M0, f8::F8};
+
+/// Width and height of a tile in texels
+pub const TILE: usize = 4;
+
+/// 4x4 texels in row major order sharing one power of two scale
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Tile4x4 {
+  pub scale: E8M0,
+  pub data: [F8; TILE * TILE],
+}
+
+impl Tile4x4 {
+  /// Quantizes 16 texels, choosing the smallest scale which avoids clipping
+  pub fn encode(v: &[f32; TILE * TILE]) -> Self {
+    let amax = v.iter().fold(0f32, |m, v| m.max(v.abs()));
+    let scale = E8M0::for_absmax(amax);
+    let inv = 1.0 / scale.to_f32();
+    Tile4x4 {
+      scale,
+      data: v.map(|v| F8::approx_from(v * inv)),
+    }
+  }
+  pub fn decode(&self) -> [f32; TILE * TILE] {
+    let s = self.scale.to_f32();
+    self.data.map(|d| d.v() * s)
+  }
+}
+
+/// A compressed single channel image, with tiles in row major order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedTexture {
+  pub width: usize,
+  pub height: usize,
+  pub tiles: Vec<Tile4x4>,
+}
+
+impl CompressedTexture {
+  /// Compresses a row-major image. Tiles past the right or bottom edge repeat the edge texels.
+  pub fn encode(src: &[f32], width: usize, height: usize) -> Self {
+    assert_eq!(src.len(), width * height, "Mismatched size");
+    let (tw, th) = (width.div_ceil(TILE), height.div_ceil(TILE));
+    let mut tiles = Vec::with_capacity(tw * th);
+    for ty in 0..th {
+      for tx in 0..tw {
+        let mut block = [0.0; TILE * TILE];
+        for (i, b) in block.iter_mut().enumerate() {
+          let x = (tx * TILE + i % TILE).min(width - 1);
+          let y = (ty * TILE + i / TILE).min(height - 1);
+          *b = src[y * width + x];
+        }
+        tiles.push(Tile4x4::encode(&block));
+      }
+    }
+    CompressedTexture {
+      width,
+      height,
+      tiles,
+    }
+  }
+  /// Decompresses into a row-major image
+  pub fn decode(&self) -> Vec<f32> {
+    let tw = self.width.div_ceil(TILE);
+    let mut out = vec![0.0; self.width * self.height];
+    for (t, tile) in self.tiles.iter().enumerate() {
+      let (tx, ty) = (t % tw, t / tw);
+      for (i, v) in tile.decode().iter().enumerate() {
+        let (x, y) = (tx * TILE + i % TILE, ty * TILE + i / TILE);
+        if x < self.width && y < self.height {
+          out[y * self.width + x] = *v;
+        }
+      }
+    }
+    out
+  }
+  /// Size of the compressed tiles in bytes
+  pub fn byte_len(&self) -> usize { self.tiles.len() * (TILE * TILE + 1) }
+}
+
+/// Peak signal to noise ratio in decibels of `approx` against `src`, for signal peak `peak`.
+/// Identical inputs give infinity.
+pub fn psnr(src: &[f32], approx: &[f32], peak: f32) -> f32 {
+  assert_eq!(src.len(), approx.len(), "Mismatched lengths");
+  let mse = src
+    .iter()
+    .zip(approx)
+    .map(|(&a, &b)| ((a - b) as f64).powi(2))
+    .sum::<f64>()
+    / src.len().max(1) as f64;
+  (10.0 * ((peak as f64).powi(2) / mse).log10()) as f32
+}
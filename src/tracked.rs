@@ -0,0 +1,68 @@
+//! F8 values carrying a bound on the rounding error accumulated in computing them.
+
+use crate::f8::F8;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// A value within `error` of the exact result of the computation which produced it
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Tracked<T> {
+  pub value: T,
+  pub error: f32,
+}
+
+impl Tracked<F8> {
+  /// A value known exactly
+  pub fn exact(value: F8) -> Self { Tracked { value, error: 0.0 } }
+  /// Rounds `v` to the nearest F8, with the rounding error as the bound
+  pub fn from_f32(v: f32) -> Self {
+    let value = F8::approx_from(v);
+    Tracked {
+      value,
+      error: (value.v() - v).abs(),
+    }
+  }
+  /// The range the exact result lies in
+  pub fn bounds(self) -> (f32, f32) { (self.value.v() - self.error, self.value.v() + self.error) }
+  /// Rounds an exact f32 result, adding its rounding error to the inherited `error`
+  fn round(exact: f32, error: f32) -> Self {
+    let value = F8::approx_from(exact);
+    Tracked {
+      value,
+      error: error + (value.v() - exact).abs(),
+    }
+  }
+}
+
+// the f32 results are exact, see `impl Add for F8`, so each operation rounds exactly once
+
+impl Add for Tracked<F8> {
+  type Output = Self;
+  fn add(self, o: Self) -> Self {
+    Tracked::round(self.value.v() + o.value.v(), self.error + o.error)
+  }
+}
+
+impl Sub for Tracked<F8> {
+  type Output = Self;
+  fn sub(self, o: Self) -> Self { self + -o }
+}
+
+impl Mul for Tracked<F8> {
+  type Output = Self;
+  fn mul(self, o: Self) -> Self {
+    let (a, b) = (self.value.v(), o.value.v());
+    // |ab - xy| <= |a| e_y + |b| e_x + e_x e_y for |a - x| <= e_x and |b - y| <= e_y
+    let inherited = a.abs() * o.error + b.abs() * self.error + self.error * o.error;
+    Tracked::round(a * b, inherited)
+  }
+}
+
+impl Neg for Tracked<F8> {
+  type Output = Self;
+  fn neg(self) -> Self {
+    Tracked {
+      value: -self.value,
+      error: self.error,
+    }
+  }
+}
@@ -0,0 +1,137 @@
+//! A small in memory vector store, holding rows as F8 with one absmax scale each, for exact
+//! nearest neighbor search over a few thousand to a few million vectors.
+
+use crate::{distance::dot_and_norms, f8::F8, scaled::absmax_scale};
+
+/// How queries are scored against rows
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Metric {
+  /// The dot product, higher is nearer
+  Dot,
+  /// The cosine similarity, higher is nearer
+  Cosine,
+  /// The squared Euclidean distance, lower is nearer
+  L2,
+}
+
+impl Metric {
+  fn higher_is_nearer(self) -> bool { self != Metric::L2 }
+}
+
+/// Rows of `dim` F8 stored contiguously, each with its own scale
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorStoreF8 {
+  dim: usize,
+  data: Vec<F8>,
+  scales: Vec<f32>,
+}
+
+fn quantize_row(v: &[f32]) -> (f32, Vec<F8>) {
+  let scale = absmax_scale(v);
+  (
+    scale,
+    v.iter().map(|&x| F8::approx_from(x / scale)).collect(),
+  )
+}
+
+impl VectorStoreF8 {
+  pub fn new(dim: usize) -> Self {
+    VectorStoreF8 {
+      dim,
+      data: vec![],
+      scales: vec![],
+    }
+  }
+  pub fn dim(&self) -> usize { self.dim }
+  pub fn len(&self) -> usize { self.scales.len() }
+  pub fn is_empty(&self) -> bool { self.scales.is_empty() }
+  /// Quantizes and appends a row, returning its index
+  pub fn push(&mut self, v: &[f32]) -> usize {
+    assert_eq!(v.len(), self.dim, "v is not of length dim");
+    let (scale, row) = quantize_row(v);
+    self.data.extend(row);
+    self.scales.push(scale);
+    self.scales.len() - 1
+  }
+  /// The stored row `i` and its scale
+  pub fn row(&self, i: usize) -> (&[F8], f32) {
+    (&self.data[i * self.dim..(i + 1) * self.dim], self.scales[i])
+  }
+  /// Dequantizes row `i` into `out`
+  pub fn get(&self, i: usize, out: &mut [f32]) {
+    let (row, s) = self.row(i);
+    for (o, f) in out.iter_mut().zip(row) {
+      *o = f.v() * s;
+    }
+  }
+  /// Scores `query` against every row into `out`, quantizing the query the same way as rows
+  pub fn scores(&self, query: &[f32], metric: Metric, out: &mut [f32]) {
+    assert_eq!(query.len(), self.dim, "query is not of length dim");
+    assert_eq!(out.len(), self.len(), "out is not of length len()");
+    let (qs, q) = quantize_row(query);
+    for (i, o) in out.iter_mut().enumerate() {
+      let (row, rs) = self.row(i);
+      if metric == Metric::L2 {
+        // summed directly, as expanding into norms and a dot product cancels for near
+        // duplicates
+        *o = q
+          .iter()
+          .zip(row)
+          .map(|(a, b)| {
+            let d = a.v() * qs - b.v() * rs;
+            d * d
+          })
+          .sum();
+        continue;
+      }
+      let (ab, aa, bb) = dot_and_norms(&q, row);
+      *o = match metric {
+        Metric::Cosine if aa == 0.0 || bb == 0.0 => 0.0,
+        Metric::Cosine => (ab as f64 / (aa as f64 * bb as f64).sqrt()) as f32,
+        _ => ab * qs * rs,
+      };
+    }
+  }
+  /// `scores` for each of the row major `queries`, into `out` which is `queries x len()`
+  pub fn scores_batch(&self, queries: &[f32], metric: Metric, out: &mut [f32]) {
+    let n = self.len();
+    assert!(
+      queries.len().is_multiple_of(self.dim.max(1)),
+      "queries is not a multiple of dim"
+    );
+    assert_eq!(
+      out.len(),
+      queries.len() / self.dim.max(1) * n,
+      "out is not queries x len()"
+    );
+    for (q, o) in queries
+      .chunks_exact(self.dim.max(1))
+      .zip(out.chunks_exact_mut(n.max(1)))
+    {
+      self.scores(q, metric, o);
+    }
+  }
+  /// The `k` nearest rows to `query` as `(index, score)`, nearest first with ties broken by
+  /// lower index
+  pub fn search(&self, query: &[f32], metric: Metric, k: usize) -> Vec<(usize, f32)> {
+    let mut scores = vec![0.0; self.len()];
+    self.scores(query, metric, &mut scores);
+    let mut ranked: Vec<(usize, f32)> = scores.into_iter().enumerate().collect();
+    let by_nearness = |a: &(usize, f32), b: &(usize, f32)| {
+      let c = a.1.total_cmp(&b.1);
+      if metric.higher_is_nearer() {
+        c.reverse()
+      } else {
+        c
+      }
+      .then(a.0.cmp(&b.0))
+    };
+    let k = k.min(ranked.len());
+    if k > 0 && k < ranked.len() {
+      ranked.select_nth_unstable_by(k - 1, by_nearness);
+    }
+    ranked.truncate(k);
+    ranked.sort_by(by_nearness);
+    ranked
+  }
+}
@@ -0,0 +1,45 @@
+//! JavaScript bindings through `wasm-bindgen`.
+//!
+//! F8 values cross as their bits, a `number` for scalars and a `Uint8Array` for slices, while
+//! floats use `Float32Array`. Build with `wasm-pack build --features wasm`.
+
+use crate::{f8::F8, scaled::absmax_scale};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(js_name = f8FromF32)]
+pub fn f8_from_f32(f: f32) -> u8 { F8::approx_from(f).to_bits() }
+
+#[wasm_bindgen(js_name = f8ToF32)]
+pub fn f8_to_f32(bits: u8) -> f32 { F8::from_bits(bits).v() }
+
+#[wasm_bindgen(js_name = f8Add)]
+pub fn f8_add(a: u8, b: u8) -> u8 { (F8::from_bits(a) + F8::from_bits(b)).to_bits() }
+
+#[wasm_bindgen(js_name = f8Sub)]
+pub fn f8_sub(a: u8, b: u8) -> u8 { (F8::from_bits(a) - F8::from_bits(b)).to_bits() }
+
+#[wasm_bindgen(js_name = f8Mul)]
+pub fn f8_mul(a: u8, b: u8) -> u8 { (F8::from_bits(a) * F8::from_bits(b)).to_bits() }
+
+/// Rounds every element to the nearest F8
+#[wasm_bindgen]
+pub fn quantize(src: &[f32]) -> Vec<u8> { quantize_with_scale(src, 1.0) }
+
+/// Rounds every element divided by `scale` to the nearest F8
+#[wasm_bindgen(js_name = quantizeWithScale)]
+pub fn quantize_with_scale(src: &[f32], scale: f32) -> Vec<u8> {
+  src
+    .iter()
+    .map(|&v| F8::approx_from(v / scale).to_bits())
+    .collect()
+}
+
+/// The scale mapping the largest magnitude of `src` onto the largest F8
+#[wasm_bindgen(js_name = absmaxScale)]
+pub fn absmax(src: &[f32]) -> f32 { absmax_scale(src) }
+
+/// Decodes F8 bits, multiplying by `scale`
+#[wasm_bindgen]
+pub fn dequantize(bits: &[u8], scale: f32) -> Vec<f32> {
+  bits.iter().map(|&b| F8::from_bits(b).v() * scale).collect()
+}